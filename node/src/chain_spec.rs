@@ -262,6 +262,7 @@ fn testnet_genesis(
 			key: Some(root_key),
 		},
 		transaction_payment: Default::default(),
+		treasury: Default::default(),
 		subspace_module: Default::default(),
 	}
 }
@@ -299,6 +300,7 @@ fn newton_genesis(
 			key: Some(root_key),
 		},
 		transaction_payment: Default::default(),
+		treasury: Default::default(),
 		subspace_module: SubspaceModuleConfig {
 			stakes: stakes,
 			balances_issuance: balances_issuance
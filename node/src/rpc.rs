@@ -39,6 +39,7 @@ where
 	C::Api: BlockBuilder<Block>,
 	C::Api: subspace_custom_rpc_runtime_api::ModuleRuntimeApi<Block>,
 	C::Api: subspace_custom_rpc_runtime_api::NetworkRuntimeApi<Block>,
+	C::Api: subspace_custom_rpc_runtime_api::EmissionRuntimeApi<Block>,
 	P: TransactionPool + 'static
 {
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
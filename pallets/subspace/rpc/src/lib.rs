@@ -17,6 +17,7 @@ use sp_api::ProvideRuntimeApi;
 
 pub use subspace_custom_rpc_runtime_api::ModuleRuntimeApi;
 pub use subspace_custom_rpc_runtime_api::NetworkRuntimeApi;
+pub use subspace_custom_rpc_runtime_api::EmissionRuntimeApi;
 
 #[rpc(client, server)]
 pub trait SubspaceCustomApi<BlockHash> {
@@ -25,11 +26,18 @@ pub trait SubspaceCustomApi<BlockHash> {
 	fn get_modules(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
 	#[method(name = "module_getModule")]
 	fn get_module(&self, netuid: u16, uid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+	#[method(name = "module_getModulesByTag")]
+	fn get_modules_by_tag(&self, netuid: u16, tag: Vec<u8>, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
 
 	#[method(name = "network_getNetwork")]
 	fn get_network(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
 	#[method(name = "network_getNetworksInfo")]
 	fn get_networks(&self, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+
+	#[method(name = "emission_getProjectedIssuance")]
+	fn get_projected_issuance(&self, block_number: u64, at: Option<BlockHash>) -> RpcResult<u64>;
+	#[method(name = "emission_getKeyEmission")]
+	fn get_key_emission(&self, netuid: u16, key: Vec<u8>, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
 }
 
 pub struct SubspaceCustom<C, P> {
@@ -65,6 +73,7 @@ where
 	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
 	C::Api: ModuleRuntimeApi<Block>,
 	C::Api: NetworkRuntimeApi<Block>,
+	C::Api: EmissionRuntimeApi<Block>,
 	{ 
 
 
@@ -103,6 +112,24 @@ where
 			)).into()
 		})
 	}
+
+	fn get_modules_by_tag(
+		&self,
+		netuid: u16,
+		tag: Vec<u8>,
+		at: Option<<Block as BlockT>::Hash>
+	) -> RpcResult<Vec<u8>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.get_modules_by_tag(at, netuid, tag).map_err(|e| {
+			CallError::Custom(ErrorObject::owned(
+				Error::RuntimeError.into(),
+				"Unable to get modules by tag.",
+				Some(e.to_string()),
+			)).into()
+		})
+	}
 	
 	fn get_network(
 				&self, 
@@ -136,4 +163,39 @@ where
 			)).into()
 		})
 	}
+
+	fn get_projected_issuance(
+		&self,
+		block_number: u64,
+		at: Option<<Block as BlockT>::Hash>
+	) -> RpcResult<u64> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.get_projected_issuance(at, block_number).map_err(|e| {
+			CallError::Custom(ErrorObject::owned(
+				Error::RuntimeError.into(),
+				"Unable to get projected issuance.",
+				Some(e.to_string()),
+			)).into()
+		})
+	}
+
+	fn get_key_emission(
+		&self,
+		netuid: u16,
+		key: Vec<u8>,
+		at: Option<<Block as BlockT>::Hash>
+	) -> RpcResult<Vec<u8>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.get_key_emission(at, netuid, key).map_err(|e| {
+			CallError::Custom(ErrorObject::owned(
+				Error::RuntimeError.into(),
+				"Unable to get key emission.",
+				Some(e.to_string()),
+			)).into()
+		})
+	}
 }
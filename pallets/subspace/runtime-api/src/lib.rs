@@ -3,14 +3,19 @@ extern crate alloc;
 use alloc::vec::Vec;
 
 // Here we declare the runtime API. It is implemented it the `impl` block in
-// src/module.rs, src/network.rs 
+// src/module.rs, src/network.rs, src/block_step.rs
 sp_api::decl_runtime_apis! {
 	pub trait ModuleRuntimeApi {
 		fn get_module(netuid: u16, uid: u16) -> Vec<u8>;
 		fn get_modules(netuid: u16) -> Vec<u8>;
+		fn get_modules_by_tag(netuid: u16, tag: Vec<u8>) -> Vec<u8>;
 	}
 	pub trait NetworkRuntimeApi {
 		fn get_network(netuid: u16) -> Vec<u8>;
 		fn get_networks() -> Vec<u8>;
 	}
+	pub trait EmissionRuntimeApi {
+		fn get_projected_issuance(block_number: u64) -> u64;
+		fn get_key_emission(netuid: u16, key: Vec<u8>) -> Vec<u8>;
+	}
 }
\ No newline at end of file
@@ -1,4 +1,10 @@
 //! Subspace pallet benchmarking.
+//!
+//! Every dispatchable and every part of the block step is benchmarked here. The resulting
+//! weights are written to weight_info.rs. Components:
+//! `n` the network size, `w` the number of weights of a call or the weight entries walked
+//! by an epoch phase, `r` the rows handled by an epoch step, `t` the emission tuples drained,
+//! `e` the endpoints and `g` the tags of a served module.
 
 #![cfg(feature = "runtime-benchmarks")]
 //mod benchmarking;
@@ -10,369 +16,447 @@ use frame_benchmarking::{benchmarks, whitelisted_caller, account};
 use frame_system::RawOrigin;
 use frame_support::sp_std::vec;
 use frame_support::inherent::Vec;
+use frame_support::traits::Currency;
+use frame_support::BoundedVec;
 pub use pallet::*;
 use frame_support::assert_ok;
+use sp_core::H256;
 //use mock::{Test, new_test_ext};
 
-benchmarks! {
-   
-  // Add individual benchmarks here
-  benchmark_epoch_without_weights { 
-
-    // This is a whitelisted caller who can make transaction without weights.
-    let caller: T::AccountId = whitelisted_caller::<AccountIdOf<T>>(); 
-    let caller_origin = <T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
-
-    // Lets create a single network.
-    let n: u16 = 4096;
-    let netuid: u16 = 11; //11 is the benchmark network.
-    let tempo: u16 = 1;
-    
-    assert_ok!( Subspace::<T>::do_add_network( RawOrigin::Root.into(), netuid.try_into().unwrap(), tempo.into()));
-    Subspace::<T>::set_max_allowed_uids( netuid, n ); 
-
-    // Lets fill the network with 100 UIDS and no weights.
-    let mut seed : u32 = 1;
-    for uid in 0..n as u16 {
-        let block_number: u64 = Subspace::<T>::get_current_block_as_u64();
-        let key: T::AccountId = account("Alice", 0, seed);
-        Subspace::<T>::append_module( netuid, &key, block_number );
-        seed = seed + 1;
-    }
-
-  }: _( RawOrigin::Signed( caller.clone() ) )
-
-  // Add individual benchmarks here
-  /*benchmark_drain_emission { 
-
-    // This is a whitelisted caller who can make transaction without weights.
-    let caller: T::AccountId = whitelisted_caller::<AccountIdOf<T>>(); 
-    let caller_origin = <T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
-
-    // Lets create a single network.
-    let n: u16 = 4096;
-    let netuid: u16 = 11; //11 is the benchmark network.
-    let tempo: u16 = 1;
-    
-    Subspace::<T>::do_add_network( caller_origin.clone(), netuid.try_into().unwrap(), tempo.into());
-    Subspace::<T>::set_max_allowed_uids( netuid, n ); 
-    Subspace::<T>::set_tempo( netuid, tempo );
-
-    // Lets fill the network with 100 UIDS and no weights.
-    let mut seed : u32 = 1;
-    let mut emission: Vec<(T::AccountId, u64)> = vec![];
-    for uid in 0..n as u16 {
-        let block_number: u64 = Subspace::<T>::get_current_block_as_u64();
-        let key: T::AccountId = account("Alice", 0, SEED);
-        Subspace::<T>::append_module( netuid, &key, block_number );
-        SEED = SEED + 1;
-        emission.push( ( key, 1 ) );
-    }
-    Subspace::<T>::sink_emission( netuid, emission );
- 
-  }: _( RawOrigin::Signed( caller.clone() ) )  */
-
+// Largest network the benchmarks are run against.
+const MAX_N: u32 = 4096;
 
-  benchmark_register { 
-
-    // This is a whitelisted caller who can make transaction without weights.
-    let caller: T::AccountId = whitelisted_caller::<AccountIdOf<T>>(); 
-
-    // Lets create a single network.
-    let n: u16 = 10;
-    let netuid: u16 = 1; //11 is the benchmark network.
-    let tempo: u16 = 1;
-    
+// Creates a network of 'n' staked modules with a weights row of 'row_len' entries on each of the
+// first 'n_rows' modules. Returns the keys in uid order.
+fn setup_network<T: Config>( netuid: u16, n: u16, n_rows: u16, row_len: u16 ) -> Vec<T::AccountId> {
+    Subspace::<T>::init_new_network( netuid, u16::MAX );
+    Subspace::<T>::set_max_allowed_uids( netuid, n );
+    Subspace::<T>::set_max_allowed_validators( netuid, n );
+    Subspace::<T>::set_min_allowed_weights( netuid, 0 );
+    Subspace::<T>::set_max_weight_limit( netuid, u16::MAX );
+    Subspace::<T>::set_weights_set_rate_limit( netuid, 0 );
+    Subspace::<T>::set_serving_rate_limit( netuid, 0 );
 
     let block_number: u64 = Subspace::<T>::get_current_block_as_u64();
-    let start_nonce: u64 = (39420842u64 + 100u64*netuid as u64).into();
-    let (nonce, work): (u64, Vec<u8>) = Subspace::<T>::create_work_for_block_number( netuid, block_number, start_nonce );
-
-    assert_ok!(Subspace::<T>::do_add_network( RawOrigin::Root.into(), netuid.try_into().unwrap(), tempo.into()));
-    
-    let mut seed : u32 = 1;
-    let block_number: u64 = Subspace::<T>::get_current_block_as_u64();
-    let key: T::AccountId = account("Alice", 0, seed);
-        
-  }: register( RawOrigin::Signed( caller.clone() ), netuid, block_number, nonce, work, key )
-
- benchmark_epoch_with_weights { 
-    let caller: T::AccountId = whitelisted_caller::<AccountIdOf<T>>(); 
-    let caller_origin = <T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
-    Subspace::<T>::create_network_with_weights(
-      caller_origin.clone(), 
-      11u16.into(), // netuid
-      4096u16.into(), // n
-      1000u16.into(), // tempo
-      100u16.into(), // n_vals
-      1000u16.into() // nweights
-    );
-  }: _( RawOrigin::Signed( caller.clone() ) ) 
-
-  benchmark_set_weights {
-    
-    // This is a whitelisted caller who can make transaction without weights.
-    let caller: T::AccountId = whitelisted_caller::<AccountIdOf<T>>(); 
-    let caller_origin = <T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
-    let netuid: u16 = 1;
-    let tempo: u16 = 1;
-    
-   
-    assert_ok!( Subspace::<T>::do_add_network( RawOrigin::Root.into(), netuid.try_into().unwrap(), tempo.into()));
-    Subspace::<T>::set_max_allowed_uids( netuid, 4096 ); 
-
-   assert_ok!(Subspace::<T>::do_sudo_set_max_registrations_per_block(RawOrigin::Root.into(), netuid.try_into().unwrap(), 4096 ));
-    
-    let mut seed : u32 = 1; 
-    let mut dests: Vec<u16> = vec![];
-    let mut weights: Vec<u16> = vec![];
-    let signer : T::AccountId = account("Alice", 0, seed);
-
-    for id in 0..4096 as u16 {
-      let block_number: u64 = Subspace::<T>::get_current_block_as_u64();
-      let start_nonce: u64 = (39420842u64 + 100u64*id as u64).into();
-      let (nonce, work): (u64, Vec<u8>) = Subspace::<T>::create_work_for_block_number( id, block_number, start_nonce );
-      
+    let mut keys: Vec<T::AccountId> = vec![];
+    for seed in 0..n as u32 {
         let key: T::AccountId = account("Alice", 0, seed);
-        seed = seed +1;
-      
-      
-      let block_number: u64 = Subspace::<T>::get_current_block_as_u64();
-      
-      assert_ok!( Subspace::<T>::do_registration(caller_origin.clone(), netuid.try_into().unwrap(), block_number, nonce, work, key.clone() )); 
-
-      let uid = Subspace::<T>::get_uid_for_net_and_key(netuid, &key.clone()).unwrap();
-      dests.push(id.clone());
-      weights.push(id.clone());
+        Subspace::<T>::append_module( netuid, &key, block_number );
+        Subspace::<T>::increase_stake_on_account( &key, 1_000_000_000 );
+        keys.push( key );
     }
+    for uid_i in 0..n_rows.min( n ) {
+        let row: Vec<(u16, u16)> = (0..n).filter( |uid_j| *uid_j != uid_i ).take( row_len as usize ).map( |uid_j| ( uid_j, 1 ) ).collect();
+        Weights::<T>::insert( netuid, uid_i, UidVec::<T, (u16, u16)>::try_from( row ).unwrap() );
+    }
+    keys
+}
+
+// Returns 'g' distinct tags starting with 'prefix'.
+fn make_tags( prefix: &[u8], g: u32 ) -> Vec<Vec<u8>> {
+    (0..g).map( |i| {
+        let mut tag: Vec<u8> = prefix.to_vec();
+        tag.extend( [ b'a' + ( i / 26 % 26 ) as u8, b'a' + ( i % 26 ) as u8 ] );
+        tag
+    }).collect()
+}
+
+// Gives the key enough free balance to stake from.
+fn fund_account<T: Config>( key: &T::AccountId ) {
+    let balance = Subspace::<T>::u64_to_balance( 1_000_000_000_000 ).unwrap();
+    T::Currency::deposit_creating( key, balance );
+}
 
-  }: set_weights(RawOrigin::Signed( signer.clone() ), netuid, dests, weights)
+benchmarks! {
 
+  // =================
+  // ==== Weights ====
+  // =================
 
-  benchmark_add_stake {
-    let caller: T::AccountId = whitelisted_caller::<AccountIdOf<T>>(); 
-    let caller_origin = <T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
+  set_weights {
+    let w in 1 .. MAX_N - 1;
     let netuid: u16 = 1;
-    let tempo: u16 = 1;
-    
-
-    assert_ok!( Subspace::<T>::do_add_network( RawOrigin::Root.into(), netuid.try_into().unwrap(), tempo.into()));
-    Subspace::<T>::set_max_allowed_uids( netuid, 4096 ); 
-    assert_eq!(Subspace::<T>::get_max_allowed_uids(netuid), 4096);
-
-    let mut seed : u32 = 1;
-    let key: T::AccountId = account("Alice", 0, seed);
+    let keys: Vec<T::AccountId> = setup_network::<T>( netuid, w as u16 + 1, 0, 0 );
+    let dests: Vec<u16> = (1..=w as u16).collect();
+    let weights: Vec<u16> = vec![ 1; w as usize ];
 
-    assert_ok!( Subspace::<T>::do_registration(caller_origin.clone(), netuid.try_into().unwrap() ));
+  }: set_weights( RawOrigin::Signed( keys[0].clone() ), netuid, dests, weights, 0 )
 
-    let amount: u64 = 1;
-    let amoun_to_be_staked = Subspace::<T>::u64_to_balance( 1000000000);
-
-    Subspace::<T>::add_balance_to_account(&key.clone(), amoun_to_be_staked.unwrap());
-
-  }: add_stake(RawOrigin::Signed( key.clone() ), key, amount)
-
-  benchmark_remove_stake{
-    let caller: T::AccountId = whitelisted_caller::<AccountIdOf<T>>(); 
-    let caller_origin = <T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
+  set_weights_by_key {
+    let w in 1 .. MAX_N - 1;
     let netuid: u16 = 1;
-    let tempo: u16 = 1;
-    
-
-    assert_ok!( Subspace::<T>::do_add_network( RawOrigin::Root.into(), netuid.try_into().unwrap(), tempo.into()));
-    Subspace::<T>::set_max_allowed_uids( netuid, 4096 ); 
-    assert_eq!(Subspace::<T>::get_max_allowed_uids(netuid), 4096);
-
-
-    let key: T::AccountId = account("Alice", 0, seed);
-
-    assert_ok!( Subspace::<T>::do_registration(RawOrigin::Signed( key.clone() ).into(), netuid.try_into().unwrap());
-
-    let amoun_to_be_staked = Subspace::<T>::u64_to_balance( 1000000000);
-    Subspace::<T>::add_balance_to_account(&key.clone(), amoun_to_be_staked.unwrap());
-
-    assert_ok!( Subspace::<T>::add_stake(RawOrigin::Signed( key.clone() ).into() , 1000));
-
-    let amount_unstaked: u64 = 1;
+    let keys: Vec<T::AccountId> = setup_network::<T>( netuid, w as u16 + 1, 0, 0 );
+    let dest_keys: Vec<T::AccountId> = keys[1..].to_vec();
+    let weights: Vec<u16> = vec![ 1; w as usize ];
 
-  }: remove_stake(RawOrigin::Signed( key.clone() ), amount_unstaked)
+  }: set_weights_by_key( RawOrigin::Signed( keys[0].clone() ), netuid, dest_keys, weights, 0 )
 
-  benchmark_serve_module{
-    let caller: T::AccountId = whitelisted_caller::<AccountIdOf<T>>(); 
-    let caller_origin = <T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
+  patch_weights {
+    let w in 1 .. MAX_N - 1;
     let netuid: u16 = 1;
-    let ip: u128 = 1676056785;
-    let port: u16 = 128;
+    // The patched row is already full, so the merge walks the largest row.
+    let keys: Vec<T::AccountId> = setup_network::<T>( netuid, MAX_N as u16, 1, MAX_N as u16 - 1 );
+    let upserts: Vec<(u16, u16)> = (1..=w as u16).map( |uid| ( uid, 2 ) ).collect();
 
-    Subspace::<T>::set_serving_rate_limit(netuid, 0);
+  }: patch_weights( RawOrigin::Signed( keys[0].clone() ), netuid, upserts, vec![], 0 )
 
-  }: serve_module(RawOrigin::Signed( caller.clone() ), netuid, ip, port, ip_type)
-
-  benchmark_sudo_register {
-    let caller: T::AccountId = whitelisted_caller::<AccountIdOf<T>>(); 
-    let caller_origin = <T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
+  commit_weights {
     let netuid: u16 = 1;
-    let tempo: u16 = 0;
-    
-    let stake: u64 = 10;
-    let balance: u64 = 1000000000;
-
-    assert_ok!( Subspace::<T>::do_add_network( RawOrigin::Root.into(), netuid.try_into().unwrap(), tempo.into()));
-    Subspace::<T>::set_max_allowed_uids( netuid, 4096 ); 
-    assert_eq!(Subspace::<T>::get_max_allowed_uids(netuid), 4096);
-
-    let mut seed : u32 = 1;
-    let block_number: u64 = Subspace::<T>::get_current_block_as_u64();
-    let key: T::AccountId = account("Alice", 0, seed);
-
-    let amoun_to_be_staked = Subspace::<T>::u64_to_balance( balance );
-    Subspace::<T>::add_balance_to_account(&key.clone(), amoun_to_be_staked.unwrap());
+    let keys: Vec<T::AccountId> = setup_network::<T>( netuid, 2, 0, 0 );
+    Subspace::<T>::set_commit_reveal_enabled( netuid, true );
+    let commit_hash: H256 = Subspace::<T>::hash_weights_commit( &keys[0], netuid, &vec![ 1 ], &vec![ 1 ], &vec![ 0 ] );
 
-  }: sudo_register(RawOrigin::<AccountIdOf<T>>::Root, netuid, key)
+  }: commit_weights( RawOrigin::Signed( keys[0].clone() ), netuid, commit_hash )
 
-  benchmark_sudo_add_network {
+  // Drops 'k' unrevealed commitments queued to expire at the same block.
+  expire_weight_commits {
+    let k in 1 .. MAX_N;
     let netuid: u16 = 1;
-    let tempo: u16 = 1;
-    
-
-  }: sudo_add_network(RawOrigin::<AccountIdOf<T>>::Root, netuid, tempo)
+    let keys: Vec<T::AccountId> = setup_network::<T>( netuid, k as u16, 0, 0 );
+    Subspace::<T>::set_commit_reveal_enabled( netuid, true );
+    for key in keys.iter() {
+      let commit_hash: H256 = Subspace::<T>::hash_weights_commit( key, netuid, &vec![ 0 ], &vec![ 1 ], &vec![ 0 ] );
+      assert_ok!( Subspace::<T>::do_commit_weights( RawOrigin::Signed( key.clone() ).into(), netuid, commit_hash ) );
+    }
+    let expiry_block: u64 = Subspace::<T>::get_current_block_as_u64() + Subspace::<T>::get_weights_reveal_window( netuid ) + 1;
+  }: { Subspace::<T>::expire_weight_commits( netuid, expiry_block ); }
 
-  benchmark_sudo_remove_network {
+  reveal_weights {
+    let w in 1 .. MAX_N - 1;
     let netuid: u16 = 1;
-    let tempo: u16 = 0;
-    
+    let keys: Vec<T::AccountId> = setup_network::<T>( netuid, w as u16 + 1, 0, 0 );
+    Subspace::<T>::set_commit_reveal_enabled( netuid, true );
+    let dests: Vec<u16> = (1..=w as u16).collect();
+    let weights: Vec<u16> = vec![ 1; w as usize ];
+    let salt: Vec<u8> = vec![ 0; 32 ];
+    let commit_hash: H256 = Subspace::<T>::hash_weights_commit( &keys[0], netuid, &dests, &weights, &salt );
+    assert_ok!( Subspace::<T>::do_commit_weights( RawOrigin::Signed( keys[0].clone() ).into(), netuid, commit_hash ) );
 
-    assert_ok!( Subspace::<T>::do_add_network( RawOrigin::Root.into(), netuid.try_into().unwrap(), tempo.into()));
+  }: reveal_weights( RawOrigin::Signed( keys[0].clone() ), netuid, dests, weights, salt, 0 )
 
-  }: sudo_remove_network(RawOrigin::<AccountIdOf<T>>::Root, netuid)
+  // =================
+  // ==== Staking ====
+  // =================
 
-  benchmark_sudo_set_serving_rate_limit {
-    let serving_rate_limit: u64 = 100;
+  add_stake {
     let netuid: u16 = 1;
+    let keys: Vec<T::AccountId> = setup_network::<T>( netuid, 1, 0, 0 );
+    fund_account::<T>( &keys[0] );
 
-  }: sudo_set_serving_rate_limit(RawOrigin::<AccountIdOf<T>>::Root, netuid, serving_rate_limit)
-
-
+  }: add_stake( RawOrigin::Signed( keys[0].clone() ), 1_000_000 )
 
-    assert_ok!( Subspace::<T>::do_add_network( RawOrigin::Root.into(), netuid.try_into().unwrap(), tempo.into()));
+  remove_stake {
+    let netuid: u16 = 1;
+    let keys: Vec<T::AccountId> = setup_network::<T>( netuid, 1, 0, 0 );
+    fund_account::<T>( &keys[0] );
+    assert_ok!( Subspace::<T>::do_add_stake( RawOrigin::Signed( keys[0].clone() ).into(), 1_000_000 ) );
 
-  }: 
+  }: remove_stake( RawOrigin::Signed( keys[0].clone() ), keys[0].clone(), 1_000_000 )
 
+  // ==================================
+  // ==== Serving and registration ====
+  // ==================================
 
-  benchmark_sudo_set_weights_set_rate_limit {
-    let netuid: u16 = 1; 
-    let weights_set_rate_limit: u64 = 3;
-    let tempo: u16 = 1;
-    
+  serve_module {
+    let e in 0 .. T::MaxModuleEndpoints::get();
+    let g in 0 .. T::MaxModuleTags::get();
+    let netuid: u16 = 1;
+    let keys: Vec<T::AccountId> = setup_network::<T>( netuid, 1, 0, 0 );
+    // The previously served tags are all dropped from the index.
+    let old_tags = Subspace::<T>::validate_tags( make_tags( b"old-", g ) ).unwrap();
+    Subspace::<T>::set_module_tags( netuid, &keys[0], old_tags );
+    let endpoint = ModuleEndpoint {
+      transport: Transport::Tcp,
+      ip_type: 4,
+      ip: 1676056785,
+      port: 128,
+      hostname: Some( BoundedVec::try_from( b"module.commune.example".to_vec() ).unwrap() ),
+    };
+    let endpoints: Vec<ModuleEndpoint> = vec![ endpoint; e as usize ];
+
+  }: serve_module( RawOrigin::Signed( keys[0].clone() ), netuid, b"module".to_vec(), endpoints, b"http://module.commune.example".to_vec(), make_tags( b"new-", g ) )
+
+  register {
+    let netuid: u16 = 1;
+    setup_network::<T>( netuid, MAX_N as u16 - 1, 0, 0 );
+    Subspace::<T>::set_max_registrations_per_block( netuid, u16::MAX );
+    let key: T::AccountId = whitelisted_caller::<AccountIdOf<T>>();
+    fund_account::<T>( &key );
 
-    assert_ok!( Subspace::<T>::do_add_network( RawOrigin::Root.into(), netuid.try_into().unwrap(), tempo.into()));
+    // The call itself is disabled, the registration path is measured directly.
+  }: { Subspace::<T>::do_registration( RawOrigin::Signed( key ).into(), netuid )? }
 
-  }: sudo_set_weights_set_rate_limit(RawOrigin::<AccountIdOf<T>>::Root, netuid, weights_set_rate_limit)
+  // ====================
+  // ==== Sudo calls ====
+  // ====================
 
+  sudo_set_serving_rate_limit {
+    let netuid: u16 = 1;
+    Subspace::<T>::init_new_network( netuid, 1 );
+  }: sudo_set_serving_rate_limit( RawOrigin::<AccountIdOf<T>>::Root, netuid, 100 )
 
-  benchmark_sudo_set_bonds_moving_average {
+  sudo_set_allow_private_ips {
     let netuid: u16 = 1;
-    let bonds_moving_average: u64 = 100;
-    let tempo: u16 = 1;
-    
+    Subspace::<T>::init_new_network( netuid, 1 );
+  }: sudo_set_allow_private_ips( RawOrigin::<AccountIdOf<T>>::Root, netuid, true )
 
-    assert_ok!( Subspace::<T>::do_add_network( RawOrigin::Root.into(), netuid.try_into().unwrap(), tempo.into()));
+  sudo_set_tx_rate_limit {
+  }: sudo_set_tx_rate_limit( RawOrigin::<AccountIdOf<T>>::Root, 100 )
 
-  }: sudo_set_bonds_moving_average(RawOrigin::<AccountIdOf<T>>::Root, netuid, bonds_moving_average)
+  sudo_set_treasury_cut {
+  }: sudo_set_treasury_cut( RawOrigin::<AccountIdOf<T>>::Root, 10 )
 
-  benchmark_sudo_set_max_allowed_validators {
+  sudo_set_weights_set_rate_limit {
     let netuid: u16 = 1;
-    let tempo: u16 = 1;
-    
-    let max_allowed_validators: u16 = 10;
-
-    assert_ok!( Subspace::<T>::do_add_network( RawOrigin::Root.into(), netuid.try_into().unwrap(), tempo.into()));
+    Subspace::<T>::init_new_network( netuid, 1 );
+  }: sudo_set_weights_set_rate_limit( RawOrigin::<AccountIdOf<T>>::Root, netuid, 3 )
 
-  }: sudo_set_max_allowed_validators(RawOrigin::<AccountIdOf<T>>::Root, netuid, max_allowed_validators)
+  sudo_set_weights_version_key {
+    let netuid: u16 = 1;
+    Subspace::<T>::init_new_network( netuid, 1 );
+  }: sudo_set_weights_version_key( RawOrigin::<AccountIdOf<T>>::Root, netuid, 1 )
 
+  sudo_set_weights_decay_half_life {
+    let netuid: u16 = 1;
+    Subspace::<T>::init_new_network( netuid, 1 );
+  }: sudo_set_weights_decay_half_life( RawOrigin::<AccountIdOf<T>>::Root, netuid, 100 )
 
-  benchmark_sudo_set_adjustment_interval {
+  sudo_set_inactive_prune_window {
     let netuid: u16 = 1;
-    let tempo: u16 = 1;
-    
-    let adjustment_interval: u16 = 12;
+    Subspace::<T>::init_new_network( netuid, 1 );
+  }: sudo_set_inactive_prune_window( RawOrigin::<AccountIdOf<T>>::Root, netuid, 100 )
 
-    assert_ok!( Subspace::<T>::do_add_network( RawOrigin::Root.into(), netuid.try_into().unwrap(), tempo.into()));
+  sudo_set_inactive_prune_min_stake {
+    let netuid: u16 = 1;
+    Subspace::<T>::init_new_network( netuid, 1 );
+  }: sudo_set_inactive_prune_min_stake( RawOrigin::<AccountIdOf<T>>::Root, netuid, 1_000_000_000 )
 
-  }: sudo_set_adjustment_interval(RawOrigin::<AccountIdOf<T>>::Root, netuid, adjustment_interval)
+  sudo_set_bonds_moving_average {
+    let netuid: u16 = 1;
+    Subspace::<T>::init_new_network( netuid, 1 );
+  }: sudo_set_bonds_moving_average( RawOrigin::<AccountIdOf<T>>::Root, netuid, 100 )
 
-  benchmark_sudo_set_target_registrations_per_interval {
+  sudo_set_max_allowed_validators {
     let netuid: u16 = 1;
-    let tempo: u16 = 1;
-    
-    let target_registrations_per_interval: u16 = 300;
+    Subspace::<T>::init_new_network( netuid, 1 );
+  }: sudo_set_max_allowed_validators( RawOrigin::<AccountIdOf<T>>::Root, netuid, 10 )
 
-    assert_ok!( Subspace::<T>::do_add_network( RawOrigin::Root.into(), netuid.try_into().unwrap(), tempo.into()));
+  sudo_set_difficulty {
+    let netuid: u16 = 1;
+    Subspace::<T>::init_new_network( netuid, 1 );
+  }: sudo_set_difficulty( RawOrigin::<AccountIdOf<T>>::Root, netuid, 1 )
 
-  }: sudo_set_target_registrations_per_interval(RawOrigin::<AccountIdOf<T>>::Root, netuid, target_registrations_per_interval)
+  sudo_set_adjustment_interval {
+    let netuid: u16 = 1;
+    Subspace::<T>::init_new_network( netuid, 1 );
+  }: sudo_set_adjustment_interval( RawOrigin::<AccountIdOf<T>>::Root, netuid, 12 )
 
-  benchmark_sudo_set_activity_cutoff {
+  sudo_set_target_registrations_per_interval {
     let netuid: u16 = 1;
-    let tempo: u16 = 1;
-    
-    let activity_cutoff: u16 = 300;
+    Subspace::<T>::init_new_network( netuid, 1 );
+  }: sudo_set_target_registrations_per_interval( RawOrigin::<AccountIdOf<T>>::Root, netuid, 300 )
 
-    assert_ok!( Subspace::<T>::do_add_network( RawOrigin::Root.into(), netuid.try_into().unwrap(), tempo.into()));
+  sudo_set_activity_cutoff {
+    let netuid: u16 = 1;
+    Subspace::<T>::init_new_network( netuid, 1 );
+  }: sudo_set_activity_cutoff( RawOrigin::<AccountIdOf<T>>::Root, netuid, 300 )
 
-  }: sudo_set_activity_cutoff(RawOrigin::<AccountIdOf<T>>::Root, netuid, activity_cutoff)
+  sudo_set_max_allowed_uids {
+    let netuid: u16 = 1;
+    Subspace::<T>::init_new_network( netuid, 1 );
+  }: sudo_set_max_allowed_uids( RawOrigin::<AccountIdOf<T>>::Root, netuid, 4096 )
 
+  sudo_set_min_allowed_weights {
+    let netuid: u16 = 1;
+    Subspace::<T>::init_new_network( netuid, 1 );
+  }: sudo_set_min_allowed_weights( RawOrigin::<AccountIdOf<T>>::Root, netuid, 10 )
 
-  benchmark_sudo_set_max_allowed_uids {
+  sudo_set_immunity_period {
     let netuid: u16 = 1;
-    let tempo: u16 = 1;
-    
-    let max_allowed_uids: u16 = 4096;
+    Subspace::<T>::init_new_network( netuid, 1 );
+  }: sudo_set_immunity_period( RawOrigin::<AccountIdOf<T>>::Root, netuid, 100 )
 
-    assert_ok!( Subspace::<T>::do_add_network( RawOrigin::Root.into(), netuid.try_into().unwrap(), tempo.into()));
+  sudo_set_max_weight_limit {
+    let netuid: u16 = 1;
+    Subspace::<T>::init_new_network( netuid, 1 );
+  }: sudo_set_max_weight_limit( RawOrigin::<AccountIdOf<T>>::Root, netuid, 100 )
 
-  }: sudo_set_max_allowed_uids(RawOrigin::<AccountIdOf<T>>::Root, netuid, max_allowed_uids)
+  sudo_set_max_registrations_per_block {
+    let netuid: u16 = 1;
+    Subspace::<T>::init_new_network( netuid, 1 );
+  }: sudo_set_max_registrations_per_block( RawOrigin::<AccountIdOf<T>>::Root, netuid, 100 )
 
-  benchmark_sudo_set_min_allowed_weights {
+  sudo_set_rho {
     let netuid: u16 = 1;
-    let tempo: u16 = 1;
-    
-    let min_allowed_weights: u16 = 10;
+    Subspace::<T>::init_new_network( netuid, 1 );
+  }: sudo_set_rho( RawOrigin::<AccountIdOf<T>>::Root, netuid, 10 )
 
-    assert_ok!( Subspace::<T>::do_add_network( RawOrigin::Root.into(), netuid.try_into().unwrap(), tempo.into()));
+  sudo_set_kappa {
+    let netuid: u16 = 1;
+    Subspace::<T>::init_new_network( netuid, 1 );
+  }: sudo_set_kappa( RawOrigin::<AccountIdOf<T>>::Root, netuid, 32_767 )
 
-  }: sudo_set_min_allowed_weights(RawOrigin::<AccountIdOf<T>>::Root, netuid, min_allowed_weights)
+  sudo_set_incentive_ratio {
+    let netuid: u16 = 1;
+    Subspace::<T>::init_new_network( netuid, 1 );
+  }: sudo_set_incentive_ratio( RawOrigin::<AccountIdOf<T>>::Root, netuid, 50 )
 
+  sudo_set_consensus_mechanism {
+    let netuid: u16 = 1;
+    Subspace::<T>::init_new_network( netuid, 1 );
+  }: sudo_set_consensus_mechanism( RawOrigin::<AccountIdOf<T>>::Root, netuid, ConsensusMechanismKind::StakeWeighted )
 
-  benchmark_sudo_set_immunity_period {
+  sudo_schedule_hyperparameter_change {
     let netuid: u16 = 1;
-    let tempo: u16 = 1;
-    
-    let immunity_period: u16 = 100;
+    Subspace::<T>::init_new_network( netuid, 1 );
+  }: sudo_schedule_hyperparameter_change( RawOrigin::<AccountIdOf<T>>::Root, netuid, HyperparameterChange::Kappa( 32_767 ), 0 )
 
-    assert_ok!( Subspace::<T>::do_add_network( RawOrigin::Root.into(), netuid.try_into().unwrap(), tempo.into()));
+  sudo_cancel_hyperparameter_change {
+    let netuid: u16 = 1;
+    Subspace::<T>::init_new_network( netuid, 1 );
+    assert_ok!( Subspace::<T>::do_sudo_schedule_hyperparameter_change( RawOrigin::Root.into(), netuid, HyperparameterChange::Kappa( 32_767 ), 0 ) );
+  }: sudo_cancel_hyperparameter_change( RawOrigin::<AccountIdOf<T>>::Root, netuid, 0 )
 
-  }: sudo_set_immunity_period(RawOrigin::<AccountIdOf<T>>::Root, netuid, immunity_period)
+  sudo_set_commit_reveal_enabled {
+    let netuid: u16 = 1;
+    Subspace::<T>::init_new_network( netuid, 1 );
+  }: sudo_set_commit_reveal_enabled( RawOrigin::<AccountIdOf<T>>::Root, netuid, true )
 
-  benchmark_sudo_set_max_weight_limit {
+  sudo_set_weights_reveal_window {
     let netuid: u16 = 1;
-    let tempo: u16 = 1;
-    
-    let max_weight_limit: u16 = 100;
+    Subspace::<T>::init_new_network( netuid, 1 );
+  }: sudo_set_weights_reveal_window( RawOrigin::<AccountIdOf<T>>::Root, netuid, 100 )
 
-    assert_ok!( Subspace::<T>::do_add_network( RawOrigin::Root.into(), netuid.try_into().unwrap(), tempo.into()));
+  // ====================
+  // ==== Block step ====
+  // ====================
 
-  }: sudo_set_max_weight_limit(RawOrigin::<AccountIdOf<T>>::Root, netuid, max_weight_limit)
+  // Minting the treasury cut of the block emission, done once per block.
+  pay_treasury {
+    Subspace::<T>::set_treasury_cut( 10 );
+    let treasury_emission: u64 = Subspace::<T>::get_treasury_emission( 1_000_000_000 );
+  }: { Subspace::<T>::pay_treasury( treasury_emission ); }
 
-  benchmark_sudo_set_max_registrations_per_block {
+  // The work done for a network on a block where its epoch is not due.
+  block_step_network {
     let netuid: u16 = 1;
-    let tempo: u16 = 1;
-    
-    let max_registrations_per_block: u16 = 100;
+    setup_network::<T>( netuid, 1, 0, 0 );
+    let block_number: u64 = Subspace::<T>::get_current_block_as_u64();
+    assert!( Subspace::<T>::blocks_until_next_epoch( netuid, u16::MAX, block_number ) != 0 );
+  }: {
+    Subspace::<T>::adjust_registration_terms_for_networks();
+    Subspace::<T>::generate_emission( block_number );
+  }
+
+  // Applies 'q' changes queued for the next epoch, each one checked against the current bounds.
+  apply_hyperparameter_changes {
+    let q in 1 .. T::MaxScheduledChanges::get();
+    let netuid: u16 = 1;
+    setup_network::<T>( netuid, 2, 0, 0 );
+    for _ in 0..q {
+      assert_ok!( Subspace::<T>::do_sudo_schedule_hyperparameter_change( RawOrigin::Root.into(), netuid, HyperparameterChange::MaxAllowedUids( 2 ), 0 ) );
+    }
+    let block_number: u64 = Subspace::<T>::get_current_block_as_u64();
+  }: { Subspace::<T>::apply_hyperparameter_changes( netuid, block_number, true ); }
 
-    assert_ok!( Subspace::<T>::do_add_network( RawOrigin::Root.into(), netuid.try_into().unwrap(), tempo.into()));
+  drain_emission {
+    let t in 1 .. MAX_N;
+    let netuid: u16 = 1;
+    let keys: Vec<T::AccountId> = setup_network::<T>( netuid, t as u16, 0, 0 );
+    Subspace::<T>::set_tempo( netuid, 1 ); // Every tuple is due on the next block.
+    Subspace::<T>::load_emission_tuples( netuid, keys.into_iter().map( |key| ( key, 1_000 ) ).collect() );
+  }: { Subspace::<T>::drain_emission( 0 ); }
+
+  // Deregistering the first uid of 'n', every weights and bonds row is re-pointed at the moved uid.
+  remove_module {
+    let n in 2 .. MAX_N;
+    let netuid: u16 = 1;
+    // Every row points at both the removed and the moved uid, so the remap rewrites all of them.
+    setup_network::<T>( netuid, n as u16, n as u16, n as u16 );
+    for uid_i in 0..n as u16 {
+      Bonds::<T>::insert( netuid, uid_i, Weights::<T>::get( netuid, uid_i ) );
+    }
+  }: { Subspace::<T>::remove_module( netuid, 0 ); }
 
-  }: sudo_set_max_registrations_per_block(RawOrigin::<AccountIdOf<T>>::Root, netuid, max_registrations_per_block)
+  start_epoch {
+    let n in 1 .. MAX_N;
+    let netuid: u16 = 1;
+    setup_network::<T>( netuid, n as u16, 0, 0 );
+  }: { Subspace::<T>::start_epoch( netuid, 1_000_000_000 ); }
 
+  // Loads 'r' rows holding 'w' weight entries in total.
+  epoch_load {
+    let r in 1 .. MAX_N;
+    let w in 0 .. 256 * MAX_N;
+    let netuid: u16 = 1;
+    setup_network::<T>( netuid, MAX_N as u16, r as u16, ( w / r ).min( MAX_N - 1 ) as u16 );
+    let mut state: EpochState<T> = Subspace::<T>::begin_epoch( netuid, 1_000_000_000 );
+  }: { Subspace::<T>::run_epoch_phase( netuid, &mut state, r as u16 ); }
+
+  // Computes the consensus of 'c' columns holding 'w' weight entries in total.
+  epoch_consensus {
+    let c in 1 .. MAX_N;
+    let w in 0 .. 256 * MAX_N;
+    let netuid: u16 = 1;
+    setup_network::<T>( netuid, MAX_N as u16, MAX_N as u16, ( w / MAX_N ).min( MAX_N - 1 ) as u16 );
+    let mut state: EpochState<T> = Subspace::<T>::begin_epoch( netuid, 1_000_000_000 );
+    while state.phase != EpochPhase::Consensus { Subspace::<T>::run_epoch_phase( netuid, &mut state, u16::MAX ); }
+  }: { Subspace::<T>::run_epoch_phase( netuid, &mut state, c as u16 ); }
+
+  // Sums 'r' clipped weights rows holding 'w' entries in total into the ranks.
+  epoch_rank {
+    let r in 1 .. MAX_N;
+    let w in 0 .. 256 * MAX_N;
+    let netuid: u16 = 1;
+    setup_network::<T>( netuid, MAX_N as u16, r as u16, ( w / r ).min( MAX_N - 1 ) as u16 );
+    let mut state: EpochState<T> = Subspace::<T>::begin_epoch( netuid, 1_000_000_000 );
+    while state.phase != EpochPhase::Rank { Subspace::<T>::run_epoch_phase( netuid, &mut state, u16::MAX ); }
+  }: { Subspace::<T>::run_epoch_phase( netuid, &mut state, r as u16 ); }
+
+  // Computes the bonds moving average of 'r' rows holding 'w' weight entries in total.
+  epoch_ema {
+    let r in 1 .. MAX_N;
+    let w in 0 .. 256 * MAX_N;
+    let netuid: u16 = 1;
+    setup_network::<T>( netuid, MAX_N as u16, r as u16, ( w / r ).min( MAX_N - 1 ) as u16 );
+    let mut state: EpochState<T> = Subspace::<T>::begin_epoch( netuid, 1_000_000_000 );
+    while state.phase != EpochPhase::Ema { Subspace::<T>::run_epoch_phase( netuid, &mut state, u16::MAX ); }
+  }: { Subspace::<T>::run_epoch_phase( netuid, &mut state, r as u16 ); }
+
+  // Writes back 'r' bonds rows holding 'w' entries in total.
+  epoch_bonds {
+    let r in 1 .. MAX_N;
+    let w in 0 .. 256 * MAX_N;
+    let netuid: u16 = 1;
+    setup_network::<T>( netuid, MAX_N as u16, r as u16, ( w / r ).min( MAX_N - 1 ) as u16 );
+    let mut state: EpochState<T> = Subspace::<T>::begin_epoch( netuid, 1_000_000_000 );
+    while state.phase != EpochPhase::Bonds { Subspace::<T>::run_epoch_phase( netuid, &mut state, u16::MAX ); }
+  }: { Subspace::<T>::run_epoch_phase( netuid, &mut state, r as u16 ); }
 
+  epoch_emission {
+    let n in 1 .. MAX_N;
+    let netuid: u16 = 1;
+    setup_network::<T>( netuid, n as u16, n as u16, 1 );
+    let mut state: EpochState<T> = Subspace::<T>::begin_epoch( netuid, 1_000_000_000 );
+    while state.phase != EpochPhase::Emission { Subspace::<T>::run_epoch_phase( netuid, &mut state, u16::MAX ); }
+  }: { Subspace::<T>::run_epoch_phase( netuid, &mut state, 0 ); }
 
+  epoch_write_back {
+    let n in 1 .. MAX_N;
+    let netuid: u16 = 1;
+    setup_network::<T>( netuid, n as u16, n as u16, 1 );
+    let mut state: EpochState<T> = Subspace::<T>::begin_epoch( netuid, 1_000_000_000 );
+    while state.phase != EpochPhase::WriteBack { Subspace::<T>::run_epoch_phase( netuid, &mut state, u16::MAX ); }
+  }: { Subspace::<T>::run_epoch_phase( netuid, &mut state, 0 ); }
+
+  // A full epoch run in a single block, kept to compare against the sum of its phases.
+  benchmark_epoch_without_weights {
+    let caller: T::AccountId = whitelisted_caller::<AccountIdOf<T>>();
+    setup_network::<T>( 11, MAX_N as u16, 0, 0 );
+  }: _( RawOrigin::Signed( caller.clone() ) )
 
+  benchmark_epoch_with_weights {
+    let caller: T::AccountId = whitelisted_caller::<AccountIdOf<T>>();
+    setup_network::<T>( 11, MAX_N as u16, 100, 1000 );
+  }: _( RawOrigin::Signed( caller.clone() ) )
+}
@@ -2,20 +2,35 @@ use super::*;
 use substrate_fixed::types::I110F18;
 use substrate_fixed::types::I64F64;
 use frame_support::inherent::Vec;
+use frame_support::sp_std::vec;
 use frame_support::storage::IterableStorageMap;
 use frame_support::storage::IterableStorageDoubleMap;
+use frame_support::weights::Weight;
+use frame_support::traits::Imbalance;
+use frame_support::pallet_prelude::{Decode, Encode, Get};
+use scale_info::TypeInfo;
+
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeyEmissionInfo {
+    pub lifetime_emission: u64,
+    pub history: Vec<(u64, u64)>, // ( block, amount ) of the last payouts, oldest first.
+}
 
 impl<T: Config> Pallet<T> { 
 
-    pub fn block_step() {
+    // Runs the per-block work of every network and returns the weight consumed.
+    //
+    pub fn block_step() -> Weight {
         let block_number: u64 = Self::get_current_block_as_u64();
         log::debug!("block_step for block: {:?} ", block_number );
         // --- 1. Adjust difficulties.
 		Self::adjust_registration_terms_for_networks( );
         // --- 2. Drains emission tuples ( key, amount ).
-        Self::drain_emission( block_number );
+        let drain_weight: Weight = Self::drain_emission( block_number );
         // --- 3. Generates emission tuples from epoch functions.
-		Self::generate_emission( block_number );
+		let generate_weight: Weight = Self::generate_emission( block_number );
+        drain_weight.saturating_add( generate_weight )
     }
 
     // Helper function which returns the number of blocks remaining before we will run the epoch on this
@@ -42,9 +57,10 @@ impl<T: Config> Pallet<T> {
         if blocks_until_epoch / 2 == 0 { return n_remaining } // drain all.
         if tempo / 2 == 0 { return n_remaining } // drain all
         if n_remaining == 0 { return 0 } // nothing to drain at all.
-        // Else return enough tuples to drain all within half the epoch length.
-        let to_sink_via_tempo: usize = n_remaining / (tempo as usize / 2);
-        let to_sink_via_blocks_until_epoch: usize = n_remaining / (blocks_until_epoch as usize / 2);
+        // Else return enough tuples to drain all within half the epoch length, rounded up so that at least
+        // one tuple is paid every block.
+        let to_sink_via_tempo: usize = ( n_remaining + tempo as usize / 2 - 1 ) / (tempo as usize / 2);
+        let to_sink_via_blocks_until_epoch: usize = ( n_remaining + blocks_until_epoch as usize / 2 - 1 ) / (blocks_until_epoch as usize / 2);
         if to_sink_via_tempo > to_sink_via_blocks_until_epoch {
             return to_sink_via_tempo;   
         } else {
@@ -53,82 +69,242 @@ impl<T: Config> Pallet<T> {
     }
 
     pub fn has_loaded_emission_tuples( netuid: u16 ) -> bool { LoadedEmission::<T>::contains_key( netuid ) }
-    pub fn get_loaded_emission_tuples( netuid: u16 ) -> Vec<(T::AccountId, u64)> { LoadedEmission::<T>::get( netuid ).unwrap() }
+    pub fn get_loaded_emission_cursor( netuid: u16 ) -> u32 { LoadedEmissionCursor::<T>::get( netuid ) }
+    pub fn get_loaded_emission_tuples( netuid: u16 ) -> Vec<(T::AccountId, u64)> { LoadedEmission::<T>::get( netuid ).unwrap().into_inner() }
 
     // Reads from the loaded emission storage which contains lists of pending emission tuples ( key, amount )
-    // and distributes small chunks of them at a time.
+    // and distributes small chunks of them at a time, so that the payouts are spread across the tempo.
+    // The network cursor points at the next tuple to pay. Returns the weight consumed.
     //
-    pub fn drain_emission( _: u64 ) {
+    pub fn drain_emission( block_number: u64 ) -> Weight {
+        let mut weight: Weight = Weight::zero();
         // --- 1. We iterate across each network.
-        for ( netuid, _ ) in <Tempo<T> as IterableStorageMap<u16, u16>>::iter() {
+        for ( netuid, tempo ) in <Tempo<T> as IterableStorageMap<u16, u16>>::iter() {
             if !Self::has_loaded_emission_tuples( netuid ) { continue } // There are no tuples to emit.
-            let tuples_to_drain: Vec<(T::AccountId, u64)> = Self::get_loaded_emission_tuples( netuid );
-            for (key, amount) in tuples_to_drain.iter() {                 
-                Self::emit_inflation_through_account( &key, *amount );
-            }            
-            LoadedEmission::<T>::remove( netuid );
+            let loaded_tuples: Vec<(T::AccountId, u64)> = Self::get_loaded_emission_tuples( netuid );
+
+            // --- 2. Pay the share of the remaining tuples due this block.
+            let cursor: usize = ( LoadedEmissionCursor::<T>::get( netuid ) as usize ).min( loaded_tuples.len() );
+            let n_remaining: usize = loaded_tuples.len() - cursor;
+            let end: usize = cursor + Self::tuples_to_drain_this_block( netuid, tempo, block_number, n_remaining ).min( n_remaining );
+            Self::pay_emission_tuples( netuid, &loaded_tuples[ cursor..end ], block_number );
+            weight = weight.saturating_add( T::WeightInfo::drain_emission( ( end - cursor ) as u32 ) );
+
+            // --- 3. Move the cursor, dropping the tuples once they are all paid.
+            if end >= loaded_tuples.len() {
+                LoadedEmission::<T>::remove( netuid );
+                LoadedEmissionCursor::<T>::remove( netuid );
+            } else {
+                LoadedEmissionCursor::<T>::insert( netuid, end as u32 );
+            }
         }
+        weight
     }
 
     // Iterates through networks queues more emission onto their pending storage.
-    // If a network has no blocks left until tempo, we run the epoch function and generate
-    // more token emission tuples for later draining onto accounts.
+    // If a network has no blocks left until tempo, we start its epoch. Epochs in progress are then advanced
+    // within the per-block epoch budget and generate more token emission tuples for later draining onto accounts.
+    // Returns the weight consumed. The per-network step weight also covers the adjustment of the registration terms
+    // which block_step runs beforehand.
     //
-    pub fn generate_emission( block_number: u64 ) {
+    pub fn generate_emission( block_number: u64 ) -> Weight {
+
+        // The epoch work all networks may do this block.
+        let epoch_budget: u64 = T::EpochWeightPerBlock::get().ref_time();
+        let mut epoch_used: u64 = 0;
+        let mut weight: Weight = Weight::zero();
+
+        // The emission released this block by the schedule. The treasury takes its cut first, the rest is shared
+        // between networks in proportion of their emission values.
+        let block_emission: u64 = Self::get_scheduled_block_emission( Self::get_total_issuance() );
+        BlockEmission::<T>::put( block_emission );
+        let treasury_emission: u64 = Self::get_treasury_emission( block_emission );
+        let treasury_paid: u64 = Self::pay_treasury( treasury_emission );
+        weight = weight.saturating_add( T::WeightInfo::pay_treasury() );
+        let network_emission: u64 = block_emission - treasury_emission;
+        let total_emission_values: u64 = <EmissionValues<T> as IterableStorageMap<u16, u64>>::iter_values().fold( 0, |sum, value| sum.saturating_add( value ) );
+        // A treasury cut which could not be minted is not released.
+        let mut released_emission: u64 = treasury_paid;
 
         // --- 1. Iterate through network ids.
         for ( netuid, tempo )  in <Tempo<T> as IterableStorageMap<u16, u16>>::iter() {
+            weight = weight.saturating_add( T::WeightInfo::block_step_network() );
+
+            // --- 1.1 Apply hyperparameter changes scheduled for this block.
+            weight = weight.saturating_add( Self::apply_hyperparameter_changes( netuid, block_number, false ) );
+
+            // --- 1.2 Drop weight commitments which can no longer be revealed.
+            weight = weight.saturating_add( Self::expire_weight_commits( netuid, block_number ) );
 
             // --- 2. Queue the emission due to this network.
-            let new_queued_emission = EmissionValues::<T>::get( netuid );
+            let new_queued_emission: u64 = Self::get_network_block_emission( netuid, network_emission, total_emission_values );
             PendingEmission::<T>::mutate( netuid, | queued | *queued += new_queued_emission );
+            released_emission = released_emission.saturating_add( new_queued_emission );
             log::debug!("netuid_i: {:?} queued_emission: +{:?} ", netuid, new_queued_emission );  
             // --- 3. Check to see if this network has reached tempo.
             if Self::blocks_until_next_epoch( netuid, tempo, block_number ) != 0 {
-                // --- 3.1 No epoch, increase blocks since last step and continue,
+                // --- 3.1 No epoch, increase blocks since last step.
                 Self::set_blocks_since_last_step( netuid, Self::get_blocks_since_last_step( netuid ) + 1 );
-                continue;
+            } else {
+                // --- 4 This network is at tempo and we are starting its epoch.
+                // Apply the changes queued for this epoch boundary so the epoch sees them all at once.
+                weight = weight.saturating_add( Self::apply_hyperparameter_changes( netuid, block_number, true ) );
+
+                // The previous epoch must be complete before the next one starts. This work is charged
+                // regardless of the budget.
+                weight = weight.saturating_add( Weight::from_ref_time( Self::finish_epoch( netuid ) ) );
+
+                // Shrink the network and deregister the modules still flagged by the previous epoch before the snapshot
+                // of the next one, within what is left of the budget. The others wait for the end of the next epoch.
+                epoch_used = epoch_used.saturating_add( Self::prune_modules( netuid, block_number, epoch_budget.saturating_sub( epoch_used ) ) );
+
+                // First frain the queued emission.
+                let emission_to_drain:u64 = PendingEmission::<T>::get( netuid ); 
+                PendingEmission::<T>::insert( netuid, 0 );
+
+                // --- 5. Start the epoch mechanism, its phases are advanced below and over the next blocks.
+                Self::start_epoch( netuid, emission_to_drain );
+                weight = weight.saturating_add( T::WeightInfo::start_epoch( Self::get_network_n( netuid ) as u32 ) );
+
+                // --- 6 Set counters.
+                Self::set_blocks_since_last_step( netuid, 0 );
+                Self::set_last_mechanism_step_block( netuid, block_number );
             }
 
-            // --- 4 This network is at tempo and we are running its epoch.
-            // First frain the queued emission.
-            let emission_to_drain:u64 = PendingEmission::<T>::get( netuid ); 
-            PendingEmission::<T>::insert( netuid, 0 );
-
-            // --- 5. Run the epoch mechanism and return emission tuples for keys in the network.
-            let emission_tuples_this_block: Vec<(T::AccountId, u64)> = Self::epoch( netuid, emission_to_drain );
-                
-            // --- 6. Check that the emission does not exceed the allowed total.
-            let emission_sum: u128 = emission_tuples_this_block.iter().map( |(_account_id, e)| *e as u128 ).sum();
-            if emission_sum > emission_to_drain as u128 { continue } // Saftey check.
-
-            // --- 7. Sink the emission tuples onto the already loaded.
-            let mut concat_emission_tuples: Vec<(T::AccountId, u64)> = emission_tuples_this_block.clone();
-            if Self::has_loaded_emission_tuples( netuid ) {
-                // 7.a We already have loaded emission tuples, so we concat the new ones.
-                let mut current_emission_tuples: Vec<(T::AccountId, u64)> = Self::get_loaded_emission_tuples( netuid );
-                concat_emission_tuples.append( &mut current_emission_tuples );
-            } 
-            LoadedEmission::<T>::insert( netuid, concat_emission_tuples );
-
-            // --- 8 Set counters.
-            Self::set_blocks_since_last_step( netuid, 0 );
-            Self::set_last_mechanism_step_block( netuid, block_number );        
+            // --- 7. Advance the epoch in progress with what is left of the budget. Once complete its emission
+            // tuples are loaded for draining. The epoch state read each block is charged at its bound.
+            if Self::is_epoch_in_progress( netuid ) {
+                weight = weight.saturating_add( Self::get_epoch_state_proof_weight() );
+            }
+            epoch_used = epoch_used.saturating_add( Self::advance_epoch( netuid, epoch_budget.saturating_sub( epoch_used ) ) );
+
+            // --- 8. Between epochs, shrink the network down to its max allowed uids and deregister the modules flagged
+            // by the last one with what is left of the budget.
+            if !Self::is_epoch_in_progress( netuid ) {
+                epoch_used = epoch_used.saturating_add( Self::prune_modules( netuid, block_number, epoch_budget.saturating_sub( epoch_used ) ) );
+            }
+        }
+
+        // --- 9. The released emission counts towards the issuance as soon as it is queued, so the emission
+        // waiting to be paid out can never push the issuance past the max supply.
+        TotalIssuance::<T>::put( Self::get_total_issuance().saturating_add( released_emission ) );
+
+        weight.saturating_add( Weight::from_ref_time( epoch_used ) )
+    }
+
+    // Returns the part of the block emission diverted to the treasury account.
+    //
+    pub fn get_treasury_emission( block_emission: u64 ) -> u64 {
+        let cut: u64 = ( Self::get_treasury_cut() as u64 ).min( 100 );
+        ( block_emission as u128 * cut as u128 / 100 ) as u64
+    }
+
+    // Mints the treasury emission into the free balance of the treasury account and returns the amount minted.
+    // An emission below the existential deposit cannot create the treasury account and mints nothing.
+    //
+    pub fn pay_treasury( treasury_emission: u64 ) -> u64 {
+        if treasury_emission == 0 { return 0 }
+        let amount = match Self::u64_to_balance( treasury_emission ) {
+            Some( amount ) => amount,
+            None => return 0
+        };
+        if T::Currency::deposit_creating( &T::TreasuryAccount::get(), amount ).peek() != amount { return 0 }
+        log::debug!("treasury emission: +{:?} ", treasury_emission );
+        treasury_emission
+    }
+
+    // Returns the share of the block emission due to the network, in proportion of its emission value.
+    //
+    pub fn get_network_block_emission( netuid: u16, block_emission: u64, total_emission_values: u64 ) -> u64 {
+        if total_emission_values == 0 { return 0 }
+        let share: u128 = EmissionValues::<T>::get( netuid ) as u128 * block_emission as u128 / total_emission_values as u128;
+        share as u64
+    }
+
+    // Returns the number of halvings the emission went through at 'issuance'.
+    //
+    pub fn get_halvings( issuance: u64 ) -> u32 { get_halvings( issuance, T::MaxSupply::get() ) }
+
+    // Returns the emission of a block at 'issuance'.
+    //
+    pub fn get_scheduled_block_emission( issuance: u64 ) -> u64 {
+        get_scheduled_block_emission( issuance, T::MaxSupply::get(), T::InitialBlockEmission::get() )
+    }
+
+    // Returns the issuance projected at 'block_number', assuming the scheduled emission is released on every
+    // block from now on. Blocks in the past return the current issuance.
+    //
+    pub fn get_projected_issuance( block_number: u64 ) -> u64 {
+        let blocks: u64 = block_number.saturating_sub( Self::get_current_block_as_u64() );
+        get_projected_issuance( Self::get_total_issuance(), blocks, T::MaxSupply::get(), T::InitialBlockEmission::get() )
+    }
+
+    // Sinks the emission tuples of an epoch behind the loaded ones which are still unpaid. The loaded tuples are
+    // bounded by the max uids, the oldest unpaid tuples which no longer fit are paid out right away.
+    //
+    pub fn load_emission_tuples( netuid: u16, emission_tuples: Vec<(T::AccountId, u64)> ) {
+        let mut concat_emission_tuples: Vec<(T::AccountId, u64)> = vec![];
+        if Self::has_loaded_emission_tuples( netuid ) {
+            // We already have loaded emission tuples, the paid ones are dropped and the new ones go after the rest.
+            let cursor: usize = LoadedEmissionCursor::<T>::take( netuid ) as usize;
+            concat_emission_tuples = Self::get_loaded_emission_tuples( netuid ).into_iter().skip( cursor ).collect();
+        } 
+        let room: usize = ( MaxUidsOf::<T>::get() as usize ).saturating_sub( emission_tuples.len() );
+        if concat_emission_tuples.len() > room {
+            let overflow: Vec<(T::AccountId, u64)> = concat_emission_tuples.drain( ..concat_emission_tuples.len() - room ).collect();
+            Self::pay_emission_tuples( netuid, &overflow, Self::get_current_block_as_u64() );
+        }
+        concat_emission_tuples.extend( emission_tuples );
+        LoadedEmission::<T>::insert( netuid, Self::to_uid_vec( concat_emission_tuples ) );
+    }
+
+    // Pays the emission tuples onto their keys, records them in the key histories and emits EmissionPaid.
+    //
+    pub fn pay_emission_tuples( netuid: u16, tuples: &[(T::AccountId, u64)], block_number: u64 ) {
+        if tuples.is_empty() { return }
+        for (key, amount) in tuples.iter() {
+            Self::emit_inflation_through_account( &key, *amount );
+            Self::record_emission( netuid, &key, *amount, block_number );
         }
+        Self::deposit_event( Event::EmissionPaid( netuid, tuples.to_vec() ) );
     }
-    // Distributes token inflation through the key based on emission. The call ensures that the inflation
-    // is distributed onto the accounts in proportion of the stake  minus the take. This function
+
+    // Distributes token inflation through the key based on emission. The emission was already counted
+    // in the total issuance when the schedule released it, so only the stake counters move.
     //
     pub fn emit_inflation_through_account( key: &T::AccountId, emission: u64) {
         // --- 1.We simply increase the key's stake.
-        Self::increase_stake_on_account( &key, emission ); 
-            // --- 4. The emission proportion is remaining_emission * ( stake / total_stake ).
-        log::debug!(" key: {:?} emission: +{:?} ", key, stake_proportion );
+        Stake::<T>::insert( key, Stake::<T>::get( key ).saturating_add( emission ) );
+        TotalStake::<T>::put( TotalStake::<T>::get().saturating_add( emission ) );
+        log::debug!(" key: {:?} emission: +{:?} ", key, emission );
+    }
 
-        }
 
+    // Adds the payout to the lifetime emission of the key on this network and pushes it onto the key's history,
+    // dropping the oldest payout once the history holds MaxEmissionHistory entries.
+    //
+    pub fn record_emission( netuid: u16, key: &T::AccountId, emission: u64, block_number: u64 ) {
+        LifetimeEmission::<T>::mutate( netuid, key, | total | *total = total.saturating_add( emission ) );
+        let max_history: usize = T::MaxEmissionHistory::get() as usize;
+        if max_history == 0 { return }
+        EmissionHistory::<T>::mutate( netuid, key, | history | {
+            if history.len() >= max_history { history.remove( 0 ); }
+            let pushed: bool = history.try_push( ( block_number, emission ) ).is_ok();
+            debug_assert!( pushed, "the oldest payout was dropped, the history has room" );
+        });
     }
 
+    pub fn get_lifetime_emission( netuid: u16, key: &T::AccountId ) -> u64 { LifetimeEmission::<T>::get( netuid, key ) }
+    pub fn get_emission_history( netuid: u16, key: &T::AccountId ) -> Vec<(u64, u64)> { EmissionHistory::<T>::get( netuid, key ).into_inner() }
+
+    // Returns the emission record of the key on this network, for the runtime API.
+    //
+    pub fn get_key_emission( netuid: u16, key: &T::AccountId ) -> KeyEmissionInfo {
+        KeyEmissionInfo {
+            lifetime_emission: Self::get_lifetime_emission( netuid, key ),
+            history: Self::get_emission_history( netuid, key ),
+        }
+    }
 
     // Adjusts the network difficulties of every active network. Reseting state parameters.
     //
@@ -169,3 +345,106 @@ impl<T: Config> Pallet<T> {
 
 
 }
+
+// Returns the number of halvings the emission went through at 'issuance'. The k-th halving happens once the
+// issuance reaches max_supply * ( 1 - 1/2^k ), so every halving period releases half of the remaining supply.
+pub fn get_halvings( issuance: u64, max_supply: u64 ) -> u32 {
+    let mut halvings: u32 = 0;
+    while halvings < 64 && issuance >= max_supply - max_supply.checked_shr( halvings + 1 ).unwrap_or( 0 ) {
+        halvings += 1;
+    }
+    halvings
+}
+
+// Returns the emission of a block at 'issuance': the initial block emission halved at every halving and never
+// more than what is left below the max supply.
+pub fn get_scheduled_block_emission( issuance: u64, max_supply: u64, initial_block_emission: u64 ) -> u64 {
+    if issuance >= max_supply { return 0 }
+    let emission: u64 = initial_block_emission.checked_shr( get_halvings( issuance, max_supply ) ).unwrap_or( 0 );
+    emission.min( max_supply - issuance )
+}
+
+// Returns the issuance after 'blocks' more blocks of scheduled emission, starting at 'issuance'.
+pub fn get_projected_issuance( issuance: u64, blocks: u64, max_supply: u64, initial_block_emission: u64 ) -> u64 {
+    let mut issuance: u64 = issuance;
+    let mut blocks: u64 = blocks;
+    while blocks > 0 {
+        let emission: u64 = get_scheduled_block_emission( issuance, max_supply, initial_block_emission );
+        if emission == 0 { break } // The supply is exhausted.
+
+        // The emission stays constant until the next halving, so whole halving periods are skipped at once.
+        let next_halving: u64 = max_supply - max_supply.checked_shr( get_halvings( issuance, max_supply ) + 1 ).unwrap_or( 0 );
+        let blocks_to_halving: u64 = ( next_halving.saturating_sub( issuance ) + emission - 1 ) / emission;
+        let step: u64 = blocks.min( blocks_to_halving.max( 1 ) );
+        issuance = issuance.saturating_add( emission.saturating_mul( step ) ).min( max_supply );
+        blocks -= step;
+    }
+    issuance
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::block_step::{get_halvings, get_scheduled_block_emission, get_projected_issuance};
+
+    const MAX_SUPPLY: u64 = 1_000_000;
+    const INITIAL_BLOCK_EMISSION: u64 = 1_000;
+
+    // Releases the scheduled emission one block at a time.
+    fn simulate_issuance( issuance: u64, blocks: u64, max_supply: u64, initial_block_emission: u64 ) -> u64 {
+        (0..blocks).fold( issuance, |issuance, _| issuance + get_scheduled_block_emission( issuance, max_supply, initial_block_emission ) )
+    }
+
+    #[test]
+    fn test_halvings_boundaries() {
+        assert_eq!( get_halvings( 0, MAX_SUPPLY ), 0 );
+        assert_eq!( get_halvings( 499_999, MAX_SUPPLY ), 0 );
+        assert_eq!( get_halvings( 500_000, MAX_SUPPLY ), 1 );
+        assert_eq!( get_halvings( 749_999, MAX_SUPPLY ), 1 );
+        assert_eq!( get_halvings( 750_000, MAX_SUPPLY ), 2 );
+        assert_eq!( get_halvings( 875_000, MAX_SUPPLY ), 3 );
+        assert_eq!( get_halvings( MAX_SUPPLY, MAX_SUPPLY ), 64 );
+        assert_eq!( get_halvings( u64::MAX, u64::MAX ), 64 );
+    }
+
+    #[test]
+    fn test_scheduled_block_emission_halves() {
+        assert_eq!( get_scheduled_block_emission( 0, MAX_SUPPLY, INITIAL_BLOCK_EMISSION ), 1_000 );
+        assert_eq!( get_scheduled_block_emission( 499_999, MAX_SUPPLY, INITIAL_BLOCK_EMISSION ), 1_000 );
+        assert_eq!( get_scheduled_block_emission( 500_000, MAX_SUPPLY, INITIAL_BLOCK_EMISSION ), 500 );
+        assert_eq!( get_scheduled_block_emission( 750_000, MAX_SUPPLY, INITIAL_BLOCK_EMISSION ), 250 );
+        assert_eq!( get_scheduled_block_emission( 875_000, MAX_SUPPLY, INITIAL_BLOCK_EMISSION ), 125 );
+    }
+
+    #[test]
+    fn test_scheduled_block_emission_max_supply() {
+        assert_eq!( get_scheduled_block_emission( MAX_SUPPLY, MAX_SUPPLY, INITIAL_BLOCK_EMISSION ), 0 );
+        assert_eq!( get_scheduled_block_emission( MAX_SUPPLY + 1, MAX_SUPPLY, INITIAL_BLOCK_EMISSION ), 0 );
+        // The emission never takes the issuance past the max supply.
+        assert_eq!( get_scheduled_block_emission( 0, 1_500, 2_000 ), 1_500 );
+        assert_eq!( get_scheduled_block_emission( 1_400, 1_500, 2_000 ), 100 );
+        assert_eq!( get_projected_issuance( 0, 10, 1_500, 2_000 ), 1_500 );
+    }
+
+    #[test]
+    fn test_projected_issuance_halving_periods() {
+        // Every halving period lasts max_supply / ( 2 * initial_block_emission ) blocks.
+        assert_eq!( get_projected_issuance( 0, 0, MAX_SUPPLY, INITIAL_BLOCK_EMISSION ), 0 );
+        assert_eq!( get_projected_issuance( 0, 500, MAX_SUPPLY, INITIAL_BLOCK_EMISSION ), 500_000 );
+        assert_eq!( get_projected_issuance( 0, 1_000, MAX_SUPPLY, INITIAL_BLOCK_EMISSION ), 750_000 );
+        assert_eq!( get_projected_issuance( 0, 1_250, MAX_SUPPLY, INITIAL_BLOCK_EMISSION ), 812_500 );
+        assert_eq!( get_projected_issuance( 0, 1_500, MAX_SUPPLY, INITIAL_BLOCK_EMISSION ), 875_000 );
+        // A block straddling a halving is released at the emission before it.
+        assert_eq!( get_projected_issuance( 499_500, 2, MAX_SUPPLY, INITIAL_BLOCK_EMISSION ), 501_000 );
+    }
+
+    #[test]
+    fn test_projected_issuance_matches_block_by_block() {
+        for ( issuance, blocks ) in [ ( 0, 10_000 ), ( 123_456, 777 ), ( 499_999, 3 ), ( 999_000, 10_000 ) ] {
+            assert_eq!( get_projected_issuance( issuance, blocks, MAX_SUPPLY, INITIAL_BLOCK_EMISSION ), simulate_issuance( issuance, blocks, MAX_SUPPLY, INITIAL_BLOCK_EMISSION ) );
+        }
+        // Across every halving the supply is approached but never exceeded.
+        let issuance: u64 = get_projected_issuance( 0, u64::MAX, MAX_SUPPLY, INITIAL_BLOCK_EMISSION );
+        assert!( issuance <= MAX_SUPPLY );
+        assert_eq!( issuance, simulate_issuance( 0, 20_000, MAX_SUPPLY, INITIAL_BLOCK_EMISSION ) );
+    }
+}
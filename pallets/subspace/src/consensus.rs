@@ -0,0 +1,125 @@
+use super::*;
+use crate::math::*;
+use frame_support::sp_std::vec;
+use frame_support::inherent::Vec;
+use substrate_fixed::types::I32F32;
+
+// The column sums of the network weights once every row of the epoch has been summed. Stake and weights
+// have already been masked for inactivity, validator permits and deregistered modules.
+pub struct RankInputs {
+    // Active stake of permitted validators, normalized. range: I32F32(0, 1)
+    pub active_stake: Vec<I32F32>,
+    // Column sums of the weights before clipping: pr_j = SUM(i) w_ij * s_i.
+    pub preranks: Vec<I32F32>,
+    // Column sums of the weights after clipping, the preranks if the mechanism does not clip.
+    pub rank_sums: Vec<I32F32>,
+    // Steepness of the trust sigmoid, 0 < rho <= 40.
+    pub rho: I32F32,
+    // Consensus majority ratio, the trust at which the sigmoid is centered. range: I32F32(0, 1)
+    pub kappa: I32F32,
+}
+
+// The per-uid scores produced by a consensus mechanism. Every vector has length n.
+pub struct RankScores {
+    pub ranks: Vec<I32F32>,
+    pub trust: Vec<I32F32>,
+    pub incentive: Vec<I32F32>,
+}
+
+// A rule turning the network weights and stake into incentive. The epoch walks the weights a few rows or
+// columns per block and dividends are always paid through an EMA of the bonds the mechanism leaves.
+// Networks select their mechanism with the ConsensusMechanism hyperparameter.
+pub trait ConsensusMechanism {
+    // Weights are clipped at the kappa stake-weighted median of their column before ranks and bonds are computed.
+    const CLIPS_WEIGHTS: bool;
+    // Weights and bonds are read, otherwise ranks and bonds are left empty.
+    const USES_WEIGHTS: bool;
+    fn score( inputs: &RankInputs ) -> RankScores;
+}
+
+// Ranks are the stake-weighted sum of the weights, dividends are paid through an EMA of bonds.
+// Trust and consensus are not computed and are left at zero.
+pub struct StakeWeightedConsensus;
+
+impl ConsensusMechanism for StakeWeightedConsensus {
+    const CLIPS_WEIGHTS: bool = false;
+    const USES_WEIGHTS: bool = true;
+
+    fn score( inputs: &RankInputs ) -> RankScores {
+        let mut ranks: Vec<I32F32> = inputs.rank_sums.clone();
+        inplace_normalize( &mut ranks );
+        let incentive: Vec<I32F32> = ranks.clone();
+        log::trace!( "I (=R): {:?}", &incentive );
+        RankScores { trust: vec![ I32F32::from_num( 0 ); ranks.len() ], ranks, incentive }
+    }
+}
+
+// Weights are clipped at the kappa stake-weighted median of each column before ranks and bonds
+// are computed, so a minority of stake cannot inflate the score of a module. Incentive is the rank
+// scaled by a sigmoid of the trust, steepened by rho and centered at kappa.
+pub struct YumaConsensus;
+
+impl ConsensusMechanism for YumaConsensus {
+    const CLIPS_WEIGHTS: bool = true;
+    const USES_WEIGHTS: bool = true;
+
+    fn score( inputs: &RankInputs ) -> RankScores {
+        // Compute server trust: ratio of rank after vs. rank before.
+        let trust: Vec<I32F32> = vecdiv( &inputs.rank_sums, &inputs.preranks ); // range: I32F32(0, 1)
+        log::trace!( "T: {:?}", &trust );
+
+        let mut ranks: Vec<I32F32> = inputs.rank_sums.clone();
+        inplace_normalize( &mut ranks );  // range: I32F32(0, 1)
+
+        // Scale ranks by the trust sigmoid: inc_j = r_j * sigmoid( t_j, rho, kappa ).
+        let mut incentive: Vec<I32F32> = ranks.iter().zip( trust.iter() ).map( |(rank_j, trust_j)| *rank_j * sigmoid_safe( *trust_j, inputs.rho, inputs.kappa ) ).collect();
+        inplace_normalize( &mut incentive );  // range: I32F32(0, 1)
+        log::trace!( "I: {:?}", &incentive );
+
+        RankScores { ranks, trust, incentive }
+    }
+}
+
+// Weights are ignored and incentive is the active stake itself. No bonds are kept.
+pub struct StakeConsensus;
+
+impl ConsensusMechanism for StakeConsensus {
+    const CLIPS_WEIGHTS: bool = false;
+    const USES_WEIGHTS: bool = false;
+
+    fn score( inputs: &RankInputs ) -> RankScores {
+        let incentive: Vec<I32F32> = inputs.active_stake.clone();
+        log::trace!( "I (=S): {:?}", &incentive );
+        RankScores { ranks: incentive.clone(), trust: vec![ I32F32::from_num( 0 ); incentive.len() ], incentive }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+
+    // Returns true if the mechanism clips the weights at the consensus of their column.
+    pub fn consensus_clips_weights( mechanism: ConsensusMechanismKind ) -> bool {
+        match mechanism {
+            ConsensusMechanismKind::StakeWeighted => StakeWeightedConsensus::CLIPS_WEIGHTS,
+            ConsensusMechanismKind::Yuma => YumaConsensus::CLIPS_WEIGHTS,
+            ConsensusMechanismKind::Stake => StakeConsensus::CLIPS_WEIGHTS,
+        }
+    }
+
+    // Returns true if the mechanism reads the weights and bonds.
+    pub fn consensus_uses_weights( mechanism: ConsensusMechanismKind ) -> bool {
+        match mechanism {
+            ConsensusMechanismKind::StakeWeighted => StakeWeightedConsensus::USES_WEIGHTS,
+            ConsensusMechanismKind::Yuma => YumaConsensus::USES_WEIGHTS,
+            ConsensusMechanismKind::Stake => StakeConsensus::USES_WEIGHTS,
+        }
+    }
+
+    // Runs the consensus mechanism on the summed weights of the epoch.
+    pub fn run_consensus_mechanism( mechanism: ConsensusMechanismKind, inputs: &RankInputs ) -> RankScores {
+        match mechanism {
+            ConsensusMechanismKind::StakeWeighted => StakeWeightedConsensus::score( inputs ),
+            ConsensusMechanismKind::Yuma => YumaConsensus::score( inputs ),
+            ConsensusMechanismKind::Stake => StakeConsensus::score( inputs ),
+        }
+    }
+}
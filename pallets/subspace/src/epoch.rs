@@ -4,6 +4,10 @@ use frame_support::sp_std::vec;
 use frame_support::inherent::Vec;
 use substrate_fixed::types::{I32F32, I64F64, I96F32};
 use frame_support::storage::IterableStorageDoubleMap;
+use frame_support::BoundedVec;
+use frame_support::weights::Weight;
+use frame_support::pallet_prelude::MaxEncodedLen;
+use crate::consensus::{RankInputs, RankScores};
 
 impl<T: Config> Pallet<T> {
 
@@ -53,6 +57,7 @@ impl<T: Config> Pallet<T> {
         for ( uid_i, key ) in < Keys<T> as IterableStorageDoubleMap<u16, u16, T::AccountId >>::iter_prefix( netuid ) {
             keys.push( (uid_i, key) ); 
         }
+        keys.sort_by_key( |(uid_i, _)| *uid_i ); // Looked up by uid while the epoch is in progress.
         log::trace!( "keys: {:?}", &keys );
 
         // Access network stake as normalized vector.
@@ -76,6 +81,22 @@ impl<T: Config> Pallet<T> {
         // == Validator permits ==
         // =======================
 
+        // Get max allowed validators.
+        let max_allowed_validators: u16 = Self::get_max_allowed_validators( netuid );
+        log::trace!( "max_allowed_validators:\n{:?}\n", max_allowed_validators );
+
+        // The top-k keys by stake hold a validator permit this epoch.
+        let validator_permits: Vec<bool> = is_topk( &stake, max_allowed_validators as usize );
+        log::trace!( "validator_permits:\n{:?}\n", &validator_permits );
+
+        // Logical negation of validator_permits.
+        let validator_forbids: Vec<bool> = validator_permits.iter().map(|&b| !b).collect();
+
+        // Remove stake without a validator permit.
+        inplace_mask_vector( &validator_forbids, &mut active_stake );
+        inplace_normalize( &mut active_stake );
+        log::trace!( "S (permit):\n{:?}\n", &active_stake );
+
         // =============
         // == Weights ==
         // =============
@@ -84,6 +105,9 @@ impl<T: Config> Pallet<T> {
         let mut weights: Vec<Vec<I32F32>> = Self::get_weights( netuid );
         log::trace!( "W:\n{:?}\n", &weights );
 
+        // Mask weights that are not from permitted validators.
+        inplace_mask_rows( &validator_forbids, &mut weights );
+        // log::trace!( "W (permit):\n{:?}\n", &weights );
 
         // Remove self-weight by masking diagonal.
         inplace_mask_diag( &mut weights );
@@ -97,6 +121,11 @@ impl<T: Config> Pallet<T> {
         inplace_row_normalize( &mut weights );
         // log::trace!( "W (mask+norm):\n{:?}\n", &weights );
 
+        // Scale each row down with the age of its weights.
+        let weights_decay: Vec<I32F32> = Self::get_weights_decay( netuid, &last_update, current_block );
+        weights = row_hadamard( &weights, &weights_decay );
+        // log::trace!( "W (mask+norm+decay):\n{:?}\n", &weights );
+
         // ================================
         // == Consensus ==
         // ================================
@@ -104,14 +133,33 @@ impl<T: Config> Pallet<T> {
         // Compute preranks: r_j = SUM(i) w_ij * s_i
         let preranks: Vec<I32F32> = matmul( &weights, &active_stake );
 
+        // Consensus majority ratio, e.g. 51%.
+        let kappa: I32F32 = Self::get_float_kappa( netuid );
+        // Calculate consensus as stake-weighted median of weights.
+        let consensus: Vec<I32F32> = weighted_median_col( &active_stake, &weights, kappa );
+        log::trace!( "C:\n{:?}\n", &consensus );
+
+        // Clip weights at majority consensus.
+        let mut clipped_weights: Vec<Vec<I32F32>> = weights.clone();
+        inplace_col_clip( &mut clipped_weights, &consensus );
+
         // ====================================
         // == Ranks, Server Trust, Incentive ==
         // ====================================
 
         // Compute ranks: r_j = SUM(i) w_ij * s_i
-        let mut ranks: Vec<I32F32> = matmul( &weights, &active_stake );
+        let mut ranks: Vec<I32F32> = matmul( &clipped_weights, &active_stake );
+
+        // Compute server trust: ratio of rank after vs. rank before.
+        let trust: Vec<I32F32> = vecdiv( &ranks, &preranks );
+        log::trace!( "T:\n{:?}\n", &trust );
+
         inplace_normalize( &mut ranks );
-        let incentive: Vec<I32F32> = ranks.clone();
+
+        // Scale ranks by the trust sigmoid: inc_j = r_j * sigmoid( t_j, rho, kappa ).
+        let rho: I32F32 = Self::get_float_rho( netuid );
+        let mut incentive: Vec<I32F32> = ranks.iter().zip( trust.iter() ).map( |(rank_j, trust_j)| *rank_j * sigmoid_safe( *trust_j, rho, kappa ) ).collect();
+        inplace_normalize( &mut incentive );
         log::trace!( "I:\n{:?}\n", &incentive );
 
         // =========================
@@ -120,18 +168,18 @@ impl<T: Config> Pallet<T> {
 
         // Access network bonds column normalized.
         let mut bonds: Vec<Vec<I32F32>> = Self::get_bonds( netuid );
+        inplace_mask_rows( &validator_forbids, &mut bonds ); // mask bonds of keys without a permit
         inplace_mask_matrix( &outdated, &mut bonds );  // mask outdated bonds
         inplace_col_normalize( &mut bonds ); // sum_i b_ij = 1
         // log::trace!( "B:\n{:?}\n", &bonds );
 
         // Compute bonds delta column normalized.
-        let mut bonds_delta: Vec<Vec<I32F32>> = row_hadamard( &weights, &active_stake ); // ΔB = W◦S
+        let mut bonds_delta: Vec<Vec<I32F32>> = row_hadamard( &clipped_weights, &active_stake ); // ΔB = W◦S
         inplace_col_normalize( &mut bonds_delta ); // sum_i b_ij = 1
         // log::trace!( "ΔB:\n{:?}\n", &bonds_delta );
     
         // Compute bonds moving average.
-        let bonds_moving_average: I64F64 = I64F64::from_num( Self::get_bonds_moving_average( netuid ) ) / I64F64::from_num( 1_000_000 );
-        let alpha: I32F32 = I32F32::from_num(1) - I32F32::from_num( bonds_moving_average );
+        let alpha: I32F32 = Self::get_float_bonds_alpha( netuid );
         let mut ema_bonds: Vec<Vec<I32F32>> = mat_ema( &bonds_delta, &bonds, alpha );
         inplace_col_normalize( &mut ema_bonds ); // sum_i b_ij = 1
         // log::trace!( "emaB:\n{:?}\n", &ema_bonds );
@@ -145,8 +193,10 @@ impl<T: Config> Pallet<T> {
         // == Emission and Pruning scores ==
         // =================================
 
-        // Compute emission scores.
-        let mut normalized_emission: Vec<I32F32> = incentive.iter().zip( dividends.clone() ).map( |(ii, di)| ii + di ).collect();
+        // Compute emission scores, incentive and dividends weighted by the network incentive ratio.
+        let incentive_ratio: I32F32 = Self::get_float_incentive_ratio( Self::get_incentive_ratio( netuid ) );
+        let dividends_ratio: I32F32 = I32F32::from_num( 1 ) - incentive_ratio;
+        let mut normalized_emission: Vec<I32F32> = incentive.iter().zip( dividends.clone() ).map( |(ii, di)| incentive_ratio * ii + dividends_ratio * di ).collect();
         inplace_normalize( &mut normalized_emission );
         
         // If emission is zero, replace emission with normalized stake.
@@ -174,20 +224,30 @@ impl<T: Config> Pallet<T> {
         // ===================
         let cloned_emission: Vec<u64> = emission.clone();
         let cloned_ranks: Vec<u16> = ranks.iter().map(|xi| fixed_proportion_to_u16(*xi)).collect::<Vec<u16>>();
+        let cloned_trust: Vec<u16> = trust.iter().map(|xi| fixed_proportion_to_u16(*xi)).collect::<Vec<u16>>();
+        let cloned_consensus: Vec<u16> = consensus.iter().map(|xi| fixed_proportion_to_u16(*xi)).collect::<Vec<u16>>();
         let cloned_incentive: Vec<u16> = incentive.iter().map(|xi| fixed_proportion_to_u16(*xi)).collect::<Vec<u16>>();
         let cloned_dividends: Vec<u16> = dividends.iter().map(|xi| fixed_proportion_to_u16(*xi)).collect::<Vec<u16>>();
         let cloned_pruning_scores: Vec<u16> = pruning_scores.iter().map(|xi| fixed_proportion_to_u16(*xi)).collect::<Vec<u16>>();
-        Active::<T>::insert( netuid, active.clone() );
-        Emission::<T>::insert( netuid, cloned_emission );
-        Rank::<T>::insert( netuid, cloned_ranks);
-        Incentive::<T>::insert( netuid, cloned_incentive );
-        Dividends::<T>::insert( netuid, cloned_dividends );
-        PruningScores::<T>::insert( netuid, cloned_pruning_scores );
+        Active::<T>::insert( netuid, Self::to_uid_vec( active.clone() ) );
+        Emission::<T>::insert( netuid, Self::to_uid_vec( cloned_emission ) );
+        Rank::<T>::insert( netuid, Self::to_uid_vec( cloned_ranks ) );
+        Trust::<T>::insert( netuid, Self::to_uid_vec( cloned_trust ) );
+        Consensus::<T>::insert( netuid, Self::to_uid_vec( cloned_consensus ) );
+        Incentive::<T>::insert( netuid, Self::to_uid_vec( cloned_incentive ) );
+        Dividends::<T>::insert( netuid, Self::to_uid_vec( cloned_dividends ) );
+        PruningScores::<T>::insert( netuid, Self::to_uid_vec( cloned_pruning_scores ) );
+
+        ValidatorPermits::<T>::insert( netuid, Self::to_uid_vec( validator_permits.clone() ) );
 
         for i in 0..n {
-            let new_bonds_row: Vec<(u16,u16)> = (0..n).zip( vec_fixed_proportions_to_u16( ema_bonds[i as usize].clone() ) ).collect();
-            Bonds::<T>::insert( netuid, i, new_bonds_row );
-           
+            // Set bonds only if uid retains validator permit, otherwise clear bonds.
+            if validator_permits[i as usize] {
+                let new_bonds_row: Vec<(u16,u16)> = (0..n).zip( vec_fixed_proportions_to_u16( ema_bonds[i as usize].clone() ) ).collect();
+                Bonds::<T>::insert( netuid, i, Self::to_uid_vec( new_bonds_row ) );
+            } else if Bonds::<T>::contains_key( netuid, i ) {
+                Bonds::<T>::remove( netuid, i );
+            }
         }
 
         let mut result: Vec<(T::AccountId, u64)> = vec![]; 
@@ -199,7 +259,8 @@ impl<T: Config> Pallet<T> {
     }
 
     // Calculates reward consensus values, then updates rank, incentive, dividend, pruning_score, emission and bonds, and 
-    // returns the emissions for uids/keys in a given `netuid`.
+    // returns the emissions for uids/keys in a given `netuid`. All the epoch phases run within this call, blocks
+    // advance the same phases over several blocks through advance_epoch.
     //
     // # Args:
     // 	* 'netuid': ( u16 ):
@@ -208,10 +269,116 @@ impl<T: Config> Pallet<T> {
     // 	* 'rao_emission': ( u64 ):
     //         - The total emission for the epoch.
     //
-    // 	* 'debug' ( bool ):
-    // 		- Print debugging outputs.
-    //    
     pub fn epoch( netuid: u16, rao_emission: u64 ) -> Vec<(T::AccountId, u64)> {
+        // An epoch in progress shares the snapshot storage, so it is completed first.
+        Self::finish_epoch( netuid );
+        let mut state: EpochState<T> = Self::begin_epoch( netuid, rao_emission );
+        loop {
+            if let Some( result ) = Self::run_epoch_phase( netuid, &mut state, u16::MAX ) { return result }
+        }
+    }
+
+    // Returns true if an epoch has been started on the network and has not written back yet.
+    pub fn is_epoch_in_progress( netuid: u16 ) -> bool { EpochInProgress::<T>::contains_key( netuid ) }
+
+    // Starts the epoch on the network. Its phases then advance over the following blocks within the
+    // per-block epoch budget.
+    pub fn start_epoch( netuid: u16, rao_emission: u64 ) {
+        let state: EpochState<T> = Self::begin_epoch( netuid, rao_emission );
+        EpochInProgress::<T>::insert( netuid, state );
+    }
+
+    // Runs the remaining phases of the epoch in progress on the network, if any, regardless of the budget.
+    // Returns the ref time consumed.
+    pub fn finish_epoch( netuid: u16 ) -> u64 {
+        if !Self::is_epoch_in_progress( netuid ) { return 0 }
+        Self::advance_epoch( netuid, u64::MAX )
+    }
+
+    // Advances the epoch in progress on the network by as many phase steps as fit in 'ref_time_budget' and
+    // returns the ref time consumed. Once the write-back phase ran, the emission tuples are loaded for draining
+    // and the epoch state is removed.
+    //
+    pub fn advance_epoch( netuid: u16, ref_time_budget: u64 ) -> u64 {
+        let mut state: EpochState<T> = match EpochInProgress::<T>::get( netuid ) {
+            Some( state ) => state,
+            None => return 0
+        };
+        let state_ref_time: u64 = T::DbWeight::get().reads_writes( 1, 1 ).ref_time();
+        let mut used: u64 = 0;
+        loop {
+            let remaining: u64 = ref_time_budget.saturating_sub( used );
+            let rows: u16 = if Self::is_epoch_phase_chunked( &state.phase ) {
+                let remaining_rows: u16 = state.n.saturating_sub( state.cursor );
+                let row_ref_time: u64 = Self::get_epoch_step_ref_time( &state, 1, Self::get_epoch_row_entries( &state ) ).max( 1 );
+                let affordable: u64 = ( remaining / row_ref_time ).min( remaining_rows as u64 );
+                if affordable == 0 && !Self::is_within_epoch_budget( row_ref_time, remaining ) { break } // Out of budget for this block.
+                ( affordable as u16 ).max( 1 ).min( remaining_rows )
+            } else {
+                if !Self::is_within_epoch_budget( Self::get_epoch_step_ref_time( &state, 0, state.entries ), remaining ) { break } // Out of budget for this block.
+                0
+            };
+
+            // Charge the step for the rows and weight entries it actually walked.
+            let phase: EpochPhase = state.phase.clone();
+            let entries_before: u32 = state.entries;
+            let chunk_entries: u32 = Self::get_epoch_row_entries( &state ).saturating_mul( rows as u32 );
+            let result: Option<Vec<(T::AccountId, u64)>> = Self::run_epoch_phase( netuid, &mut state, rows );
+            let step_entries: u32 = match phase {
+                EpochPhase::Load => state.entries.saturating_sub( entries_before ),
+                EpochPhase::Emission | EpochPhase::WriteBack => state.entries,
+                _ => chunk_entries,
+            };
+            used = used.saturating_add( Self::get_epoch_phase_ref_time( &phase, state.n, rows, step_entries ) );
+
+            if let Some( result ) = result {
+                Self::load_emission_tuples( netuid, result );
+                EpochInProgress::<T>::remove( netuid );
+                return used.saturating_add( state_ref_time );
+            }
+        }
+        EpochInProgress::<T>::insert( netuid, state );
+        used.saturating_add( state_ref_time )
+    }
+
+    // Returns the proof size of the epoch state, read and written back every block the epoch is in progress. The ref
+    // time of the access is charged by advance_epoch, the state holds about twenty per-uid vectors.
+    pub fn get_epoch_state_proof_weight() -> Weight {
+        Weight::from_proof_size( EpochState::<T>::max_encoded_len() as u64 )
+    }
+
+    // Returns true if a step costing 'ref_time' may run with 'remaining' of the per-block epoch budget left. A step
+    // larger than the whole budget still runs when nothing else was spent this block, so that it is never starved.
+    //
+    pub fn is_within_epoch_budget( ref_time: u64, remaining: u64 ) -> bool {
+        ref_time <= remaining || remaining >= T::EpochWeightPerBlock::get().ref_time()
+    }
+
+    // Returns true if the phase walks the weight matrix a few rows, or columns, at a time.
+    pub fn is_epoch_phase_chunked( phase: &EpochPhase ) -> bool {
+        !matches!( phase, EpochPhase::Emission | EpochPhase::WriteBack )
+    }
+
+    // Runs the current phase of the epoch, or the next 'max_rows' rows or columns of it for the phases which walk
+    // the weight matrix, and moves on to the next phase once done. Returns the emission tuples once the epoch is complete.
+    //
+    pub fn run_epoch_phase( netuid: u16, state: &mut EpochState<T>, max_rows: u16 ) -> Option<Vec<(T::AccountId, u64)>> {
+        log::trace!( "netuid: {:?} phase: {:?} cursor: {:?}", netuid, state.phase, state.cursor );
+        match state.phase {
+            EpochPhase::Load => Self::epoch_load( netuid, state, max_rows ),
+            EpochPhase::Consensus => Self::epoch_consensus( netuid, state, max_rows ),
+            EpochPhase::Rank => Self::epoch_rank( netuid, state, max_rows ),
+            EpochPhase::Ema => Self::epoch_ema( netuid, state, max_rows ),
+            EpochPhase::Bonds => Self::epoch_bonds( netuid, state, max_rows ),
+            EpochPhase::Emission => Self::epoch_emission( state ),
+            EpochPhase::WriteBack => return Some( Self::epoch_write_back( netuid, state ) ),
+        }
+        None
+    }
+
+    // Reads activity, stake and validator permits, which stay fixed for the rest of the epoch.
+    //
+    pub fn begin_epoch( netuid: u16, rao_emission: u64 ) -> EpochState<T> {
         // Get network size.
         let n: u16 = Self::get_network_n( netuid );
         log::trace!( "n: {:?}", n );
@@ -239,10 +406,6 @@ impl<T: Config> Pallet<T> {
         // Logical negation of inactive.
         let active: Vec<bool> = inactive.iter().map(|&b| !b).collect();
 
-        // Block at registration vector (block when each module was most recently registered).
-        let block_at_registration: Vec<u64> = Self::get_block_at_registration( netuid );
-        log::trace!( "Block at registration: {:?}", &block_at_registration );
-
         // ===========
         // == Stake ==
         // ===========
@@ -272,87 +435,321 @@ impl<T: Config> Pallet<T> {
         inplace_normalize( &mut active_stake );
         log::trace!( "S (mask+norm): {:?}", &active_stake );
 
-        // =============
-        // == Weights ==
-        // =============
-
-        // Access network weights row normalized.
-        let mut weights: Vec<Vec<(u16, I32F32)>> = Self::get_weights_sparse( netuid );
-        // log::trace!( "W: {:?}", &weights );
-
-        // Remove self-weight by masking diagonal.
-        weights = mask_diag_sparse( &weights );
-        // log::trace!( "W (permit+diag): {:?}", &weights );
+        // =======================
+        // == Validator permits ==
+        // =======================
 
-        // Remove weights referring to deregistered modules.
-        weights = vec_mask_sparse_matrix( &weights, &last_update, &block_at_registration, &| updated, registered | updated <= registered );
-        // log::trace!( "W (permit+diag+outdate): {:?}", &weights );
+        // Get max allowed validators.
+        let max_allowed_validators: u16 = Self::get_max_allowed_validators( netuid );
+        log::trace!( "max_allowed_validators: {:?}", max_allowed_validators );
 
-        // Normalize remaining weights.
-        inplace_row_normalize_sparse( &mut weights );
-        // log::trace!( "W (mask+norm): {:?}", &weights );
+        // The top-k keys by stake hold a validator permit this epoch.
+        let validator_permits: Vec<bool> = is_topk( &stake, max_allowed_validators as usize );
+        log::trace!( "validator_permits: {:?}", &validator_permits );
 
-        // ================================
-        // == Consensus ==
-        // ================================
-        
-        // Compute preranks: r_j = SUM(i) w_ij * s_i
-        let preranks: Vec<I32F32> = matmul_sparse( &weights, &active_stake, n );
-        // log::trace!( "R (before): {:?}", &preranks );
+        // Logical negation of validator_permits.
+        let validator_forbids: Vec<bool> = validator_permits.iter().map(|&b| !b).collect();
 
-        // =============================
-        // == Ranks, Trust, Incentive ==
-        // =============================
+        // Remove stake without a validator permit.
+        inplace_mask_vector( &validator_forbids, &mut active_stake );
+        inplace_normalize( &mut active_stake );
+        log::trace!( "S (mask+norm+permit): {:?}", &active_stake );
+
+        EpochState {
+            phase: EpochPhase::Load,
+            cursor: 0,
+            n: n,
+            entries: 0,
+            current_block: current_block,
+            rao_emission: rao_emission,
+            incentive_ratio: Self::get_incentive_ratio( netuid ),
+            mechanism: Self::get_consensus_mechanism( netuid ),
+            kappa: Self::get_float_kappa( netuid ).to_bits(),
+            rho: Self::get_float_rho( netuid ).to_bits(),
+            bonds_alpha: Self::get_float_bonds_alpha( netuid ).to_bits(),
+            keys: Self::to_uid_vec( keys ),
+            active: Self::to_uid_vec( active ),
+            validator_permits: Self::to_uid_vec( validator_permits ),
+            stake: Self::to_uid_vec( vec_fixed_to_bits( &stake ) ),
+            active_stake: Self::to_uid_vec( vec_fixed_to_bits( &active_stake ) ),
+            preranks: Self::to_uid_vec( vec![ 0; n as usize ] ),
+            rank_sums: Self::to_uid_vec( vec![ 0; n as usize ] ),
+            bonds_sums: Self::to_uid_vec( vec![ 0; n as usize ] ),
+            ema_sums: Self::to_uid_vec( vec![ 0; n as usize ] ),
+            ranks: BoundedVec::default(),
+            trust: BoundedVec::default(),
+            consensus: Self::to_uid_vec( vec![ 0; n as usize ] ),
+            incentive: BoundedVec::default(),
+            dividends: Self::to_uid_vec( vec![ 0; n as usize ] ),
+            emission: BoundedVec::default(),
+            pruning_scores: BoundedVec::default(),
+        }
+    }
 
-        // Compute ranks: r_j = SUM(i) w_ij * s_i.
-        let mut ranks: Vec<I32F32> = matmul_sparse( &weights, &active_stake, n );
-        // log::trace!( "R (after): {:?}", &ranks );
+    // Snapshots the next 'max_rows' weights rows, masked, normalized and decayed, along with the masked bonds rows,
+    // and adds them to the column sums. The weights of staked rows are also kept by column for the consensus phase.
+    //
+    fn epoch_load( netuid: u16, state: &mut EpochState<T>, max_rows: u16 ) {
+        let n: u16 = state.n;
+        let zero: I32F32 = I32F32::from_num( 0 );
+        let last_update: Vec<u64> = Self::get_last_update( netuid );
+        let block_at_registration: Vec<u64> = Self::get_block_at_registration( netuid );
+        let half_life: u64 = Self::get_weights_decay_half_life( netuid );
+        let uses_weights: bool = Self::consensus_uses_weights( state.mechanism );
+        let clips_weights: bool = Self::consensus_clips_weights( state.mechanism );
+        let active_stake: Vec<I32F32> = vec_fixed_from_bits( &state.active_stake );
+        let mut preranks: Vec<I32F32> = vec_fixed_from_bits( &state.preranks );
+        let mut bonds_sums: Vec<I32F32> = vec_fixed_from_bits( &state.bonds_sums );
+
+        // Entries referring to modules registered after the row was last updated are outdated.
+        let is_outdated = | uid_i: u16, uid_j: u16 | -> bool { last_update[ uid_i as usize ] <= block_at_registration[ uid_j as usize ] };
+
+        let end: u16 = state.cursor.saturating_add( max_rows ).min( n );
+        for uid_i in state.cursor..end {
+            if !state.validator_permits[ uid_i as usize ] { continue } // Weights and bonds without a permit are masked.
+            if !uses_weights { continue } // The mechanism leaves ranks and bonds empty.
+            if Self::is_replaced_since_epoch_began( netuid, state, uid_i ) { continue } // The snapshot stake is not the new key's.
+
+            // =============
+            // == Weights ==
+            // =============
+
+            // Rows set by key are resolved to current uids, only departed keys are dropped.
+            let key: T::AccountId = Keys::<T>::get( netuid, uid_i );
+            let mut weights_row: Vec<(u16, I32F32)> = match Self::get_key_weights_row( netuid, &key ) {
+                Some( row ) => row.iter().filter( |(uid_j, _)| *uid_j < n ).map( |(uid_j, weight_ij)| ( *uid_j, u16_proportion_to_fixed( *weight_ij ) ) ).collect(),
+                None => Weights::<T>::get( netuid, uid_i ).iter()
+                    .filter( |(uid_j, _)| *uid_j < n && !is_outdated( uid_i, *uid_j ) )
+                    .map( |(uid_j, weight_ij)| ( *uid_j, u16_proportion_to_fixed( *weight_ij ) ) ).collect(),
+            };
+
+            // Remove self-weight.
+            weights_row.retain( |(uid_j, _)| *uid_j != uid_i );
+
+            // Normalize remaining weights, then scale the row down with its age.
+            let row_sum: I32F32 = weights_row.iter().map( |(_, value)| *value ).sum();
+            let decay: I32F32 = half_life_decay( state.current_block.saturating_sub( last_update[ uid_i as usize ] ), half_life );
+            if row_sum > zero {
+                weights_row.iter_mut().for_each( |(_, value)| *value = *value / row_sum * decay );
+            }
+            state.entries = state.entries.saturating_add( weights_row.len() as u32 );
 
-        inplace_normalize( &mut ranks );  // range: I32F32(0, 1)
-        let incentive: Vec<I32F32> = ranks.clone();
-        log::trace!( "I (=R): {:?}", &incentive );
+            // Compute preranks: pr_j = SUM(i) w_ij * s_i.
+            let stake_i: I32F32 = active_stake[ uid_i as usize ];
+            for ( uid_j, value ) in weights_row.iter() {
+                preranks[ *uid_j as usize ] += stake_i * *value;
+            }
 
-        // =========================
-        // == Bonds and Dividends ==
-        // =========================
+            // Only the rows holding stake weigh in the column consensus.
+            if clips_weights && stake_i > zero {
+                for ( uid_j, value ) in weights_row.iter() {
+                    EpochColumns::<T>::mutate( netuid, *uid_j, |column| Self::push_uid_value( column, ( uid_i, value.to_bits() ) ) );
+                }
+            }
+            EpochWeights::<T>::insert( netuid, uid_i, Self::to_uid_vec( weights_row.iter().map( |(uid_j, value)| ( *uid_j, value.to_bits() ) ).collect() ) );
+
+            // ===========
+            // == Bonds ==
+            // ===========
+
+            // Remove bonds referring to deregistered modules.
+            let bonds_row: Vec<(u16, i64)> = Bonds::<T>::get( netuid, uid_i ).iter()
+                .filter( |(uid_j, _)| *uid_j < n && !is_outdated( uid_i, *uid_j ) )
+                .map( |(uid_j, bonds_ij)| ( *uid_j, u16_proportion_to_fixed( *bonds_ij ).to_bits() ) ).collect();
+            for ( uid_j, bits ) in bonds_row.iter() {
+                bonds_sums[ *uid_j as usize ] += I32F32::from_bits( *bits );
+            }
+            EpochBonds::<T>::insert( netuid, uid_i, Self::to_uid_vec( bonds_row ) );
+        }
+        state.preranks = Self::to_uid_vec( vec_fixed_to_bits( &preranks ) );
+        state.bonds_sums = Self::to_uid_vec( vec_fixed_to_bits( &bonds_sums ) );
+        state.cursor = end;
+        if state.cursor >= n {
+            state.phase = if clips_weights { EpochPhase::Consensus } else { EpochPhase::Rank };
+            state.cursor = 0;
+        }
+    }
 
-        // Access network bonds column normalized.
-        let mut bonds: Vec<Vec<(u16, I32F32)>> = Self::get_bonds_sparse( netuid );
-        // log::trace!( "B: {:?}", &bonds );
-        
-        // Remove bonds referring to deregistered modules.
-        bonds = vec_mask_sparse_matrix( &bonds, &last_update, &block_at_registration, &| updated, registered | updated <= registered );
-        // log::trace!( "B (outdatedmask): {:?}", &bonds );
+    // Computes the consensus of the next 'max_columns' columns, the kappa stake-weighted median of the weights the
+    // staked rows gave the uid. The rows without a weight on the uid count as a single zero weight.
+    //
+    fn epoch_consensus( netuid: u16, state: &mut EpochState<T>, max_columns: u16 ) {
+        let zero: I32F32 = I32F32::from_num( 0 );
+        let active_stake: Vec<I32F32> = vec_fixed_from_bits( &state.active_stake );
+        let stake_sum: I32F32 = active_stake.iter().filter( |stake| **stake > zero ).sum();
+        let minority: I32F32 = stake_sum - I32F32::from_bits( state.kappa );
+
+        let end: u16 = state.cursor.saturating_add( max_columns ).min( state.n );
+        for uid_j in state.cursor..end {
+            let column: UidVec<T, (u16, i64)> = EpochColumns::<T>::take( netuid, uid_j );
+            let mut use_stake: Vec<I32F32> = column.iter().map( |(uid_i, _)| active_stake[ *uid_i as usize ] ).collect();
+            let mut use_score: Vec<I32F32> = column.iter().map( |(_, bits)| I32F32::from_bits( *bits ) ).collect();
+            let unweighted_stake: I32F32 = stake_sum.saturating_sub( use_stake.iter().sum::<I32F32>() );
+            if unweighted_stake > zero {
+                use_stake.push( unweighted_stake );
+                use_score.push( zero );
+            }
+            let partition_idx: Vec<usize> = (0..use_stake.len()).collect();
+            state.consensus[ uid_j as usize ] = weighted_median( &use_stake, &use_score, &partition_idx, minority, zero, stake_sum ).to_bits();
+        }
+        state.cursor = end;
+        if state.cursor >= state.n {
+            log::trace!( "C: {:?}", &state.consensus );
+            state.phase = EpochPhase::Rank;
+            state.cursor = 0;
+        }
+    }
 
-        // Normalize remaining bonds: sum_i b_ij = 1.
-        inplace_col_normalize_sparse( &mut bonds, n );
-        // log::trace!( "B (mask+norm): {:?}", &bonds );
+    // Adds the next 'max_rows' weights rows, clipped at the column consensus, to the ranks: r_j = SUM(i) w_ij * s_i.
+    // Once every row is summed, the network consensus mechanism turns the ranks into trust and incentive.
+    //
+    fn epoch_rank( netuid: u16, state: &mut EpochState<T>, max_rows: u16 ) {
+        let zero: I32F32 = I32F32::from_num( 0 );
+        let consensus: Vec<I32F32> = vec_fixed_from_bits( &state.consensus );
+        let active_stake: Vec<I32F32> = vec_fixed_from_bits( &state.active_stake );
+        let mut rank_sums: Vec<I32F32> = vec_fixed_from_bits( &state.rank_sums );
+
+        let end: u16 = state.cursor.saturating_add( max_rows ).min( state.n );
+        for uid_i in state.cursor..end {
+            let stake_i: I32F32 = active_stake[ uid_i as usize ];
+            if stake_i == zero { continue } // The row adds nothing.
+            for ( uid_j, value ) in Self::get_epoch_weights_row( netuid, state, &consensus, uid_i ).iter() {
+                rank_sums[ *uid_j as usize ] += stake_i * *value;
+            }
+        }
+        state.cursor = end;
+        if state.cursor < state.n {
+            state.rank_sums = Self::to_uid_vec( vec_fixed_to_bits( &rank_sums ) );
+            return
+        }
 
-        // Compute bonds delta column normalized.
-        let mut bonds: Vec<Vec<(u16, I32F32)>> = row_hadamard_sparse( &weights, &active_stake ); // ΔB = W◦S (outdated W masked)
-        // log::trace!( "ΔB: {:?}", &bonds_delta );
+        // Run the network consensus mechanism on the summed weights.
+        let inputs: RankInputs = RankInputs {
+            active_stake: active_stake,
+            preranks: vec_fixed_from_bits( &state.preranks ),
+            rank_sums: rank_sums.clone(),
+            rho: I32F32::from_bits( state.rho ),
+            kappa: I32F32::from_bits( state.kappa ),
+        };
+        let RankScores { ranks, trust, incentive } = Self::run_consensus_mechanism( state.mechanism, &inputs );
+        state.rank_sums = Self::to_uid_vec( vec_fixed_to_bits( &rank_sums ) );
+        state.ranks = Self::to_uid_vec( vec_fixed_to_bits( &ranks ) );
+        state.trust = Self::to_uid_vec( vec_fixed_to_bits( &trust ) );
+        state.incentive = Self::to_uid_vec( vec_fixed_to_bits( &incentive ) );
+        state.phase = EpochPhase::Ema;
+        state.cursor = 0;
+    }
 
-        // Normalize bonds delta.
-        inplace_col_normalize_sparse( &mut bonds, n ); // sum_i b_ij = 1
-        // log::trace!( "ΔB (norm): {:?}", &bonds_delta );
-    
+    // Computes the next 'max_rows' rows of the bonds moving average and adds them to its column sums. The new bonds
+    // are the share of each row in the clipped ranks, ΔB_ij = w_ij * s_i / r_j, the previous ones are column normalized.
+    //
+    fn epoch_ema( netuid: u16, state: &mut EpochState<T>, max_rows: u16 ) {
+        let zero: I32F32 = I32F32::from_num( 0 );
+        let alpha: I32F32 = I32F32::from_bits( state.bonds_alpha );
+        let consensus: Vec<I32F32> = vec_fixed_from_bits( &state.consensus );
+        let active_stake: Vec<I32F32> = vec_fixed_from_bits( &state.active_stake );
+        let rank_sums: Vec<I32F32> = vec_fixed_from_bits( &state.rank_sums );
+        let bonds_sums: Vec<I32F32> = vec_fixed_from_bits( &state.bonds_sums );
+        let mut ema_sums: Vec<I32F32> = vec_fixed_from_bits( &state.ema_sums );
+
+        let end: u16 = state.cursor.saturating_add( max_rows ).min( state.n );
+        for uid_i in state.cursor..end {
+            if !state.validator_permits[ uid_i as usize ] { continue } // No bonds without a permit.
+            let stake_i: I32F32 = active_stake[ uid_i as usize ];
+
+            // The row of the bonds delta ΔB_ij and the row of the column normalized bonds B_ij.
+            let mut bonds_delta_row: Vec<(u16, I32F32)> = vec![];
+            if stake_i > zero {
+                for ( uid_j, value ) in Self::get_epoch_weights_row( netuid, state, &consensus, uid_i ).iter() {
+                    let rank_sum: I32F32 = rank_sums[ *uid_j as usize ];
+                    if rank_sum > zero { bonds_delta_row.push( ( *uid_j, stake_i * *value / rank_sum ) ); }
+                }
+            }
+            let bonds_row: Vec<(u16, I32F32)> = EpochBonds::<T>::get( netuid, uid_i ).iter().map( |( uid_j, bits )| {
+                let bonds_sum: I32F32 = bonds_sums[ *uid_j as usize ];
+                ( *uid_j, if bonds_sum > zero { I32F32::from_bits( *bits ) / bonds_sum } else { I32F32::from_bits( *bits ) } )
+            }).collect();
+
+            // The row of mat_ema_sparse( ΔB, B, alpha ), keeping the positive entries.
+            let ema_row: Vec<(u16, I32F32)> = row_ema_sparse( &bonds_delta_row, &bonds_row, alpha, state.n );
+            for ( uid_j, value ) in ema_row.iter() {
+                ema_sums[ *uid_j as usize ] += *value;
+            }
+            EpochBonds::<T>::insert( netuid, uid_i, Self::to_uid_vec( ema_row.iter().map( |(uid_j, value)| ( *uid_j, value.to_bits() ) ).collect() ) );
+        }
+        state.ema_sums = Self::to_uid_vec( vec_fixed_to_bits( &ema_sums ) );
+        state.cursor = end;
+        if state.cursor >= state.n {
+            state.phase = EpochPhase::Bonds;
+            state.cursor = 0;
+        }
+    }
 
-        // Normalize EMA bonds.
-        inplace_col_normalize_sparse( &mut bonds, n ); // sum_i b_ij = 1
-        // log::trace!( "emaB: {:?}", &ema_bonds );
+    // Writes the next 'max_rows' rows of new bonds, column normalized, adds up their dividends, d_i = SUM(j) b_ij * inc_j,
+    // and drops their snapshot rows.
+    //
+    fn epoch_bonds( netuid: u16, state: &mut EpochState<T>, max_rows: u16 ) {
+        let zero: I32F32 = I32F32::from_num( 0 );
+        let ema_sums: Vec<I32F32> = vec_fixed_from_bits( &state.ema_sums );
+        let incentive: Vec<I32F32> = vec_fixed_from_bits( &state.incentive );
+        let mut dividends: Vec<I32F32> = vec_fixed_from_bits( &state.dividends );
+
+        let end: u16 = state.cursor.saturating_add( max_rows ).min( state.n );
+        for uid_i in state.cursor..end {
+            let bonds_row: UidVec<T, (u16, i64)> = EpochBonds::<T>::take( netuid, uid_i );
+            EpochWeights::<T>::remove( netuid, uid_i );
+            if Self::is_replaced_since_epoch_began( netuid, state, uid_i ) { continue } // The bonds left with the replaced key.
 
-        // Compute dividends: d_i = SUM(j) b_ij * inc_j.
-        // range: I32F32(0, 1)
-        let mut dividends: Vec<I32F32> = matmul_transpose_sparse( &bonds, &incentive );
-        inplace_normalize( &mut dividends );
-        log::trace!( "D: {:?}", &dividends );
+            // Set bonds only if uid retains validator permit, otherwise clear bonds.
+            if state.validator_permits[ uid_i as usize ] {
+                let new_bonds_row: Vec<(u16, I32F32)> = bonds_row.iter().map( |(uid_j, bits)| {
+                    let ema_sum: I32F32 = ema_sums[ *uid_j as usize ];
+                    ( *uid_j, if ema_sum > zero { I32F32::from_bits( *bits ) / ema_sum } else { I32F32::from_bits( *bits ) } )
+                }).collect();
+                dividends[ uid_i as usize ] = new_bonds_row.iter().map( |(uid_j, bonds_ij)| *bonds_ij * incentive[ *uid_j as usize ] ).sum();
+                let new_bonds_row: Vec<(u16, u16)> = new_bonds_row.iter().map( |(uid_j, bonds_ij)| ( *uid_j, fixed_proportion_to_u16( *bonds_ij ) ) ).collect();
+                Bonds::<T>::insert( netuid, uid_i, Self::to_uid_vec( new_bonds_row ) );
+            } else if Bonds::<T>::contains_key( netuid, uid_i ) {
+                Bonds::<T>::remove( netuid, uid_i );
+            }
+        }
+        state.cursor = end;
+        if state.cursor >= state.n {
+            inplace_normalize( &mut dividends ); // range: I32F32(0, 1)
+            log::trace!( "D: {:?}", &dividends );
+            state.phase = EpochPhase::Emission;
+            state.cursor = 0;
+        }
+        state.dividends = Self::to_uid_vec( vec_fixed_to_bits( &dividends ) );
+    }
 
-        // =================================
-        // == Emission and Pruning scores ==
-        // =================================
+    // Returns the snapshot weights row of the uid, clipped at the column consensus if the mechanism clips weights.
+    // A weight above a column without consensus is dropped.
+    //
+    fn get_epoch_weights_row( netuid: u16, state: &EpochState<T>, consensus: &Vec<I32F32>, uid_i: u16 ) -> Vec<(u16, I32F32)> {
+        let zero: I32F32 = I32F32::from_num( 0 );
+        let clips_weights: bool = Self::consensus_clips_weights( state.mechanism );
+        EpochWeights::<T>::get( netuid, uid_i ).iter().filter_map( |(uid_j, bits)| {
+            let value: I32F32 = I32F32::from_bits( *bits );
+            let threshold: I32F32 = consensus[ *uid_j as usize ];
+            if !clips_weights || value <= threshold { Some( ( *uid_j, value ) ) }
+            else if threshold > zero { Some( ( *uid_j, threshold ) ) }
+            else { None }
+        }).collect()
+    }
 
-        // Compute normalized emission scores. range: I32F32(0, 1)
-        let mut normalized_emission: Vec<I32F32> = incentive.iter().zip( dividends.clone() ).map( |(ii, di)| ii + di ).collect();
+    // Turns the incentive and dividends into emission and pruning scores.
+    //
+    fn epoch_emission( state: &mut EpochState<T> ) {
+        let stake: Vec<I32F32> = vec_fixed_from_bits( &state.stake );
+        let active_stake: Vec<I32F32> = vec_fixed_from_bits( &state.active_stake );
+        let incentive: Vec<I32F32> = vec_fixed_from_bits( &state.incentive );
+        let dividends: Vec<I32F32> = vec_fixed_from_bits( &state.dividends );
+
+        // Compute normalized emission scores, incentive and dividends weighted by the network incentive ratio. range: I32F32(0, 1)
+        let incentive_ratio: I32F32 = Self::get_float_incentive_ratio( state.incentive_ratio );
+        let dividends_ratio: I32F32 = I32F32::from_num( 1 ) - incentive_ratio;
+        let mut normalized_emission: Vec<I32F32> = incentive.iter().zip( dividends.clone() ).map( |(ii, di)| incentive_ratio * ii + dividends_ratio * di ).collect();
         inplace_normalize( &mut normalized_emission );
 
         // If emission is zero, replace emission with normalized stake.
@@ -366,46 +763,150 @@ impl<T: Config> Pallet<T> {
         }
         
         // Compute rao based emission scores. range: I96F32(0, rao_emission)
-        let float_rao_emission: I96F32 = I96F32::from_num( rao_emission );
+        let float_rao_emission: I96F32 = I96F32::from_num( state.rao_emission );
         let emission: Vec<I96F32> = normalized_emission.iter().map( |e: &I32F32| I96F32::from_num( *e ) * float_rao_emission ).collect();
-        let emission: Vec<u64> = emission.iter().map( |e: &I96F32| e.to_num::<u64>() ).collect();
+        let mut emission: Vec<u64> = emission.iter().map( |e: &I96F32| e.to_num::<u64>() ).collect();
         log::trace!( "nE: {:?}", &normalized_emission );
         log::trace!( "E: {:?}", &emission );
 
+        // Saftey check, the emission must not exceed the allowed total.
+        let emission_sum: u128 = emission.iter().map( |e| *e as u128 ).sum();
+        if emission_sum > state.rao_emission as u128 {
+            log::error!( "epoch emission {:?} exceeds the allowed total {:?}", emission_sum, state.rao_emission );
+            emission = vec![ 0; emission.len() ];
+        }
+
         // Set pruning scores.
         let pruning_scores: Vec<I32F32> = normalized_emission.clone();
         log::trace!( "P: {:?}", &pruning_scores );
 
+        state.emission = Self::to_uid_vec( emission );
+        state.pruning_scores = Self::to_uid_vec( vec_fixed_to_bits( &pruning_scores ) );
+        state.phase = EpochPhase::WriteBack;
+    }
+
+    // Stores the epoch values and returns the emission tuples ( key, u64 emission ) of the keys registered when
+    // the epoch began. Uids registered or replaced while the epoch was in progress keep their values.
+    //
+    fn epoch_write_back( netuid: u16, state: &mut EpochState<T> ) -> Vec<(T::AccountId, u64)> {
+        let to_u16 = | bits: &[i64] | -> Vec<u16> { bits.iter().map( |xi| fixed_proportion_to_u16( I32F32::from_bits( *xi ) ) ).collect() };
+        let replaced: Vec<bool> = (0..state.n).map( |uid_i| Self::is_replaced_since_epoch_began( netuid, state, uid_i ) ).collect();
+
         // ===================
         // == Value storage ==
         // ===================
-        let cloned_emission: Vec<u64> = emission.clone();
-        let cloned_ranks: Vec<u16> = ranks.iter().map(|xi| fixed_proportion_to_u16(*xi)).collect::<Vec<u16>>();
-        let cloned_incentive: Vec<u16> = incentive.iter().map(|xi| fixed_proportion_to_u16(*xi)).collect::<Vec<u16>>();
-        let cloned_dividends: Vec<u16> = dividends.iter().map(|xi| fixed_proportion_to_u16(*xi)).collect::<Vec<u16>>();
-        let cloned_pruning_scores: Vec<u16> = pruning_scores.iter().map(|xi| fixed_proportion_to_u16(*xi)).collect::<Vec<u16>>();
-        Active::<T>::insert( netuid, active.clone() );
-        Emission::<T>::insert( netuid, cloned_emission );
-        Rank::<T>::insert( netuid, cloned_ranks);
-        Incentive::<T>::insert( netuid, cloned_incentive );
-        Dividends::<T>::insert( netuid, cloned_dividends );
-        PruningScores::<T>::insert( netuid, cloned_pruning_scores );
-
-        for i in 0..n {
-            // Set bonds only if uid retains validator permit, otherwise clear bonds.
-            let new_bonds_row: Vec<(u16,u16)> = ema_bonds[i as usize].iter().map( |(j, value)| (*j, fixed_proportion_to_u16(*value))).collect();
-            Bonds::<T>::insert( netuid, i, new_bonds_row );
-
-        }
+        Active::<T>::mutate( netuid, |v| Self::merge_epoch_values( v, &state.active, &replaced ) );
+        Emission::<T>::mutate( netuid, |v| Self::merge_epoch_values( v, &state.emission, &replaced ) );
+        Rank::<T>::mutate( netuid, |v| Self::merge_epoch_values( v, &to_u16( &state.ranks ), &replaced ) );
+        Trust::<T>::mutate( netuid, |v| Self::merge_epoch_values( v, &to_u16( &state.trust ), &replaced ) );
+        Consensus::<T>::mutate( netuid, |v| Self::merge_epoch_values( v, &to_u16( &state.consensus ), &replaced ) );
+        Incentive::<T>::mutate( netuid, |v| Self::merge_epoch_values( v, &to_u16( &state.incentive ), &replaced ) );
+        Dividends::<T>::mutate( netuid, |v| Self::merge_epoch_values( v, &to_u16( &state.dividends ), &replaced ) );
+        PruningScores::<T>::mutate( netuid, |v| Self::merge_epoch_values( v, &to_u16( &state.pruning_scores ), &replaced ) );
+        ValidatorPermits::<T>::mutate( netuid, |v| Self::merge_epoch_values( v, &state.validator_permits, &replaced ) );
+
+        // Flag the modules inactive for too long, they are pruned when the next epoch starts.
+        Self::flag_modules_to_prune( netuid, Self::get_current_block_as_u64() );
 
         // Emission tuples ( keys, u64 emission)
         let mut result: Vec<(T::AccountId, u64)> = vec![]; 
-        for ( uid_i, key ) in keys.iter() {
-            result.push( ( key.clone(), emission[ *uid_i as usize ] ) );
+        for ( uid_i, key ) in state.keys.iter() {
+            result.push( ( key.clone(), state.emission[ *uid_i as usize ] ) );
         }
+
+        // =================
+        // == Epoch event ==
+        // =================
+        let block: u64 = Self::get_current_block_as_u64();
+        let emission_total: u64 = state.emission.iter().fold( 0, |sum, e| sum.saturating_add( *e ) );
+        let active_count: u16 = state.active.iter().filter( |a| **a ).count() as u16;
+        let validator_count: u16 = state.validator_permits.iter().filter( |p| **p ).count() as u16;
+        let top_incentive_uid: u16 = Self::get_top_incentive_uid( &state.incentive );
+        log::info!("EpochCompleted( netuid: {:?} block: {:?} emission_total: {:?} active_count: {:?} validator_count: {:?} top_incentive_uid: {:?} ) ", netuid, block, emission_total, active_count, validator_count, top_incentive_uid );
+        Self::deposit_event( Event::EpochCompleted( netuid, block, emission_total, active_count, validator_count, top_incentive_uid ) );
         result
     }
 
+    // Returns the uid with the highest incentive, the lowest uid on ties.
+    fn get_top_incentive_uid( incentive: &Vec<i64> ) -> u16 {
+        let mut top_uid: usize = 0;
+        for ( uid_i, incentive_i ) in incentive.iter().enumerate() {
+            if *incentive_i > incentive[ top_uid ] { top_uid = uid_i; }
+        }
+        top_uid as u16
+    }
+
+    // Overwrites the leading entries of a per-uid vector with the epoch values, except those of the replaced uids.
+    fn merge_epoch_values<V: Clone>( stored: &mut UidVec<T, V>, values: &[V], replaced: &[bool] ) {
+        for ( i, value ) in values.iter().enumerate() {
+            if replaced[ i ] { continue }
+            match stored.get_mut( i ) {
+                Some( stored_i ) => *stored_i = value.clone(),
+                None => Self::push_uid_value( stored, value.clone() ),
+            }
+        }
+    }
+
+    // Returns true if the uid changed hands since the epoch began. The snapshot stake and permits belong to the
+    // replaced key, so the row of the new key is left out of the epoch. The replaced key is still paid its emission.
+    pub fn is_replaced_since_epoch_began( netuid: u16, state: &EpochState<T>, uid: u16 ) -> bool {
+        match state.keys.binary_search_by_key( &uid, |(uid_i, _)| *uid_i ) {
+            Ok( index ) => Keys::<T>::get( netuid, uid ) != state.keys[ index ].1,
+            Err( _ ) => true,
+        }
+    }
+
+    // Returns the average number of weight entries per row seen so far, used to estimate the rows a step can afford.
+    pub fn get_epoch_row_entries( state: &EpochState<T> ) -> u32 {
+        let rows: u16 = match state.phase {
+            EpochPhase::Load => state.cursor,
+            _ => state.n,
+        };
+        if rows == 0 { return 0 }
+        state.entries / rows as u32
+    }
+
+    // Returns the ref time of the next step of the epoch over 'rows' rows and 'entries' weight entries.
+    pub fn get_epoch_step_ref_time( state: &EpochState<T>, rows: u16, entries: u32 ) -> u64 {
+        Self::get_epoch_phase_ref_time( &state.phase, state.n, rows, entries )
+    }
+
+    // Returns the benchmarked ref time of an epoch phase. The row and column phases are charged for the rows, or
+    // columns, they walked, the others run in a single step over the whole network.
+    pub fn get_epoch_phase_ref_time( phase: &EpochPhase, n: u16, rows: u16, entries: u32 ) -> u64 {
+        let weight: Weight = match phase {
+            EpochPhase::Load => T::WeightInfo::epoch_load( rows as u32, entries ),
+            EpochPhase::Consensus => T::WeightInfo::epoch_consensus( rows as u32, entries ),
+            EpochPhase::Rank => T::WeightInfo::epoch_rank( rows as u32, entries ),
+            EpochPhase::Ema => T::WeightInfo::epoch_ema( rows as u32, entries ),
+            EpochPhase::Bonds => T::WeightInfo::epoch_bonds( rows as u32, entries ),
+            EpochPhase::Emission => T::WeightInfo::epoch_emission( n as u32 ),
+            EpochPhase::WriteBack => T::WeightInfo::epoch_write_back( n as u32 ),
+        };
+        weight.ref_time()
+    }
+
+    // Returns the share of the emission paid as incentive, the rest going to dividends, as a proportion in [0, 1].
+    pub fn get_float_incentive_ratio( incentive_ratio: u16 ) -> I32F32 { I32F32::from_num( incentive_ratio.min( 100 ) ) / I32F32::from_num( 100 ) }
+
+    // Returns kappa, the consensus majority ratio, as a proportion in [0, 1].
+    pub fn get_float_kappa( netuid: u16 ) -> I32F32 { I32F32::from_num( Self::get_kappa( netuid ) ) / I32F32::from_num( u16::MAX ) }
+
+    // Returns rho, the steepness of the trust sigmoid.
+    pub fn get_float_rho( netuid: u16 ) -> I32F32 { I32F32::from_num( Self::get_rho( netuid ) ) }
+
+    // Returns the weight of the new bonds in the bonds EMA, i.e. 1 - bonds_moving_average / 1_000_000.
+    pub fn get_float_bonds_alpha( netuid: u16 ) -> I32F32 {
+        let bonds_moving_average: I64F64 = I64F64::from_num( Self::get_bonds_moving_average( netuid ) ) / I64F64::from_num( 1_000_000 );
+        I32F32::from_num( 1 ) - I32F32::from_num( bonds_moving_average )
+    }
+
+    // Returns the per-row decay factor of the weights given the block each row was last updated.
+    pub fn get_weights_decay( netuid: u16, last_update: &Vec<u64>, current_block: u64 ) -> Vec<I32F32> {
+        let half_life: u64 = Self::get_weights_decay_half_life( netuid );
+        last_update.iter().map( |updated| half_life_decay( current_block.saturating_sub( *updated ), half_life ) ).collect()
+    }
+
     pub fn get_normalized_stake( netuid:u16 ) -> Vec<I32F32> {
         let n: usize = Self::get_network_n( netuid ) as usize; 
         let mut stake_64: Vec<I64F64> = vec![ I64F64::from_num(0.0); n ]; 
@@ -431,7 +932,7 @@ impl<T: Config> Pallet<T> {
     pub fn get_weights_sparse( netuid:u16 ) -> Vec<Vec<(u16, I32F32)>> { 
         let n: usize = Self::get_network_n( netuid ) as usize; 
         let mut weights: Vec<Vec<(u16, I32F32)>> = vec![ vec![]; n ]; 
-        for ( uid_i, weights_i ) in < Weights<T> as IterableStorageDoubleMap<u16, u16, Vec<(u16, u16)> >>::iter_prefix( netuid ) {
+        for ( uid_i, weights_i ) in < Weights<T> as IterableStorageDoubleMap<u16, u16, UidVec<T, (u16, u16)> >>::iter_prefix( netuid ) {
             for (uid_j, weight_ij) in weights_i.iter() { 
                 weights [ uid_i as usize ].push( ( *uid_j, u16_proportion_to_fixed( *weight_ij ) ));
             }
@@ -442,7 +943,7 @@ impl<T: Config> Pallet<T> {
     pub fn get_weights( netuid:u16 ) -> Vec<Vec<I32F32>> { 
         let n: usize = Self::get_network_n( netuid ) as usize; 
         let mut weights: Vec<Vec<I32F32>> = vec![ vec![ I32F32::from_num(0.0); n ]; n ]; 
-        for ( uid_i, weights_i ) in < Weights<T> as IterableStorageDoubleMap<u16, u16, Vec<(u16, u16)> >>::iter_prefix( netuid ) {
+        for ( uid_i, weights_i ) in < Weights<T> as IterableStorageDoubleMap<u16, u16, UidVec<T, (u16, u16)> >>::iter_prefix( netuid ) {
             for (uid_j, weight_ij) in weights_i.iter() { 
                 weights [ uid_i as usize ] [ *uid_j as usize ] = u16_proportion_to_fixed(  *weight_ij );
             }
@@ -453,7 +954,7 @@ impl<T: Config> Pallet<T> {
     pub fn get_bonds_sparse( netuid:u16 ) -> Vec<Vec<(u16, I32F32)>> { 
         let n: usize = Self::get_network_n( netuid ) as usize; 
         let mut bonds: Vec<Vec<(u16, I32F32)>> = vec![ vec![]; n ]; 
-        for ( uid_i, bonds_i ) in < Bonds<T> as IterableStorageDoubleMap<u16, u16, Vec<(u16, u16)> >>::iter_prefix( netuid ) {
+        for ( uid_i, bonds_i ) in < Bonds<T> as IterableStorageDoubleMap<u16, u16, UidVec<T, (u16, u16)> >>::iter_prefix( netuid ) {
             for (uid_j, bonds_ij) in bonds_i.iter() { 
                 bonds [ uid_i as usize ].push( ( *uid_j, u16_proportion_to_fixed( *bonds_ij ) ));
             }
@@ -464,7 +965,7 @@ impl<T: Config> Pallet<T> {
     pub fn get_bonds( netuid:u16 ) -> Vec<Vec<I32F32>> { 
         let n: usize = Self::get_network_n( netuid ) as usize; 
         let mut bonds: Vec<Vec<I32F32>> = vec![ vec![ I32F32::from_num(0.0); n ]; n ]; 
-        for ( uid_i, bonds_i ) in < Bonds<T> as IterableStorageDoubleMap<u16, u16, Vec<(u16, u16)> >>::iter_prefix( netuid ) {
+        for ( uid_i, bonds_i ) in < Bonds<T> as IterableStorageDoubleMap<u16, u16, UidVec<T, (u16, u16)> >>::iter_prefix( netuid ) {
             for (uid_j, bonds_ij) in bonds_i.iter() { 
                 bonds [ uid_i as usize ] [ *uid_j as usize ] = u16_proportion_to_fixed( *bonds_ij );
             }
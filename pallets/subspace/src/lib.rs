@@ -54,16 +54,23 @@ mod benchmarks;
 // =========================
 mod block_step;
 
+mod consensus;
 mod epoch;
 mod math;
 mod network;
 mod registration;
+mod schedule;
 mod serving;
 mod staking;
 mod utils;
 mod uids;
 mod weights;
+mod migrations;
 pub mod module;
+pub mod weight_info;
+
+pub use weight_info::WeightInfo;
+pub use block_step::KeyEmissionInfo;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -75,11 +82,16 @@ pub mod pallet {
 	use serde_with::{serde_as, DisplayFromStr};
 	use frame_support::inherent::Vec;
 	use scale_info::prelude::string::String;
+	use sp_core::H256;
+	use super::WeightInfo;
+
 
+	// The storage version of the pallet, migrated to in on_runtime_upgrade.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new( 1 );
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
-	#[pallet::without_storage_info]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	// Configure the pallet by specifying the parameters and types on which it depends.
@@ -91,11 +103,22 @@ pub mod pallet {
 		// --- Currency type that will be used to place deposits on modules
 		type Currency: Currency<Self::AccountId> + Send + Sync;
 
+		// --- Weight information for the extrinsics and the block step of this pallet.
+		type WeightInfo: WeightInfo;
+
 		// =================================
 		// ==== Initial Value Constants ====
 		// =================================
 		#[pallet::constant] // Initial currency issuance.
 		type InitialIssuance: Get<u64>;
+		#[pallet::constant] // Emission per block before the first halving.
+		type InitialBlockEmission: Get<u64>;
+		#[pallet::constant] // Hard cap on the total issuance. Emission halves every time half of the remaining supply is issued.
+		type MaxSupply: Get<u64>;
+		#[pallet::constant] // Account receiving the treasury cut of the block emission.
+		type TreasuryAccount: Get<Self::AccountId>;
+		#[pallet::constant] // Initial percentage of the block emission diverted to the treasury account.
+		type InitialTreasuryCut: Get<u16>;
 		#[pallet::constant] // Initial min allowed weights setting.
 		type InitialMinAllowedWeights: Get<u16>;
 		#[pallet::constant] // Initial Emission Ratio
@@ -108,10 +131,18 @@ pub mod pallet {
 		type InitialAdjustmentInterval: Get<u16>;
 		#[pallet::constant] // Initial bonds moving average.
 		type InitialBondsMovingAverage: Get<u64>;
+		#[pallet::constant] // Initial rho.
+		type InitialRho: Get<u16>;
+		#[pallet::constant] // Initial kappa, the consensus majority ratio as a proportion of u16::MAX.
+		type InitialKappa: Get<u16>;
+		#[pallet::constant] // Initial incentive ratio, the percentage of the epoch emission paid as incentive, the rest going to dividends.
+		type InitialIncentiveRatio: Get<u16>;
 		#[pallet::constant] // Initial target registrations per interval.
 		type InitialTargetRegistrationsPerInterval: Get<u16>;
 		#[pallet::constant] // Max UID constant.
 		type InitialMaxAllowedUids: Get<u16>;
+		#[pallet::constant] // Initial maximum number of validator permits per network.
+		type InitialMaxAllowedValidators: Get<u16>;
 		#[pallet::constant] // Immunity Period Constant.
 		type InitialImmunityPeriod: Get<u16>;
 		#[pallet::constant] // Activity constant
@@ -124,19 +155,70 @@ pub mod pallet {
 		type InitialServingRateLimit: Get<u64>;
 		#[pallet::constant] // Initial transaction rate limit.
 		type InitialTxRateLimit: Get<u64>;
+
+		// ==================================
+		// ==== Hyperparameter Bounds =======
+		// ==================================
+		#[pallet::constant] // Lowest value max allowed uids can be set to.
+		type MaxAllowedUidsLowerBound: Get<u16>;
+		#[pallet::constant] // Highest value max allowed uids can be set to.
+		type MaxAllowedUidsUpperBound: Get<u16>;
+		#[pallet::constant] // Highest value the immunity period can be set to.
+		type ImmunityPeriodUpperBound: Get<u16>;
+		#[pallet::constant] // Lowest value the activity cutoff can be set to.
+		type ActivityCutoffLowerBound: Get<u16>;
+		#[pallet::constant] // Highest value the activity cutoff can be set to.
+		type ActivityCutoffUpperBound: Get<u16>;
+		#[pallet::constant] // Highest value the bonds moving average can be set to (1_000_000 = 1.0).
+		type BondsMovingAverageUpperBound: Get<u64>;
+		#[pallet::constant] // Lowest value the adjustment interval can be set to.
+		type AdjustmentIntervalLowerBound: Get<u16>;
+		#[pallet::constant] // Highest value the max registrations per block can be set to.
+		type MaxRegistrationsPerBlockUpperBound: Get<u16>;
+		#[pallet::constant] // Highest value the serving rate limit can be set to.
+		type ServingRateLimitUpperBound: Get<u64>;
+		#[pallet::constant] // Weight the block step may spend advancing epochs in progress each block.
+		type EpochWeightPerBlock: Get<Weight>;
+		#[pallet::constant] // Number of past epoch payouts kept in the emission history of each key.
+		type MaxEmissionHistory: Get<u32>;
+		#[pallet::constant] // Maximum number of endpoints a module can serve.
+		type MaxModuleEndpoints: Get<u32>;
+		#[pallet::constant] // Maximum length in bytes of a module name.
+		type MaxNameLength: Get<u32>;
+		#[pallet::constant] // Maximum length in bytes of a module uri.
+		type MaxUriLength: Get<u32>;
+		#[pallet::constant] // Maximum number of hyperparameter changes queued on a network.
+		type MaxScheduledChanges: Get<u32>;
+		#[pallet::constant] // Maximum number of capability tags a module can advertise.
+		type MaxModuleTags: Get<u32>;
+		#[pallet::constant] // Maximum length in bytes of a module tag.
+		type MaxTagLength: Get<u32>;
 	}
 
 	pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 
+	// Bound of the per-uid storage vectors and rows. A network never holds more uids than MaxAllowedUidsUpperBound.
+	pub struct MaxUidsOf<T>( PhantomData<T> );
+	impl<T: Config> Get<u32> for MaxUidsOf<T> {
+		fn get() -> u32 { T::MaxAllowedUidsUpperBound::get() as u32 }
+	}
+	pub type UidVec<T, V> = BoundedVec<V, MaxUidsOf<T>>;
+
+	// A module capability tag, e.g. b"text-embedding".
+	pub type Tag<T> = BoundedVec<u8, <T as Config>::MaxTagLength>;
+	pub type TagsOf<T> = BoundedVec<Tag<T>, <T as Config>::MaxModuleTags>;
+
 	// ============================
 	// ==== Staking + Accounts ====
 	// ============================
 	#[pallet::type_value] 
 	pub fn DefaultAccountTake<T: Config>() -> u64 { 0 }
 	#[pallet::type_value]
-	pub fn DefaultBlockEmission<T: Config>() -> u64 {1_000_000_000}
+	pub fn DefaultBlockEmission<T: Config>() -> u64 { T::InitialBlockEmission::get() }
 	#[pallet::type_value] 
 	pub fn DefaultTotalIssuance<T: Config>() -> u64 { T::InitialIssuance::get() }
+	#[pallet::type_value]
+	pub fn DefaultTreasuryCut<T: Config>() -> u16 { T::InitialTreasuryCut::get() }
 	#[pallet::type_value] 
 	pub fn DefaultAccount<T: Config>() -> T::AccountId { T::AccountId::decode(&mut sp_runtime::traits::TrailingZeroInput::zeroes()).unwrap()}
 
@@ -148,6 +230,8 @@ pub mod pallet {
 	pub type BlockEmission<T> = StorageValue<_, u64, ValueQuery, DefaultBlockEmission<T>>;
 	#[pallet::storage] // --- ITEM ( total_issuance )
 	pub type TotalIssuance<T> = StorageValue<_, u64, ValueQuery, DefaultTotalIssuance<T>>;
+	#[pallet::storage] // --- ITEM ( treasury_cut ) | Percentage of the block emission paid to the treasury account.
+	pub type TreasuryCut<T> = StorageValue<_, u16, ValueQuery, DefaultTreasuryCut<T>>;
 	#[pallet::storage] // --- MAP ( hot ) --> stake | Returns the total amount of stake under a key.
     pub type TotalKeyStake<T:Config> = StorageMap<_, Identity, T::AccountId, u64, ValueQuery, DefaultAccountTake<T>>;
 
@@ -220,20 +304,50 @@ pub mod pallet {
 	// =================================
 	
 
-	#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+	// Longest DNS name, in bytes, an endpoint hostname can hold.
+	pub const MAX_HOSTNAME_LENGTH: u32 = 253;
+
+	// The transport protocol an endpoint is served over.
+	#[derive(Decode, Encode, PartialEq, Eq, Clone, Copy, Debug, TypeInfo, MaxEncodedLen)]
+	pub enum Transport {
+		Tcp,
+		Udp,
+	}
+
+	#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo, MaxEncodedLen)]
+	pub struct ModuleEndpoint {
+		pub transport: Transport, // --- Transport protocol of the endpoint.
+		pub ip_type: u8, // --- Endpoint ip version, 4 or 6.
+		pub ip: u128, // --- Endpoint u128 encoded ip address of type ip_type.
+		pub port: u16, // --- Endpoint u16 encoded port.
+		pub hostname: Option<BoundedVec<u8, ConstU32<MAX_HOSTNAME_LENGTH>>>, // --- Optional DNS hostname resolving to the endpoint.
+	}
+
+	#[derive(Decode, Encode, PartialEqNoBound, EqNoBound, CloneNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
 	pub struct Module<T: Config> {
-		pub key: T::AccountId,
-		pub uid: Compact<u16>,
-		pub name : Vec<u8>, // --- Module name.
-		pub netuid: Compact<u16>,
-        pub ip: u128, // --- Module u128 encoded ip address of type v6 or v4.
-        pub port: u16, // --- Module u16 encoded port.
-		pub uri : Vec<u8>, // --- Module uri.
-		pub last_update: Compact<u64>,
+		pub block: u64, // --- Block the module was last served at.
+		pub name : BoundedVec<u8, T::MaxNameLength>, // --- Module name.
+		pub endpoints: BoundedVec<ModuleEndpoint, T::MaxModuleEndpoints>, // --- Endpoints the module is served at.
+		pub uri : BoundedVec<u8, T::MaxUriLength>, // --- Module uri.
 	}
 
 	#[pallet::storage] // --- MAP ( netuid, key ) --> module
-	pub(super) type Modules<T:Config> = StorageDoubleMap<_, Identity, u16, Blake2_128Concat, T::AccountId, Module, OptionQuery>;
+	pub(super) type Modules<T:Config> = StorageDoubleMap<_, Identity, u16, Blake2_128Concat, T::AccountId, Module<T>, OptionQuery>;
+	#[pallet::storage] // --- DMAP ( netuid, key ) --> tags | Capability tags advertised by the module.
+	pub(super) type ModuleTags<T:Config> = StorageDoubleMap<_, Identity, u16, Blake2_128Concat, T::AccountId, TagsOf<T>, ValueQuery>;
+	#[pallet::storage] // --- NMAP ( netuid, tag, key ) --> bool | Index of the modules advertising each tag on a network.
+	pub(super) type TagIndex<T:Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Identity, u16>,
+			NMapKey<Blake2_128Concat, Tag<T>>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+		),
+		bool,
+		ValueQuery
+	>;
 
 	// Rate limiting
 	#[pallet::type_value]
@@ -252,6 +366,10 @@ pub mod pallet {
 
 	#[pallet::storage] // --- MAP ( netuid ) --> serving_rate_limit
 	pub type ServingRateLimit<T> = StorageMap<_, Identity, u16, u64, ValueQuery, DefaultServingRateLimit<T>> ;
+	#[pallet::type_value] 
+	pub fn DefaultAllowPrivateIps<T: Config>() -> bool { false }
+	#[pallet::storage] // --- MAP ( netuid ) --> allow_private_ips, modules may serve private and loopback addresses (local test networks).
+	pub type AllowPrivateIps<T> = StorageMap<_, Identity, u16, bool, ValueQuery, DefaultAllowPrivateIps<T>> ;
 
 	// =======================================
 	// ==== Networkwork Hyperparam storage ====
@@ -259,10 +377,20 @@ pub mod pallet {
 	#[pallet::type_value] 
 	pub fn DefaultWeightsSetRateLimit<T: Config>() -> u64 { 0 }
 	#[pallet::type_value] 
+	pub fn DefaultWeightsVersionKey<T: Config>() -> u64 { 0 }
+	#[pallet::type_value] 
+	pub fn DefaultWeightsDecayHalfLife<T: Config>() -> u64 { 0 }
+	#[pallet::type_value] 
+	pub fn DefaultInactivePruneWindow<T: Config>() -> u64 { 0 }
+	#[pallet::type_value] 
+	pub fn DefaultInactivePruneMinStake<T: Config>() -> u64 { 0 }
+	#[pallet::type_value] 
 	pub fn DefaultBlockAtRegistration<T: Config>() -> u64 { 0 }
 	#[pallet::type_value] 
 	pub fn DefaultMaxAllowedUids<T: Config>() -> u16 { T::InitialMaxAllowedUids::get() }
 	#[pallet::type_value] 
+	pub fn DefaultMaxAllowedValidators<T: Config>() -> u16 { T::InitialMaxAllowedValidators::get() }
+	#[pallet::type_value] 
 	pub fn DefaultImmunityPeriod<T: Config>() -> u16 { T::InitialImmunityPeriod::get() }
 	#[pallet::type_value] 
 	pub fn DefaultActivityCutoff<T: Config>() -> u16 { T::InitialActivityCutoff::get() }
@@ -274,13 +402,23 @@ pub mod pallet {
 	pub fn DefaultAdjustmentInterval<T: Config>() -> u16 { T::InitialAdjustmentInterval::get() }
 	#[pallet::type_value] 
 	pub fn DefaultTargetRegistrationsPerInterval<T: Config>() -> u16 { T::InitialTargetRegistrationsPerInterval::get() }
+	#[pallet::type_value] 
+	pub fn DefaultBondsMovingAverage<T: Config>() -> u64 { T::InitialBondsMovingAverage::get() }
+	#[pallet::type_value] 
+	pub fn DefaultRho<T: Config>() -> u16 { T::InitialRho::get() }
+	#[pallet::type_value] 
+	pub fn DefaultKappa<T: Config>() -> u16 { T::InitialKappa::get() }
+	#[pallet::type_value] 
+	pub fn DefaultIncentiveRatio<T: Config>() -> u16 { T::InitialIncentiveRatio::get() }
 
-	#[pallet::storage] // --- MAP ( netuid ) --> uid, we use to record uids to prune at next epoch.
-    pub type ModulesToPruneAtNextEpoch<T:Config> = StorageMap<_, Identity, u16, u16, ValueQuery>;
+	#[pallet::storage] // --- MAP ( netuid ) --> uids flagged as inactive by the last epoch, deregistered a few per block before the next epoch.
+    pub type ModulesToPruneAtNextEpoch<T:Config> = StorageMap<_, Identity, u16, UidVec<T, u16>, ValueQuery>;
 	#[pallet::storage] // --- MAP ( netuid ) --> registrations_this_interval
 	pub type RegistrationsThisInterval<T:Config> = StorageMap<_, Identity, u16, u16, ValueQuery>;
 	#[pallet::storage] // --- MAP ( netuid ) --> max_allowed_uids
 	pub type MaxAllowedUids<T> = StorageMap<_, Identity, u16, u16, ValueQuery, DefaultMaxAllowedUids<T> >;
+	#[pallet::storage] // --- MAP ( netuid ) --> max_allowed_validators
+	pub type MaxAllowedValidators<T> = StorageMap<_, Identity, u16, u16, ValueQuery, DefaultMaxAllowedValidators<T> >;
 	#[pallet::storage] // --- MAP ( netuid ) --> immunity_period
 	pub type ImmunityPeriod<T> = StorageMap<_, Identity, u16, u16, ValueQuery, DefaultImmunityPeriod<T> >;
 	#[pallet::storage] // --- MAP ( netuid ) --> activity_cutoff
@@ -289,49 +427,191 @@ pub mod pallet {
 	pub type MaxWeightsLimit<T> = StorageMap< _, Identity, u16, u16, ValueQuery, DefaultMaxWeightsLimit<T> >;
 	#[pallet::storage] // --- MAP ( netuid ) --> min_allowed_weights
 	pub type MinAllowedWeights<T> = StorageMap< _, Identity, u16, u16, ValueQuery, DefaultMinAllowedWeights<T> >;
+	#[pallet::storage] // --- MAP ( netuid ) --> bonds_moving_average
+	pub type BondsMovingAverage<T> = StorageMap<_, Identity, u16, u64, ValueQuery, DefaultBondsMovingAverage<T> >;
 	#[pallet::storage] // --- MAP ( netuid ) --> adjustment_interval
 	pub type AdjustmentInterval<T> = StorageMap<_, Identity, u16, u16, ValueQuery, DefaultAdjustmentInterval<T> >;
 	#[pallet::storage] // --- MAP ( netuid ) --> weights_set_rate_limit
 	pub type WeightsSetRateLimit<T> = StorageMap<_, Identity, u16, u64, ValueQuery, DefaultWeightsSetRateLimit<T> >;
+	#[pallet::storage] // --- MAP ( netuid ) --> weights_decay_half_life, in blocks, 0 disables the decay.
+	pub type WeightsDecayHalfLife<T> = StorageMap<_, Identity, u16, u64, ValueQuery, DefaultWeightsDecayHalfLife<T> >;
+	#[pallet::storage] // --- MAP ( netuid ) --> inactive_prune_window, blocks past the activity cutoff before an inactive module is pruned, 0 disables pruning.
+	pub type InactivePruneWindow<T> = StorageMap<_, Identity, u16, u64, ValueQuery, DefaultInactivePruneWindow<T> >;
+	#[pallet::storage] // --- MAP ( netuid ) --> inactive_prune_min_stake, inactive modules holding at least this stake are never pruned.
+	pub type InactivePruneMinStake<T> = StorageMap<_, Identity, u16, u64, ValueQuery, DefaultInactivePruneMinStake<T> >;
+	#[pallet::storage] // --- MAP ( netuid ) --> weights_version_key
+	pub type WeightsVersionKey<T> = StorageMap<_, Identity, u16, u64, ValueQuery, DefaultWeightsVersionKey<T> >;
+	#[pallet::storage] // --- MAP ( netuid ) --> rho
+	pub type Rho<T> = StorageMap<_, Identity, u16, u16, ValueQuery, DefaultRho<T> >;
+	#[pallet::storage] // --- MAP ( netuid ) --> kappa
+	pub type Kappa<T> = StorageMap<_, Identity, u16, u16, ValueQuery, DefaultKappa<T> >;
+	#[pallet::storage] // --- MAP ( netuid ) --> incentive_ratio, percentage of the epoch emission paid as incentive.
+	pub type IncentiveRatio<T> = StorageMap<_, Identity, u16, u16, ValueQuery, DefaultIncentiveRatio<T> >;
 	#[pallet::storage] // --- MAP ( netuid ) --> target_registrations_this_interval
 	pub type TargetRegistrationsPerInterval<T> = StorageMap<_, Identity, u16, u16, ValueQuery, DefaultTargetRegistrationsPerInterval<T> >;
 	#[pallet::storage] // --- DMAP ( netuid, uid ) --> block_at_registration
 	pub type BlockAtRegistration<T:Config> = StorageDoubleMap<_, Identity, u16, Identity, u16, u64, ValueQuery, DefaultBlockAtRegistration<T> >;
 
+	// =====================================
+	// ==== Network Consensus Mechanism ====
+	// =====================================
+
+	// Selects the consensus mechanism a network runs its epoch with (see consensus.rs).
+	#[derive(Decode, Encode, PartialEq, Eq, Clone, Copy, Debug, TypeInfo, MaxEncodedLen)]
+	pub enum ConsensusMechanismKind {
+		StakeWeighted, // Stake-weighted ranks with bond dividends.
+		Yuma, // Stake-weighted ranks over weights clipped at the kappa median, with bond dividends.
+		Stake, // Incentive proportional to active stake, weights are ignored.
+	}
+
+	#[pallet::type_value] 
+	pub fn DefaultNetworkConsensusMechanism<T: Config>() -> ConsensusMechanismKind { ConsensusMechanismKind::Yuma }
+	#[pallet::storage] // --- MAP ( netuid ) --> consensus_mechanism
+	pub type NetworkConsensusMechanism<T> = StorageMap<_, Identity, u16, ConsensusMechanismKind, ValueQuery, DefaultNetworkConsensusMechanism<T> >;
+
+	// ==========================================
+	// ==== Scheduled Hyperparameter Changes ====
+	// ==========================================
+
+	#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo, MaxEncodedLen)]
+	pub enum HyperparameterChange {
+		Tempo( u16 ),
+		MaxAllowedUids( u16 ),
+		ImmunityPeriod( u16 ),
+		ActivityCutoff( u16 ),
+		MaxWeightsLimit( u16 ),
+		MinAllowedWeights( u16 ),
+		WeightsSetRateLimit( u64 ),
+		AdjustmentInterval( u16 ),
+		TargetRegistrationsPerInterval( u16 ),
+		MaxRegistrationsPerBlock( u16 ),
+		ServingRateLimit( u64 ),
+		AllowPrivateIps( bool ),
+		CommitRevealEnabled( bool ),
+		WeightsRevealWindow( u64 ),
+		Rho( u16 ),
+		Kappa( u16 ),
+		IncentiveRatio( u16 ),
+		MaxAllowedValidators( u16 ),
+		BondsMovingAverage( u64 ),
+		ConsensusMechanism( ConsensusMechanismKind ),
+		WeightsVersionKey( u64 ),
+		WeightsDecayHalfLife( u64 ),
+		InactivePruneWindow( u64 ),
+		InactivePruneMinStake( u64 ),
+	}
+
+	#[pallet::type_value]
+	pub fn DefaultPendingHyperparameterChanges<T:Config>() -> BoundedVec<(HyperparameterChange, u64), T::MaxScheduledChanges> { Default::default() }
+
+	#[pallet::storage] // --- MAP ( netuid ) --> Vec<( change, apply_at_block )>, apply_at_block = 0 applies before the next epoch.
+	pub type PendingHyperparameterChanges<T:Config> = StorageMap<_, Identity, u16, BoundedVec<(HyperparameterChange, u64), T::MaxScheduledChanges>, ValueQuery, DefaultPendingHyperparameterChanges<T> >;
+
+	// =======================
+	// ==== Chunked Epoch ====
+	// =======================
+
+	// The phases an epoch goes through. All but the last two walk the weight matrix a few rows, or columns, per block.
+	#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo, MaxEncodedLen)]
+	pub enum EpochPhase {
+		Load, // Snapshot the masked weights and bonds rows and sum their columns.
+		Consensus, // Compute the stake-weighted median of each weights column.
+		Rank, // Sum the clipped weights rows into ranks, then run the consensus mechanism on them.
+		Ema, // Compute the bonds moving average rows.
+		Bonds, // Write the new bonds rows back and sum the dividends.
+		Emission, // Compute emission and pruning scores.
+		WriteBack, // Store the per-uid vectors and load the emission tuples.
+	}
+
+	// The intermediate values of an epoch spread over several blocks. Fixed point vectors hold I32F32 bits.
+	#[derive(Decode, Encode, PartialEqNoBound, EqNoBound, CloneNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct EpochState<T: Config> {
+		pub phase: EpochPhase,
+		pub cursor: u16, // Next uid of the row or column by column phases.
+		pub n: u16,
+		pub entries: u32, // Weight entries loaded so far, the density the phases are charged for.
+		pub current_block: u64, // Block the epoch started at.
+		pub rao_emission: u64,
+		pub incentive_ratio: u16, // Percentage of the emission paid as incentive when the epoch began.
+		pub mechanism: ConsensusMechanismKind, // Consensus mechanism of the network when the epoch began.
+		pub kappa: i64, // Consensus majority ratio when the epoch began.
+		pub rho: i64, // Steepness of the trust sigmoid when the epoch began.
+		pub bonds_alpha: i64, // Weight of the new bonds in the bonds moving average when the epoch began.
+		pub keys: UidVec<T, (u16, T::AccountId)>,
+		pub active: UidVec<T, bool>,
+		pub validator_permits: UidVec<T, bool>,
+		pub stake: UidVec<T, i64>,
+		pub active_stake: UidVec<T, i64>,
+		pub preranks: UidVec<T, i64>, // Column sums of the stake-weighted weights.
+		pub rank_sums: UidVec<T, i64>, // Column sums of the stake-weighted clipped weights.
+		pub bonds_sums: UidVec<T, i64>, // Column sums of the previous bonds.
+		pub ema_sums: UidVec<T, i64>, // Column sums of the bonds moving average.
+		pub ranks: UidVec<T, i64>,
+		pub trust: UidVec<T, i64>,
+		pub consensus: UidVec<T, i64>,
+		pub incentive: UidVec<T, i64>,
+		pub dividends: UidVec<T, i64>,
+		pub emission: UidVec<T, u64>,
+		pub pruning_scores: UidVec<T, i64>,
+	}
+
+	#[pallet::storage] // --- MAP ( netuid ) --> state of the epoch in progress.
+	pub(super) type EpochInProgress<T:Config> = StorageMap<_, Identity, u16, EpochState<T>, OptionQuery>;
+	#[pallet::storage] // --- DMAP ( netuid, uid ) --> masked weights row of the epoch in progress, ( uid, I32F32 bits ).
+	pub(super) type EpochWeights<T:Config> = StorageDoubleMap<_, Identity, u16, Identity, u16, UidVec<T, (u16, i64)>, ValueQuery>;
+	#[pallet::storage] // --- DMAP ( netuid, uid ) --> bonds row of the epoch in progress, ( uid, I32F32 bits ).
+	pub(super) type EpochBonds<T:Config> = StorageDoubleMap<_, Identity, u16, Identity, u16, UidVec<T, (u16, i64)>, ValueQuery>;
+	#[pallet::storage] // --- DMAP ( netuid, uid ) --> weights the staked rows gave the uid in the epoch in progress, ( uid, I32F32 bits ).
+	pub(super) type EpochColumns<T:Config> = StorageDoubleMap<_, Identity, u16, Identity, u16, UidVec<T, (u16, i64)>, ValueQuery>;
+
 	// =======================================
 	// ==== Networkwork Consensus Storage  ====
 	// =======================================
 	#[pallet::type_value] 
-	pub fn EmptyU16Vec<T:Config>() -> Vec<u16> { vec![] }
+	pub fn EmptyU16Vec<T:Config>() -> UidVec<T, u16> { Default::default() }
 	#[pallet::type_value] 
-	pub fn EmptyU64Vec<T:Config>() -> Vec<u64> { vec![] }
+	pub fn EmptyU64Vec<T:Config>() -> UidVec<T, u64> { Default::default() }
 	#[pallet::type_value] 
-	pub fn EmptyBoolVec<T:Config>() -> Vec<bool> { vec![] }
+	pub fn EmptyBoolVec<T:Config>() -> UidVec<T, bool> { Default::default() }
 	#[pallet::type_value] 
-	pub fn DefaultBonds<T:Config>() -> Vec<(u16, u16)> { vec![] }
+	pub fn DefaultBonds<T:Config>() -> UidVec<T, (u16, u16)> { Default::default() }
 	#[pallet::type_value] 
-	pub fn DefaultWeights<T:Config>() -> Vec<(u16, u16)> { vec![] }
+	pub fn DefaultWeights<T:Config>() -> UidVec<T, (u16, u16)> { Default::default() }
 	#[pallet::type_value] 
 	pub fn DefaultKey<T:Config>() -> T::AccountId { T::AccountId::decode(&mut sp_runtime::traits::TrailingZeroInput::zeroes()).unwrap() }
 
 	#[pallet::storage] // --- DMAP ( netuid ) --> emission
-	pub(super) type LoadedEmission<T:Config> = StorageMap< _, Identity, u16, Vec<(T::AccountId, u64)>, OptionQuery >;
+	pub(super) type LoadedEmission<T:Config> = StorageMap< _, Identity, u16, UidVec<T, (T::AccountId, u64)>, OptionQuery >;
+	#[pallet::storage] // --- MAP ( netuid ) --> index of the next loaded emission tuple to pay out.
+	pub(super) type LoadedEmissionCursor<T:Config> = StorageMap< _, Identity, u16, u32, ValueQuery >;
+	#[pallet::storage] // --- DMAP ( netuid, key ) --> emission paid to the key on the network since it first registered.
+	pub(super) type LifetimeEmission<T:Config> = StorageDoubleMap< _, Identity, u16, Blake2_128Concat, T::AccountId, u64, ValueQuery >;
+	#[pallet::storage] // --- DMAP ( netuid, key ) --> the last MaxEmissionHistory payouts ( block, amount ), oldest first.
+	pub(super) type EmissionHistory<T:Config> = StorageDoubleMap< _, Identity, u16, Blake2_128Concat, T::AccountId, BoundedVec<(u64, u64), T::MaxEmissionHistory>, ValueQuery >;
 
 	#[pallet::storage] // --- DMAP ( netuid ) --> active
-	pub(super) type Active<T:Config> = StorageMap< _, Identity, u16, Vec<bool>, ValueQuery, EmptyBoolVec<T> >;
+	pub(super) type Active<T:Config> = StorageMap< _, Identity, u16, UidVec<T, bool>, ValueQuery, EmptyBoolVec<T> >;
 	#[pallet::storage] // --- DMAP ( netuid ) --> rank
-	pub(super) type Rank<T:Config> = StorageMap< _, Identity, u16, Vec<u16>, ValueQuery, EmptyU16Vec<T>>;
+	pub(super) type Rank<T:Config> = StorageMap< _, Identity, u16, UidVec<T, u16>, ValueQuery, EmptyU16Vec<T>>;
+	#[pallet::storage] // --- DMAP ( netuid ) --> trust
+	pub(super) type Trust<T:Config> = StorageMap< _, Identity, u16, UidVec<T, u16>, ValueQuery, EmptyU16Vec<T>>;
+	#[pallet::storage] // --- DMAP ( netuid ) --> consensus
+	pub(super) type Consensus<T:Config> = StorageMap< _, Identity, u16, UidVec<T, u16>, ValueQuery, EmptyU16Vec<T>>;
 	#[pallet::storage] // --- DMAP ( netuid ) --> incentive
-	pub(super) type Incentive<T:Config> = StorageMap< _, Identity, u16, Vec<u16>, ValueQuery, EmptyU16Vec<T>>;
+	pub(super) type Incentive<T:Config> = StorageMap< _, Identity, u16, UidVec<T, u16>, ValueQuery, EmptyU16Vec<T>>;
 	#[pallet::storage] // --- DMAP ( netuid ) --> dividends
-	pub(super) type Dividends<T:Config> = StorageMap< _, Identity, u16, Vec<u16>, ValueQuery, EmptyU16Vec<T>>;
+	pub(super) type Dividends<T:Config> = StorageMap< _, Identity, u16, UidVec<T, u16>, ValueQuery, EmptyU16Vec<T>>;
 	#[pallet::storage] // --- DMAP ( netuid ) --> dividends
-	pub(super) type Emission<T:Config> = StorageMap< _, Identity, u16, Vec<u64>, ValueQuery, EmptyU64Vec<T>>;
+	pub(super) type Emission<T:Config> = StorageMap< _, Identity, u16, UidVec<T, u64>, ValueQuery, EmptyU64Vec<T>>;
+	#[pallet::storage] // --- DMAP ( netuid ) --> validator_permit
+	pub(super) type ValidatorPermits<T:Config> = StorageMap< _, Identity, u16, UidVec<T, bool>, ValueQuery, EmptyBoolVec<T> >;
 	#[pallet::storage] // --- DMAP ( netuid ) --> last_update
-	pub(super) type LastUpdate<T:Config> = StorageMap< _, Identity, u16, Vec<u64>, ValueQuery, EmptyU64Vec<T>>;
+	pub(super) type LastUpdate<T:Config> = StorageMap< _, Identity, u16, UidVec<T, u64>, ValueQuery, EmptyU64Vec<T>>;
 
 	#[pallet::storage] // --- DMAP ( netuid ) --> pruning_scores
-	pub(super) type PruningScores<T:Config> = StorageMap< _, Identity, u16, Vec<u16>, ValueQuery, EmptyU16Vec<T> >;
+	pub(super) type PruningScores<T:Config> = StorageMap< _, Identity, u16, UidVec<T, u16>, ValueQuery, EmptyU16Vec<T> >;
 
 
 	#[pallet::storage] // --- DMAP ( netuid, key ) --> uid
@@ -339,9 +619,28 @@ pub mod pallet {
 	#[pallet::storage] // --- DMAP ( netuid, uid ) --> key
 	pub(super) type Keys<T:Config> = StorageDoubleMap<_, Identity, u16, Identity, u16, T::AccountId, ValueQuery, DefaultKey<T> >;
 	#[pallet::storage] // --- DMAP ( netuid, uid ) --> weights
-    pub(super) type Weights<T:Config> = StorageDoubleMap<_, Identity, u16, Identity, u16, Vec<(u16, u16)>, ValueQuery, DefaultWeights<T> >;
+    pub(super) type Weights<T:Config> = StorageDoubleMap<_, Identity, u16, Identity, u16, UidVec<T, (u16, u16)>, ValueQuery, DefaultWeights<T> >;
+	#[pallet::storage] // --- DMAP ( netuid, key ) --> weights addressed by target key, resolved to uids at epoch time.
+    pub(super) type KeyWeights<T:Config> = StorageDoubleMap<_, Identity, u16, Blake2_128Concat, T::AccountId, UidVec<T, (T::AccountId, u16)>, OptionQuery>;
 	#[pallet::storage] // --- DMAP ( netuid, uid ) --> bonds
-    pub(super) type Bonds<T:Config> = StorageDoubleMap<_, Identity, u16, Identity, u16, Vec<(u16, u16)>, ValueQuery, DefaultBonds<T> >;
+    pub(super) type Bonds<T:Config> = StorageDoubleMap<_, Identity, u16, Identity, u16, UidVec<T, (u16, u16)>, ValueQuery, DefaultBonds<T> >;
+
+	// ===============================
+	// ==== Weights Commit-Reveal ====
+	// ===============================
+	#[pallet::type_value]
+	pub fn DefaultCommitRevealEnabled<T: Config>() -> bool { false }
+	#[pallet::type_value]
+	pub fn DefaultWeightsRevealWindow<T: Config>() -> u64 { 100 }
+
+	#[pallet::storage] // --- MAP ( netuid ) --> commit_reveal_enabled
+	pub type CommitRevealEnabled<T> = StorageMap<_, Identity, u16, bool, ValueQuery, DefaultCommitRevealEnabled<T> >;
+	#[pallet::storage] // --- MAP ( netuid ) --> weights_reveal_window
+	pub type WeightsRevealWindow<T> = StorageMap<_, Identity, u16, u64, ValueQuery, DefaultWeightsRevealWindow<T> >;
+	#[pallet::storage] // --- DMAP ( netuid, key ) --> ( commit_hash, commit_block )
+	pub(super) type WeightCommits<T:Config> = StorageDoubleMap<_, Identity, u16, Blake2_128Concat, T::AccountId, (H256, u64), OptionQuery>;
+	#[pallet::storage] // --- DMAP ( netuid, expiry_block ) --> keys whose commitment expires at the block
+	pub(super) type WeightCommitExpiries<T:Config> = StorageDoubleMap<_, Identity, u16, Identity, u64, UidVec<T, T::AccountId>, ValueQuery>;
 
 	// Pallets use events to inform users when important changes are made.
 	// https://docs.substrate.io/main-docs/build/events-errors/
@@ -355,10 +654,16 @@ pub mod pallet {
 		StakeAdded( T::AccountId, u64 ), // --- Event created when stake has been transfered from the a key account onto the key staking account.
 		StakeRemoved( T::AccountId, u64 ), // --- Event created when stake has been removed from the key staking account onto the key account.
 		WeightsSet( u16, u16 ), // ---- Event created when a caller successfully set's their weights on a network.
+		WeightsCommitted( u16, u16 ), // ---- Event created when a caller commits to a weights hash on a commit-reveal network.
+		WeightsCommitExpired( u16, T::AccountId ), // ---- Event created when an unrevealed weights commitment is dropped after the reveal window.
+		CommitRevealEnabledSet( u16, bool ), // --- Event created when commit-reveal weight setting is toggled for a network.
+		WeightsRevealWindowSet( u16, u64 ), // --- Event created when the weights reveal window is set for a network.
 		ModuleRegistered( u16, u16, T::AccountId ), // --- Event created when a new module account has been registered to the chain.
+		ModuleDeregistered( u16, u16, T::AccountId ), // --- Event created when a module account is removed from a network and its uid is reused.
 		BulkModulesRegistered( u16, u16 ), // --- Event created when multiple uids have been concurrently registered.
 		BulkBalancesSet(u16, u16),
 		MaxAllowedUidsSet( u16, u16 ), // --- Event created when max allowed uids has been set for a networkwor.
+		MaxAllowedValidatorsSet( u16, u16 ), // --- Event created when the max number of validator permits has been set for a network.
 		MaxWeightLimitSet( u16, u16 ), // --- Event created when the max weight limit has been set.
 		AdjustmentIntervalSet( u16, u16 ), // --- Event created when the adjustment interval is set for a network.
 		RegistrationPerIntervalSet( u16, u16 ), // --- Event created when registeration per interval is set for a network.
@@ -366,12 +671,29 @@ pub mod pallet {
 		ActivityCutoffSet( u16, u16 ), // --- Event created when an activity cutoff is set for a network.
 		MinAllowedWeightSet( u16, u16 ), // --- Event created when minimun allowed weight is set for a network.
 		WeightsSetRateLimitSet( u16, u64 ), // --- Event create when weights set rate limit has been set for a network.
+		WeightsVersionKeySet( u16, u64 ), // --- Event created when the weights version key required by a network is set.
+		WeightsDecayHalfLifeSet( u16, u64 ), // --- Event created when the weights decay half-life is set for a network.
+		InactivePruneWindowSet( u16, u64 ), // --- Event created when the inactive prune window is set for a network.
+		InactivePruneMinStakeSet( u16, u64 ), // --- Event created when the inactive prune min stake is set for a network.
+		ModulesFlaggedForPruning( u16, Vec<u16> ), // --- Event created when an epoch flags inactive uids to deregister at the next epoch.
 		ImmunityPeriodSet( u16, u16), // --- Event created when immunity period is set for a network.
+		BondsMovingAverageSet( u16, u64 ), // --- Event created when bonds moving average is set for a network.
+		RhoSet( u16, u16 ), // --- Event created when rho is set for a network.
+		KappaSet( u16, u16 ), // --- Event created when kappa is set for a network.
+		IncentiveRatioSet( u16, u16 ), // --- Event created when the incentive ratio is set for a network.
+		ConsensusMechanismSet( u16, ConsensusMechanismKind ), // --- Event created when the consensus mechanism is set for a network.
 		ModuleServed( u16, T::AccountId ), // --- Event created when the module server information is added to the network.
 		PrometheusServed( u16, T::AccountId ), // --- Event created when the module server information is added to the network.
 		EmissionValuesSet(), // --- Event created when emission ratios fr all networks is set.
 		ServingRateLimitSet( u16, u64 ), // --- Event created when setting the prometheus serving rate limit.
+		AllowPrivateIpsSet( u16, bool ), // --- Event created when serving private ip addresses is allowed or disallowed on a network.
 		TxRateLimitSet( u64 ), // --- Event created when setting the transaction rate limit.
+		TreasuryCutSet( u16 ), // --- Event created when setting the percentage of the block emission paid to the treasury.
+		HyperparameterChangeQueued( u16, HyperparameterChange, u64 ), // --- Event created when a hyperparameter change is queued for a network (apply_at_block = 0 means next epoch).
+		HyperparameterChangeCancelled( u16, HyperparameterChange ), // --- Event created when a queued hyperparameter change is removed from a network queue.
+		HyperparameterChangeApplied( u16, HyperparameterChange ), // --- Event created when a queued hyperparameter change takes effect on a network.
+		EpochCompleted( u16, u64, u64, u16, u16, u16 ), // --- Event created when an epoch wrote back ( netuid, block, emission_total, active_count, validator_count, top_incentive_uid ).
+		EmissionPaid( u16, Vec<(T::AccountId, u64)> ), // --- Event created when a batch of emission tuples ( key, amount ) is paid out on a network.
 	}
 
 	// Errors inform users that something went wrong.
@@ -408,7 +730,29 @@ pub mod pallet {
 		MaxAllowedUidsExceeded, // --- Thrown when number of accounts going to be registered exceed MaxAllowedUids for the network.
 		TooManyUids, // ---- Thrown when the caller attempts to set weights with more uids than allowed.
 		TxRateLimitExceeded, // --- Thrown when a transactor exceeds the rate limit for transactions.
-		RegistrationDisabled // --- Thrown when registration is disabled
+		RegistrationDisabled, // --- Thrown when registration is disabled
+		DuplicateNetuids, // ---- Thrown when the caller sets weights on the same network twice in one batch.
+		InvalidTargetKey, // ---- Thrown when a caller attempts to set weight on a key which is not registered on the network.
+		WeightsSetByKey, // ---- Thrown when patching a weights row which was set by target key.
+		EmptyWeights, // ---- Thrown when patching removes every weight of the row.
+		InvalidScheduleBlock, // --- Thrown when a hyperparameter change is scheduled for a block that is not in the future.
+		ScheduledChangeDoesNotExist, // --- Thrown when cancelling a queued hyperparameter change at an index that does not exist.
+		CommitRevealRequired, // --- Thrown when calling set_weights on a network which only accepts committed and revealed weights.
+		CommitRevealDisabled, // --- Thrown when committing or revealing weights on a network which does not run commit-reveal.
+		NoWeightsCommit, // --- Thrown when revealing weights without a pending commitment.
+		RevealTooEarly, // --- Thrown when revealing weights in the same block as the commitment.
+		RevealWindowExpired, // --- Thrown when revealing weights after the commitment has expired.
+		InvalidWeightsReveal, // --- Thrown when the revealed weights and salt do not match the commitment.
+		TooManyWeightCommits, // --- Thrown when more commitments expire at the same block than the network has uids.
+		TooManyEndpoints, // --- Thrown when serving more endpoints than MaxModuleEndpoints.
+		InvalidPort, // --- Thrown when an endpoint is served on port 0.
+		InvalidHostname, // --- Thrown when an endpoint hostname is not a valid DNS name.
+		NameTooLong, // --- Thrown when serving a module name longer than MaxNameLength.
+		UriTooLong, // --- Thrown when serving a module uri longer than MaxUriLength.
+		TooManyScheduledChanges, // --- Thrown when queueing a hyperparameter change on a network which already holds MaxScheduledChanges.
+		TooManyTags, // --- Thrown when serving more tags than MaxModuleTags.
+		InvalidTag, // --- Thrown when a tag is empty, longer than MaxTagLength or not lowercase alphanumerics and hyphens.
+		DuplicateTags, // --- Thrown when serving the same tag twice.
 	}
 
 	// ==================
@@ -471,14 +815,17 @@ pub mod pallet {
 
 				let (stake, uid) = stake_uid;
 
-				// Expand Yuma Consensus with new position.
-				Rank::<T>::mutate(netuid, |v| v.push(0));
-				Active::<T>::mutate(netuid, |v| v.push(true));
-				Emission::<T>::mutate(netuid, |v| v.push(0));
-				Incentive::<T>::mutate(netuid, |v| v.push(0));
-				Dividends::<T>::mutate(netuid, |v| v.push(0));
-				LastUpdate::<T>::mutate(netuid, |v| v.push(0));
-				PruningScores::<T>::mutate(netuid, |v| v.push(0));
+				// Expand Yuma Consensus with new position, max_uids is within the uid bound so the pushes always fit.
+				Rank::<T>::mutate( netuid, |v| Pallet::<T>::push_uid_value( v, 0 ) );
+				Trust::<T>::mutate( netuid, |v| Pallet::<T>::push_uid_value( v, 0 ) );
+				Consensus::<T>::mutate( netuid, |v| Pallet::<T>::push_uid_value( v, 0 ) );
+				Active::<T>::mutate( netuid, |v| Pallet::<T>::push_uid_value( v, true ) );
+				ValidatorPermits::<T>::mutate( netuid, |v| Pallet::<T>::push_uid_value( v, false ) );
+				Emission::<T>::mutate( netuid, |v| Pallet::<T>::push_uid_value( v, 0 ) );
+				Incentive::<T>::mutate( netuid, |v| Pallet::<T>::push_uid_value( v, 0 ) );
+				Dividends::<T>::mutate( netuid, |v| Pallet::<T>::push_uid_value( v, 0 ) );
+				LastUpdate::<T>::mutate( netuid, |v| Pallet::<T>::push_uid_value( v, 0 ) );
+				PruningScores::<T>::mutate( netuid, |v| Pallet::<T>::push_uid_value( v, 0 ) );
 		
 				// Insert account information.
 				Keys::<T>::insert(netuid, uid, key.clone()); // Make key - uid association.
@@ -517,11 +864,16 @@ pub mod pallet {
 		// 	* 'n': (T::BlockNumber):
 		// 		- The number of the block we are initializing.
 		fn on_initialize( _block_number: BlockNumberFor<T> ) -> Weight {
-			Self::block_step();
-			
-			return Weight::from_ref_time(110_634_229_000 as u64)
-						.saturating_add(T::DbWeight::get().reads(8304 as u64))
-						.saturating_add(T::DbWeight::get().writes(110 as u64));
+			// The block step reports the weight of the work it actually did.
+			Self::block_step()
+		}
+
+		// ---- Called once when the runtime is upgraded, before the on_initialize of its first block.
+		//
+		// # Returns:
+		// 	* The weight of the storage migrations which ran.
+		fn on_runtime_upgrade() -> Weight {
+			crate::migrations::migrate_to_v1::<T>()
 		}
 	}
 
@@ -558,6 +910,9 @@ pub mod pallet {
 		// 		- The u16 integer encoded weights. Interpreted as rational
 		// 		values in the range [0,1]. They must sum to in32::MAX.
 		//
+		// 	* 'version_key' ( u64 ):
+		// 		- The network version key of the validator software setting the weights.
+		//
 		// # Event:
 		// 	* WeightsSet;
 		// 		- On successfully setting the weights on chain.
@@ -569,6 +924,9 @@ pub mod pallet {
 		// 	* 'NotRegistered':
 		// 		- Attempting to set weights from a non registered account.
 		//
+		// 	* 'IncorrectNetworkVersionKey':
+		// 		- The version key is below the one required by the network.
+		//
 		// 	* 'WeightVecNotEqualSize':
 		// 		- Attempting to set weights with uids not of same length.
 		//
@@ -586,16 +944,214 @@ pub mod pallet {
 		//
 		// 	* 'MaxWeightExceeded':
 		// 		- Attempting to set weights with max value exceeding limit.
-        #[pallet::weight((Weight::from_ref_time(10_151_000_000)
-		.saturating_add(T::DbWeight::get().reads(4104))
-		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::No))]
+        #[pallet::weight((T::WeightInfo::set_weights(dests.len() as u32), DispatchClass::Normal, Pays::No))]
 		pub fn set_weights(
 			origin:OriginFor<T>, 
 			netuid: u16,
 			dests: Vec<u16>, 
 			weights: Vec<u16>,
+			version_key: u64,
+		) -> DispatchResult {
+			Self::do_set_weights( origin, netuid, dests, weights, version_key )
+		}
+
+		// --- Sets weights addressed by target key rather than uid. The keys are resolved to uids at every
+		// epoch, so the row survives uid replacement and only the entries whose key left the network are dropped.
+		//
+		// # Args:
+		// 	* `origin`: (<T as frame_system::Config>Origin):
+		// 		- The caller, a registered key.
+		//
+		// 	* `netuid` (u16):
+		// 		- The network uid we are setting these weights on.
+		//
+		// 	* `dest_keys` (Vec<T::AccountId>):
+		// 		- The keys of the modules receiving the weights.
+		//
+		// 	* 'weights' (Vec<u16>):
+		// 		- The u16 integer encoded weights.
+		//
+		// 	* 'version_key' ( u64 ):
+		// 		- The network version key of the validator software setting the weights.
+		//
+		// # Event:
+		// 	* WeightsSet;
+		// 		- On successfully setting the weights on chain.
+		//
+		// # Raises:
+		// 	* 'InvalidTargetKey':
+		// 		- One of the keys is not registered on the network.
+		//
+		// 	* Any of the errors raised by set_weights.
+        #[pallet::weight((T::WeightInfo::set_weights_by_key(dest_keys.len() as u32), DispatchClass::Normal, Pays::No))]
+		pub fn set_weights_by_key(
+			origin: OriginFor<T>,
+			netuid: u16,
+			dest_keys: Vec<T::AccountId>,
+			weights: Vec<u16>,
+			version_key: u64,
+		) -> DispatchResult {
+			Self::do_set_weights_by_key( origin, netuid, dest_keys, weights, version_key )
+		}
+
+		// --- Updates part of the caller's weights row. Upserted uids are inserted or overwritten, removed uids
+		// are dropped, and the merged row is renormalized and checked exactly like set_weights.
+		//
+		// # Args:
+		// 	* `origin`: (<T as frame_system::Config>Origin):
+		// 		- The caller, a registered key.
+		//
+		// 	* `netuid` (u16):
+		// 		- The network uid we are patching weights on.
+		//
+		// 	* `upserts` (Vec<(u16, u16)>):
+		// 		- The ( dest, weight ) pairs to insert or overwrite, on the scale of the stored row.
+		//
+		// 	* `removals` (Vec<u16>):
+		// 		- The dests to remove from the row.
+		//
+		// 	* 'version_key' ( u64 ):
+		// 		- The network version key of the validator software setting the weights.
+		//
+		// # Event:
+		// 	* WeightsSet;
+		// 		- On successfully setting the merged weights on chain.
+		//
+		// # Raises:
+		// 	* 'DuplicateUids':
+		// 		- A dest appears twice across the upserts and removals.
+		//
+		// 	* 'WeightsSetByKey':
+		// 		- The current row was set by target key and must be replaced with set_weights_by_key.
+		//
+		// 	* Any of the errors raised by set_weights.
+        #[pallet::weight((T::WeightInfo::patch_weights(( upserts.len() + removals.len() ) as u32), DispatchClass::Normal, Pays::No))]
+		pub fn patch_weights(
+			origin: OriginFor<T>,
+			netuid: u16,
+			upserts: Vec<(u16, u16)>,
+			removals: Vec<u16>,
+			version_key: u64,
+		) -> DispatchResult {
+			Self::do_patch_weights( origin, netuid, upserts, removals, version_key )
+		}
+
+		// --- Sets weights on several networks in a single call. Every entry is checked exactly like
+		// set_weights and either all of them are written or none are.
+		//
+		// # Args:
+		// 	* `origin`: (<T as frame_system::Config>Origin):
+		// 		- The caller, a key registered on each of the networks.
+		//
+		// 	* `network_weights` (Vec<(u16, Vec<u16>, Vec<u16>)>):
+		// 		- The ( netuid, dests, weights ) to set on each network.
+		//
+		// 	* 'version_key' ( u64 ):
+		// 		- The network version key of the validator software setting the weights.
+		//
+		// # Event:
+		// 	* WeightsSet;
+		// 		- One per network on successfully setting the weights on chain.
+		//
+		// # Raises:
+		// 	* 'DuplicateNetuids':
+		// 		- The same network appears more than once in the batch.
+		//
+		// 	* Any of the errors raised by set_weights.
+		#[pallet::weight((network_weights.iter().fold( Weight::zero(), |weight, ( _, dests, _ )| weight.saturating_add( T::WeightInfo::set_weights( dests.len() as u32 ) ) ),
+		DispatchClass::Normal, Pays::No))]
+		pub fn set_weights_multi(
+			origin: OriginFor<T>,
+			network_weights: Vec<(u16, Vec<u16>, Vec<u16>)>,
+			version_key: u64,
+		) -> DispatchResult {
+			Self::do_set_weights_multi( origin, network_weights, version_key )
+		}
+
+		// --- Commits to a set of weights on a commit-reveal network without exposing them. The hash is
+		// blake2_256 over the SCALE encoded ( key, netuid, dests, weights, salt ) which are later
+		// passed to reveal_weights. A new commitment replaces the previous unrevealed one.
+		//
+		// # Args:
+		// 	* `origin`: (<T as frame_system::Config>Origin):
+		// 		- The caller, a registered key.
+		//
+		// 	* `netuid` (u16):
+		// 		- The network uid we are committing weights on.
+		//
+		// 	* `commit_hash` (H256):
+		// 		- The hash of the weights to be revealed.
+		//
+		// # Event:
+		// 	* WeightsCommitted;
+		// 		- On successfully storing the commitment.
+		//
+		// # Raises:
+		// 	* 'CommitRevealDisabled':
+		// 		- The network does not run commit-reveal.
+		//
+		// 	* 'NotRegistered':
+		// 		- Attempting to commit from a non registered account.
+		//
+		// 	* 'SettingWeightsTooFast':
+		// 		- Attempting to commit faster than the weights_set_rate_limit.
+		#[pallet::weight((T::WeightInfo::commit_weights(), DispatchClass::Normal, Pays::No))]
+		pub fn commit_weights(
+			origin: OriginFor<T>,
+			netuid: u16,
+			commit_hash: H256,
+		) -> DispatchResult {
+			Self::do_commit_weights( origin, netuid, commit_hash )
+		}
+
+		// --- Reveals previously committed weights. The weights are checked exactly like set_weights and
+		// only become visible to the epoch once revealed within the network reveal window.
+		//
+		// # Args:
+		// 	* `origin`: (<T as frame_system::Config>Origin):
+		// 		- The caller, a registered key with a pending commitment.
+		//
+		// 	* `netuid` (u16):
+		// 		- The network uid we are revealing weights on.
+		//
+		// 	* `dests` (Vec<u16>):
+		// 		- The edge endpoint for the weight, i.e. j for w_ij.
+		//
+		// 	* 'weights' (Vec<u16>):
+		// 		- The u16 integer encoded weights.
+		//
+		// 	* 'salt' (Vec<u8>):
+		// 		- The salt used when computing the commit hash.
+		//
+		// 	* 'version_key' ( u64 ):
+		// 		- The network version key of the validator software revealing the weights.
+		//
+		// # Event:
+		// 	* WeightsSet;
+		// 		- On successfully revealing the weights.
+		//
+		// # Raises:
+		// 	* 'IncorrectNetworkVersionKey':
+		// 		- The version key is below the one required by the network.
+		//
+		// 	* 'NoWeightsCommit':
+		// 		- There is no pending commitment for the caller.
+		//
+		// 	* 'RevealWindowExpired':
+		// 		- The commitment is older than the reveal window.
+		//
+		// 	* 'InvalidWeightsReveal':
+		// 		- The weights and salt do not match the commitment.
+		#[pallet::weight((T::WeightInfo::reveal_weights(dests.len() as u32), DispatchClass::Normal, Pays::No))]
+		pub fn reveal_weights(
+			origin: OriginFor<T>,
+			netuid: u16,
+			dests: Vec<u16>,
+			weights: Vec<u16>,
+			salt: Vec<u8>,
+			version_key: u64,
 		) -> DispatchResult {
-			Self::do_set_weights( origin, netuid, dests, weights )
+			Self::do_reveal_weights( origin, netuid, dests, weights, salt, version_key )
 		}
 
 
@@ -631,9 +1187,7 @@ pub mod pallet {
 		// 		- Errors stemming from transaction pallet.
 		//
 		//
-		#[pallet::weight((Weight::from_ref_time(65_000_000)
-		.saturating_add(T::DbWeight::get().reads(8))
-		.saturating_add(T::DbWeight::get().writes(6)), DispatchClass::Normal, Pays::No))]
+		#[pallet::weight((T::WeightInfo::add_stake(), DispatchClass::Normal, Pays::No))]
 		pub fn add_stake(
 			origin: OriginFor<T>, 
 			amount_staked: u64
@@ -668,9 +1222,7 @@ pub mod pallet {
 		// 		- Thrown if we could not convert this amount to a balance.
 		//
 		//
-		#[pallet::weight((Weight::from_ref_time(66_000_000)
-		.saturating_add(T::DbWeight::get().reads(8))
-		.saturating_add(T::DbWeight::get().writes(6)), DispatchClass::Normal, Pays::No))]
+		#[pallet::weight((T::WeightInfo::remove_stake(), DispatchClass::Normal, Pays::No))]
 		pub fn remove_stake(
 			origin: OriginFor<T>, 
 			key: T::AccountId, 
@@ -689,26 +1241,20 @@ pub mod pallet {
 		// 	* 'netuid' (u16):
 		// 		- The u16 network identifier.
 		//
-		// 	* 'version' (u64):
-		// 		- The commune version identifier.
+		// 	* 'name' (Vec<u8>):
+		// 		- The module name, at most MaxNameLength bytes.
 		//
-		// 	* 'ip' (u64):
-		// 		- The endpoint ip information as a u128 encoded integer.
+		// 	* 'endpoints' (Vec<ModuleEndpoint>):
+		// 		- The endpoints the module is served at, at most MaxModuleEndpoints. Each holds its
+		// 		transport (TCP or UDP), ip version (4 or 6), u128 encoded ip, port and optional DNS hostname.
 		//
-		// 	* 'port' (u16):
-		// 		- The endpoint port information as a u16 encoded integer.
-		// 
-		// 	* 'ip_type' (u8):
-		// 		- The endpoint ip version as a u8, 4 or 6.
-		//
-		// 	* 'protocol' (u8):
-		// 		- UDP:1 or TCP:0 
+		// 	* 'uri' (Vec<u8>):
+		// 		- The module uri, at most MaxUriLength bytes.
 		//
-		// 	* 'placeholder1' (u8):
-		// 		- Placeholder for further extra params.
-		//
-		// 	* 'placeholder2' (u8):
-		// 		- Placeholder for further extra params.
+		// 	* 'tags' (Vec<Vec<u8>>):
+		// 		- The capability tags of the module, at most MaxModuleTags. Each is at most MaxTagLength
+		// 		bytes of lowercase alphanumerics and inner hyphens, e.g. b"text-embedding". Replaces the
+		// 		previously served tags.
 		//
 		// # Event:
 		// 	* ModuleServed;
@@ -716,30 +1262,55 @@ pub mod pallet {
 		//
 		// # Raises:
 		// 	* 'NetworkDoesNotExist':
-		// 		- Attempting to set weights on a non-existent network.
+		// 		- Attempting to serve on a non-existent network.
 		//
 		// 	* 'NotRegistered':
-		// 		- Attempting to set weights from a non registered account.
+		// 		- Attempting to serve from an account not registered on the network.
+		//
+		// 	* 'NameTooLong':
+		// 		- The name is longer than MaxNameLength.
+		//
+		// 	* 'UriTooLong':
+		// 		- The uri is longer than MaxUriLength.
+		//
+		// 	* 'TooManyEndpoints':
+		// 		- More endpoints than MaxModuleEndpoints.
 		//
 		// 	* 'InvalidIpType':
-		// 		- The ip type is not 4 or 6.
+		// 		- An endpoint ip type is not 4 or 6.
 		//
 		// 	* 'InvalidIpAddress':
-		// 		- The numerically encoded ip address does not resolve to a proper ip.
+		// 		- An endpoint numerically encoded ip address does not resolve to a proper ip, or falls in a
+		// 		non-public range (unspecified, loopback, private, link-local, multicast, documentation).
+		//
+		// 	* 'InvalidPort':
+		// 		- An endpoint port is 0.
+		//
+		// 	* 'InvalidHostname':
+		// 		- An endpoint hostname is not a valid DNS name.
+		//
+		// 	* 'TooManyTags':
+		// 		- More tags than MaxModuleTags.
+		//
+		// 	* 'InvalidTag':
+		// 		- A tag is empty, longer than MaxTagLength or holds other than lowercase alphanumerics and inner hyphens.
+		//
+		// 	* 'DuplicateTags':
+		// 		- The same tag is served twice.
 		//
 		// 	* 'ServingRateLimitExceeded':
-		// 		- Attempting to set prometheus information withing the rate limit min.
+		// 		- Attempting to serve the module within the rate limit min.
 		//
-		#[pallet::weight((Weight::from_ref_time(19_000_000)
-		.saturating_add(T::DbWeight::get().reads(2))
-		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::No))]
+		#[pallet::weight((T::WeightInfo::serve_module( endpoints.len() as u32, T::MaxModuleTags::get() ), DispatchClass::Normal, Pays::No))]
 		pub fn serve_module(
 			origin:OriginFor<T>, 
 			netuid: u16,
-			ip: u128, 
-			port: u16, 
+			name: Vec<u8>,
+			endpoints: Vec<ModuleEndpoint>,
+			uri: Vec<u8>,
+			tags: Vec<Vec<u8>>,
 		) -> DispatchResult {
-			Self::do_serve_module( origin, netuid, version, ip, port ) 
+			Self::do_serve_module( origin, netuid, name, endpoints, uri, tags ) 
 		}
 
 		// ---- Registers a new module to the network. 
@@ -782,9 +1353,7 @@ pub mod pallet {
 		//
 
 		
-		#[pallet::weight((Weight::from_ref_time(91_000_000)
-		.saturating_add(T::DbWeight::get().reads(27))
-		.saturating_add(T::DbWeight::get().writes(22)), DispatchClass::Normal, Pays::No))]
+		#[pallet::weight((T::WeightInfo::register(), DispatchClass::Normal, Pays::No))]
 		pub fn register( 
 				origin:OriginFor<T>, 
 				netuid: u16,
@@ -811,96 +1380,176 @@ pub mod pallet {
 		//
 		// 	* `hyperparameter value` (u16):
 		// 		- The value of the hyper parameter.
+		//
+		// The parameters the epoch reads are not set right away, the change is queued as a scheduled
+		// hyperparameter change applied right before the next epoch of the network.
 		//   
 
-		#[pallet::weight((Weight::from_ref_time(10_000_000)
-		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_set_serving_rate_limit(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_serving_rate_limit( origin:OriginFor<T>, netuid: u16, serving_rate_limit: u64 ) -> DispatchResult {  
 			Self::do_sudo_set_serving_rate_limit( origin, netuid, serving_rate_limit )
 		}
 
+		#[pallet::weight((T::WeightInfo::sudo_set_allow_private_ips(), DispatchClass::Operational, Pays::No))]
+		pub fn sudo_set_allow_private_ips( origin:OriginFor<T>, netuid: u16, allow_private_ips: bool ) -> DispatchResult {  
+			Self::do_sudo_set_allow_private_ips( origin, netuid, allow_private_ips )
+		}
+
 		// Sudo call for setting tx rate limit
-		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_set_tx_rate_limit(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_tx_rate_limit( origin:OriginFor<T>, tx_rate_limit: u64 ) -> DispatchResult {  
 			Self::do_sudo_set_tx_rate_limit( origin, tx_rate_limit )
 		}
 
-		#[pallet::weight((Weight::from_ref_time(15_000_000)
-		.saturating_add(T::DbWeight::get().reads(1))
-		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::No))]
+		// Sudo call for setting the percentage of the block emission paid to the treasury account.
+		#[pallet::weight((T::WeightInfo::sudo_set_treasury_cut(), DispatchClass::Operational, Pays::No))]
+		pub fn sudo_set_treasury_cut( origin:OriginFor<T>, treasury_cut: u16 ) -> DispatchResult {  
+			Self::do_sudo_set_treasury_cut( origin, treasury_cut )
+		}
+
+		#[pallet::weight((T::WeightInfo::sudo_set_weights_set_rate_limit(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_weights_set_rate_limit( origin:OriginFor<T>, netuid: u16, weights_set_rate_limit: u64 ) -> DispatchResult {  
 			Self::do_sudo_set_weights_set_rate_limit( origin, netuid, weights_set_rate_limit )
 		}
-		#[pallet::weight((Weight::from_ref_time(14_000_000)
-		.saturating_add(T::DbWeight::get().reads(1))
-		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_set_weights_version_key(), DispatchClass::Operational, Pays::No))]
+		pub fn sudo_set_weights_version_key( origin:OriginFor<T>, netuid: u16, weights_version_key: u64 ) -> DispatchResult {
+			Self::do_sudo_set_weights_version_key( origin, netuid, weights_version_key )
+		}
+		#[pallet::weight((T::WeightInfo::sudo_set_weights_decay_half_life(), DispatchClass::Operational, Pays::No))]
+		pub fn sudo_set_weights_decay_half_life( origin:OriginFor<T>, netuid: u16, weights_decay_half_life: u64 ) -> DispatchResult {
+			Self::do_sudo_set_weights_decay_half_life( origin, netuid, weights_decay_half_life )
+		}
+		#[pallet::weight((T::WeightInfo::sudo_set_inactive_prune_window(), DispatchClass::Operational, Pays::No))]
+		pub fn sudo_set_inactive_prune_window( origin:OriginFor<T>, netuid: u16, inactive_prune_window: u64 ) -> DispatchResult {
+			Self::do_sudo_set_inactive_prune_window( origin, netuid, inactive_prune_window )
+		}
+		#[pallet::weight((T::WeightInfo::sudo_set_inactive_prune_min_stake(), DispatchClass::Operational, Pays::No))]
+		pub fn sudo_set_inactive_prune_min_stake( origin:OriginFor<T>, netuid: u16, inactive_prune_min_stake: u64 ) -> DispatchResult {
+			Self::do_sudo_set_inactive_prune_min_stake( origin, netuid, inactive_prune_min_stake )
+		}
+		#[pallet::weight((T::WeightInfo::sudo_set_bonds_moving_average(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_bonds_moving_average( origin:OriginFor<T>, netuid: u16, bonds_moving_average: u64 ) -> DispatchResult {  
 			Self::do_sudo_set_bonds_moving_average( origin, netuid, bonds_moving_average )
 		}
-		#[pallet::weight((Weight::from_ref_time(14_000_000)
-		.saturating_add(T::DbWeight::get().reads(1))
-		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_set_max_allowed_validators(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_max_allowed_validators( origin:OriginFor<T>, netuid: u16, max_allowed_validators: u16 ) -> DispatchResult {  
 			Self::do_sudo_set_max_allowed_validators( origin, netuid, max_allowed_validators )
 		}
-		#[pallet::weight((Weight::from_ref_time(13_000_000)
-		.saturating_add(T::DbWeight::get().reads(1))
-		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_set_difficulty(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_difficulty( origin:OriginFor<T>, netuid: u16, difficulty: u64 ) -> DispatchResult {
 			Self::do_sudo_set_difficulty( origin, netuid, difficulty )
 		}
-		#[pallet::weight((Weight::from_ref_time(14_000_000)
-		.saturating_add(T::DbWeight::get().reads(1))
-		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_set_adjustment_interval(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_adjustment_interval( origin:OriginFor<T>, netuid: u16, adjustment_interval: u16 ) -> DispatchResult { 
 			Self::do_sudo_set_adjustment_interval( origin, netuid, adjustment_interval )
 		}
-		#[pallet::weight((Weight::from_ref_time(14_000_000)
-		.saturating_add(T::DbWeight::get().reads(1))
-		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_set_target_registrations_per_interval(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_target_registrations_per_interval( origin:OriginFor<T>, netuid: u16, target_registrations_per_interval: u16 ) -> DispatchResult {
 			Self::do_sudo_set_target_registrations_per_interval( origin, netuid, target_registrations_per_interval )
 		}
-		#[pallet::weight((Weight::from_ref_time(13_000_000)
-		.saturating_add(T::DbWeight::get().reads(1))
-		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_set_activity_cutoff(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_activity_cutoff( origin:OriginFor<T>, netuid: u16, activity_cutoff: u16 ) -> DispatchResult {
 			Self::do_sudo_set_activity_cutoff( origin, netuid, activity_cutoff )
 		}
 
-		#[pallet::weight((Weight::from_ref_time(18_000_000)
-		.saturating_add(T::DbWeight::get().reads(2))
-		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_set_max_allowed_uids(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_max_allowed_uids( origin:OriginFor<T>, netuid: u16, max_allowed_uids: u16 ) -> DispatchResult {
 			Self::do_sudo_set_max_allowed_uids(origin, netuid, max_allowed_uids )
 		}
-		#[pallet::weight((Weight::from_ref_time(13_000_000)
-		.saturating_add(T::DbWeight::get().reads(1))
-		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_set_min_allowed_weights(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_min_allowed_weights( origin:OriginFor<T>, netuid: u16, min_allowed_weights: u16 ) -> DispatchResult {
 			Self::do_sudo_set_min_allowed_weights( origin, netuid, min_allowed_weights )
 		}
 
 
-		#[pallet::weight((Weight::from_ref_time(13_000_000)
-		.saturating_add(T::DbWeight::get().reads(1))
-		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_set_immunity_period(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_immunity_period( origin:OriginFor<T>, netuid: u16, immunity_period: u16 ) -> DispatchResult {
 			Self::do_sudo_set_immunity_period( origin, netuid, immunity_period )
 		}
-		#[pallet::weight((Weight::from_ref_time(13_000_000)
-		.saturating_add(T::DbWeight::get().reads(1))
-		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_set_max_weight_limit(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_max_weight_limit( origin:OriginFor<T>, netuid: u16, max_weight_limit: u16 ) -> DispatchResult {
 			Self::do_sudo_set_max_weight_limit( origin, netuid, max_weight_limit )
 		}
-		#[pallet::weight((Weight::from_ref_time(15_000_000)
-		.saturating_add(T::DbWeight::get().reads(1))
-		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((T::WeightInfo::sudo_set_max_registrations_per_block(), DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_max_registrations_per_block(origin: OriginFor<T>, netuid: u16, max_registrations_per_block: u16 ) -> DispatchResult {
 			Self::do_sudo_set_max_registrations_per_block(origin, netuid, max_registrations_per_block )
 		}
+		#[pallet::weight((T::WeightInfo::sudo_set_rho(), DispatchClass::Operational, Pays::No))]
+		pub fn sudo_set_rho( origin:OriginFor<T>, netuid: u16, rho: u16 ) -> DispatchResult {
+			Self::do_sudo_set_rho( origin, netuid, rho )
+		}
+		#[pallet::weight((T::WeightInfo::sudo_set_kappa(), DispatchClass::Operational, Pays::No))]
+		pub fn sudo_set_kappa( origin:OriginFor<T>, netuid: u16, kappa: u16 ) -> DispatchResult {
+			Self::do_sudo_set_kappa( origin, netuid, kappa )
+		}
+		#[pallet::weight((T::WeightInfo::sudo_set_incentive_ratio(), DispatchClass::Operational, Pays::No))]
+		pub fn sudo_set_incentive_ratio( origin:OriginFor<T>, netuid: u16, incentive_ratio: u16 ) -> DispatchResult {
+			Self::do_sudo_set_incentive_ratio( origin, netuid, incentive_ratio )
+		}
+		#[pallet::weight((T::WeightInfo::sudo_set_consensus_mechanism(), DispatchClass::Operational, Pays::No))]
+		pub fn sudo_set_consensus_mechanism( origin:OriginFor<T>, netuid: u16, consensus_mechanism: ConsensusMechanismKind ) -> DispatchResult {
+			Self::do_sudo_set_consensus_mechanism( origin, netuid, consensus_mechanism )
+		}
 
+		// ---- Queues a hyperparameter change on a network. The change is applied right before the
+		// network's next epoch runs, or at the requested block when 'apply_at_block' is non zero.
+		//
+		// # Args:
+		// 	* 'origin': (<T as frame_system::Config>Origin):
+		// 		- The caller, must be sudo.
+		//
+		// 	* `netuid` (u16):
+		// 		- The network identifier.
+		//
+		// 	* `change` (HyperparameterChange):
+		// 		- The hyperparameter and its new value.
+		//
+		// 	* `apply_at_block` (u64):
+		// 		- The block at which the change is applied, 0 for the next epoch boundary.
+		//
+		// # Event:
+		// 	* HyperparameterChangeQueued;
+		// 		- On successfully queueing the change.
+		//
+		// # Raises:
+		// 	* 'NetworkDoesNotExist':
+		// 		- Attempting to schedule a change on a non-existent network.
+		//
+		// 	* 'InvalidScheduleBlock':
+		// 		- The requested block is not in the future.
+		//
+		// 	* 'TooManyScheduledChanges':
+		// 		- The network queue already holds MaxScheduledChanges changes.
+		//
+		#[pallet::weight((T::WeightInfo::sudo_schedule_hyperparameter_change(), DispatchClass::Operational, Pays::No))]
+		pub fn sudo_schedule_hyperparameter_change( origin: OriginFor<T>, netuid: u16, change: HyperparameterChange, apply_at_block: u64 ) -> DispatchResult {
+			Self::do_sudo_schedule_hyperparameter_change( origin, netuid, change, apply_at_block )
+		}
+
+		// ---- Removes the queued hyperparameter change at 'index' from the network queue.
+		//
+		// # Event:
+		// 	* HyperparameterChangeCancelled;
+		// 		- On successfully removing the change.
+		//
+		// # Raises:
+		// 	* 'ScheduledChangeDoesNotExist':
+		// 		- There is no queued change at this index.
+		//
+		#[pallet::weight((T::WeightInfo::sudo_cancel_hyperparameter_change(), DispatchClass::Operational, Pays::No))]
+		pub fn sudo_cancel_hyperparameter_change( origin: OriginFor<T>, netuid: u16, index: u32 ) -> DispatchResult {
+			Self::do_sudo_cancel_hyperparameter_change( origin, netuid, index )
+		}
+
+
+		#[pallet::weight((T::WeightInfo::sudo_set_commit_reveal_enabled(), DispatchClass::Operational, Pays::No))]
+		pub fn sudo_set_commit_reveal_enabled( origin:OriginFor<T>, netuid: u16, enabled: bool ) -> DispatchResult {
+			Self::do_sudo_set_commit_reveal_enabled( origin, netuid, enabled )
+		}
+		#[pallet::weight((T::WeightInfo::sudo_set_weights_reveal_window(), DispatchClass::Operational, Pays::No))]
+		pub fn sudo_set_weights_reveal_window( origin:OriginFor<T>, netuid: u16, weights_reveal_window: u64 ) -> DispatchResult {
+			Self::do_sudo_set_weights_reveal_window( origin, netuid, weights_reveal_window )
+		}
 
 		// Benchmarking functions.
 		#[pallet::weight((0, DispatchClass::Normal, Pays::No))]
@@ -938,7 +1587,7 @@ pub mod pallet {
 				let mut zipped_weights: Vec<( u16, u16 )> = vec![];
 				for ( uid, val ) in uids.iter().zip(normalized_values.iter()) { zipped_weights.push((*uid, *val)) }
 				if uid < n_vals {
-					Weights::<T>::insert( netuid, uid, zipped_weights );
+					Weights::<T>::insert( netuid, uid, Self::to_uid_vec( zipped_weights ) );
 				} else {
 					break;
 				}
@@ -1065,6 +1714,31 @@ impl<T: Config + Send + Sync + TypeInfo> SignedExtension for SubspaceSignedExten
                     longevity: 1,
                     ..Default::default()
                 })
+            }
+			Some(Call::commit_weights{netuid, ..}) | Some(Call::reveal_weights{netuid, ..}) => {
+				let priority: u64 = Self::get_priority_set_weights(who, *netuid);
+                Ok(ValidTransaction {
+                    priority: priority,
+                    longevity: 1,
+                    ..Default::default()
+                })
+            }
+			Some(Call::set_weights_by_key{netuid, ..}) | Some(Call::patch_weights{netuid, ..}) => {
+				let priority: u64 = Self::get_priority_set_weights(who, *netuid);
+                Ok(ValidTransaction {
+                    priority: priority,
+                    longevity: 1,
+                    ..Default::default()
+                })
+            }
+			Some(Call::set_weights_multi{network_weights, ..}) => {
+				// The batch is only as urgent as its most recently updated network.
+				let priority: u64 = network_weights.iter().map( |(netuid, _, _)| Self::get_priority_set_weights(who, *netuid) ).min().unwrap_or(0);
+                Ok(ValidTransaction {
+                    priority: priority,
+                    longevity: 1,
+                    ..Default::default()
+                })
             }
 			Some(Call::add_stake{..}) => {
                 Ok(ValidTransaction {
@@ -1111,7 +1785,7 @@ impl<T: Config + Send + Sync + TypeInfo> SignedExtension for SubspaceSignedExten
 				let transaction_fee = 0;
                 Ok((CallType::RemoveStake, transaction_fee, who.clone()))
             }
-			Some(Call::set_weights{..}) => {
+			Some(Call::set_weights{..}) | Some(Call::commit_weights{..}) | Some(Call::reveal_weights{..}) | Some(Call::set_weights_multi{..}) | Some(Call::set_weights_by_key{..}) | Some(Call::patch_weights{..}) => {
 				let transaction_fee = 0;
                 Ok((CallType::SetWeights, transaction_fee, who.clone())) 
             }
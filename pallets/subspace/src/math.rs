@@ -719,21 +719,29 @@ pub fn mat_ema( new: &Vec<Vec<I32F32>>, old: &Vec<Vec<I32F32>>, alpha: I32F32 )
 pub fn mat_ema_sparse( new: &Vec<Vec<(u16, I32F32)>>, old: &Vec<Vec<(u16, I32F32)>>, alpha: I32F32 ) -> Vec<Vec<(u16, I32F32)>> {
     assert!(new.len() == old.len());
     let n = new.len(); // assume square matrix, rows=cols
-    let zero: I32F32 = I32F32::from_num( 0.0 );
-    let one_minus_alpha:I32F32 = I32F32::from_num( 1.0 ) - alpha;
     let mut result: Vec<Vec<(u16, I32F32)>> = vec![ vec![]; n ];
     for i in 0..new.len() {
-        let mut row: Vec<I32F32> = vec![ zero; n];
-        for (j, value) in new[i].iter() {
-            row[*j as usize] += alpha * value;
-        }
-        for (j, value) in old[i].iter() {
-            row[*j as usize] += one_minus_alpha * value;
-        }
-        for (j, value) in row.iter().enumerate() {
-            if *value > zero {
-                result[i].push( (j as u16, *value) )
-            }
+        result[i] = row_ema_sparse( &new[i], &old[i], alpha, n as u16 );
+    }
+    result
+}
+
+// Return the exponential moving average of a single sparse row: `alpha * a_j + one_minus_alpha * b_j`,
+// keeping the positive entries. Rows of mat_ema_sparse, so that a matrix can be averaged a chunk of rows at a time.
+pub fn row_ema_sparse( new: &Vec<(u16, I32F32)>, old: &Vec<(u16, I32F32)>, alpha: I32F32, columns: u16 ) -> Vec<(u16, I32F32)> {
+    let zero: I32F32 = I32F32::from_num( 0.0 );
+    let one_minus_alpha:I32F32 = I32F32::from_num( 1.0 ) - alpha;
+    let mut row: Vec<I32F32> = vec![ zero; columns as usize ];
+    for (j, value) in new.iter() {
+        row[*j as usize] += alpha * value;
+    }
+    for (j, value) in old.iter() {
+        row[*j as usize] += one_minus_alpha * value;
+    }
+    let mut result: Vec<(u16, I32F32)> = vec![];
+    for (j, value) in row.iter().enumerate() {
+        if *value > zero {
+            result.push( (j as u16, *value) )
         }
     }
     result
@@ -753,6 +761,27 @@ pub fn sparse_threshold( w: &Vec<Vec<(u16, I32F32)>>, threshold: I32F32 ) -> Vec
     sparse_threshold_result
 }
 
+// Return the raw bits of each fixed point value, used to persist intermediate values losslessly.
+#[allow(dead_code)]
+pub fn vec_fixed_to_bits( vector: &Vec<I32F32> ) -> Vec<i64> { vector.iter().map( |x| x.to_bits() ).collect() }
+
+// Return the fixed point values of raw bits produced by vec_fixed_to_bits.
+#[allow(dead_code)]
+pub fn vec_fixed_from_bits( vector: &Vec<i64> ) -> Vec<I32F32> { vector.iter().map( |x| I32F32::from_bits( *x ) ).collect() }
+
+// Return the decay factor 2^(-age / half_life) of an observation, interpolated linearly between whole
+// half-lives so that it decreases smoothly with age. A zero half-life disables decay.
+#[allow(dead_code)]
+pub fn half_life_decay( age: u64, half_life: u64 ) -> I32F32 {
+    let one: I32F32 = I32F32::from_num( 1 );
+    if half_life == 0 { return one }
+    let halvings: u64 = age / half_life;
+    if halvings >= 32 { return I32F32::from_num( 0 ) }
+    let whole: I32F32 = one >> ( halvings as u32 );
+    let fraction: I32F32 = I32F32::from_num( I64F64::from_num( age % half_life ) / I64F64::from_num( half_life ) );
+    whole - whole * fraction / I32F32::from_num( 2 )
+}
+
 #[cfg(test)]
 mod tests {
     use crate::math::*;
@@ -2226,6 +2255,52 @@ mod tests {
         assert_sparse_mat_compare(&result, &target, I32F32::from_num( 0.000001 ));
     }
 
+    #[test]
+    fn test_math_row_ema_sparse_chunks() {
+        let old: Vec<f32> = vec![   0., 2., 3., 0.,
+                                    4., 0., 6., 1.,
+                                    7., 8., 0., 0.,
+                                    0., 0., 0., 0.];
+        let new: Vec<f32> = vec![   10., 20., 0., 5.,
+                                    40., 0., 60., 0.,
+                                    0., 0., 0., 0.,
+                                    100., 110., 120., 0.];
+        let old = vec_to_sparse_mat_fixed(&old, 4, false);
+        let new = vec_to_sparse_mat_fixed(&new, 4, false);
+        let alpha: I32F32 = I32F32::from_num(0.1);
+        let target = mat_ema_sparse(&new, &old, alpha);
+        // Averaged a chunk of rows at a time, the result is the same, bit for bit.
+        for chunk in 1..=4 {
+            let mut result: Vec<Vec<(u16, I32F32)>> = vec![];
+            for rows in (0..4).collect::<Vec<usize>>().chunks(chunk) {
+                for i in rows.iter() {
+                    result.push( row_ema_sparse(&new[*i], &old[*i], alpha, 4) );
+                }
+            }
+            assert_eq!(result, target);
+        }
+    }
+
+    #[test]
+    fn test_math_half_life_decay() {
+        let epsilon: I32F32 = I32F32::from_num( 0.000001 );
+        assert_float_compare( half_life_decay( 0, 0 ), I32F32::from_num( 1 ), epsilon );
+        assert_float_compare( half_life_decay( 1_000, 0 ), I32F32::from_num( 1 ), epsilon );
+        assert_float_compare( half_life_decay( 0, 100 ), I32F32::from_num( 1 ), epsilon );
+        assert_float_compare( half_life_decay( 50, 100 ), I32F32::from_num( 0.75 ), epsilon );
+        assert_float_compare( half_life_decay( 100, 100 ), I32F32::from_num( 0.5 ), epsilon );
+        assert_float_compare( half_life_decay( 150, 100 ), I32F32::from_num( 0.375 ), epsilon );
+        assert_float_compare( half_life_decay( 200, 100 ), I32F32::from_num( 0.25 ), epsilon );
+        assert_float_compare( half_life_decay( 3_200, 100 ), I32F32::from_num( 0 ), epsilon );
+        assert_float_compare( half_life_decay( u64::MAX, 1 ), I32F32::from_num( 0 ), epsilon );
+        let mut previous: I32F32 = I32F32::from_num( 1 );
+        for age in 0..1_000 {
+            let decay: I32F32 = half_life_decay( age, 7 );
+            assert!( decay <= previous );
+            previous = decay;
+        }
+    }
+
     #[test]
     fn test_math_matmul2() {
         let epsilon: I32F32 = I32F32::from_num(0.0001);
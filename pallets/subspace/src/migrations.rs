@@ -0,0 +1,49 @@
+use super::*;
+use frame_support::inherent::Vec;
+use frame_support::traits::{Get, GetStorageVersion, StorageVersion};
+use frame_support::weights::Weight;
+
+// ---- Migrates the pallet storage from version 0 to version 1.
+//
+// Version 1 changed the layout of the stored modules and of the modules flagged for pruning, and bounded
+// the weights, bonds, loaded emission tuples and per-uid vectors by MaxUidsOf.
+//
+// 	* Modules and ModulesToPruneAtNextEpoch cannot be decoded in their new layout, they are cleared. Modules
+// 	  serve again to publish their endpoints, flagged modules are flagged again at the next epoch.
+//
+// 	* The bounded values keep the encoding of the vectors they replace, they are re-encoded through the bound
+// 	  so that a value longer than MaxUidsOf is truncated rather than failing to decode.
+//
+// # Returns:
+// 	* The weight of the migration, a single read once it has run.
+//
+pub fn migrate_to_v1<T: Config>() -> Weight {
+    let on_chain_version: StorageVersion = Pallet::<T>::on_chain_storage_version();
+    if on_chain_version >= 1 { return T::DbWeight::get().reads( 1 ) }
+    log::info!("Migrating pallet_subspace storage from {:?} to version 1", on_chain_version );
+
+    // 1. Clear the values which changed layout.
+    let cleared_modules: u64 = Modules::<T>::clear( u32::MAX, None ).unique as u64;
+    let cleared_flags: u64 = ModulesToPruneAtNextEpoch::<T>::clear( u32::MAX, None ).unique as u64;
+
+    // 2. Re-encode the bounded values.
+    let mut translated: u64 = 0;
+    Weights::<T>::translate_values::<Vec<(u16, u16)>, _>( |row| { translated += 1; Some( UidVec::<T, (u16, u16)>::truncate_from( row ) ) } );
+    Bonds::<T>::translate_values::<Vec<(u16, u16)>, _>( |row| { translated += 1; Some( UidVec::<T, (u16, u16)>::truncate_from( row ) ) } );
+    LoadedEmission::<T>::translate_values::<Vec<(T::AccountId, u64)>, _>( |tuples| { translated += 1; Some( UidVec::<T, (T::AccountId, u64)>::truncate_from( tuples ) ) } );
+    Active::<T>::translate_values::<Vec<bool>, _>( |v| { translated += 1; Some( UidVec::<T, bool>::truncate_from( v ) ) } );
+    ValidatorPermits::<T>::translate_values::<Vec<bool>, _>( |v| { translated += 1; Some( UidVec::<T, bool>::truncate_from( v ) ) } );
+    Rank::<T>::translate_values::<Vec<u16>, _>( |v| { translated += 1; Some( UidVec::<T, u16>::truncate_from( v ) ) } );
+    Trust::<T>::translate_values::<Vec<u16>, _>( |v| { translated += 1; Some( UidVec::<T, u16>::truncate_from( v ) ) } );
+    Consensus::<T>::translate_values::<Vec<u16>, _>( |v| { translated += 1; Some( UidVec::<T, u16>::truncate_from( v ) ) } );
+    Incentive::<T>::translate_values::<Vec<u16>, _>( |v| { translated += 1; Some( UidVec::<T, u16>::truncate_from( v ) ) } );
+    Dividends::<T>::translate_values::<Vec<u16>, _>( |v| { translated += 1; Some( UidVec::<T, u16>::truncate_from( v ) ) } );
+    PruningScores::<T>::translate_values::<Vec<u16>, _>( |v| { translated += 1; Some( UidVec::<T, u16>::truncate_from( v ) ) } );
+    Emission::<T>::translate_values::<Vec<u64>, _>( |v| { translated += 1; Some( UidVec::<T, u64>::truncate_from( v ) ) } );
+    LastUpdate::<T>::translate_values::<Vec<u64>, _>( |v| { translated += 1; Some( UidVec::<T, u64>::truncate_from( v ) ) } );
+
+    // 3. Record the new version.
+    StorageVersion::new( 1 ).put::<Pallet<T>>();
+    log::info!("Migrated pallet_subspace storage to version 1, cleared {:?} modules and {:?} pruning flags, bounded {:?} values", cleared_modules, cleared_flags, translated );
+    T::DbWeight::get().reads_writes( 1 + cleared_modules + cleared_flags + translated, 1 + cleared_modules + cleared_flags + translated )
+}
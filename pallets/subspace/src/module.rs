@@ -3,6 +3,7 @@ use frame_support::storage::IterableStorageDoubleMap;
 use frame_support::pallet_prelude::{Decode, Encode};
 extern crate alloc;
 use alloc::vec::Vec;
+use frame_support::sp_std::vec;
 use codec::Compact;
 
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
@@ -10,18 +11,29 @@ pub struct ModuleNetworkData<T: Config> {
     key: T::AccountId,
     uid: Compact<u16>,
     netuids: Vec<Compact<u16>>,
-    module: Module,
+    module: Module<T>,
+    tags: Vec<Vec<u8>>,
     stake: Vec<(T::AccountId, Compact<u64>)>, // map of key to stake on this module/key (includes delegations)
     rank: Compact<u16>,
+    trust: Compact<u16>,
+    consensus: Compact<u16>,
     emission: Compact<u64>,
     incentive: Compact<u16>,
     dividends: Compact<u16>,
     last_update: Compact<u64>,
+    validator_permit: bool,
     weights: Vec<(Compact<u16>, Compact<u16>)>, // Vec of (uid, weight)
     bonds: Vec<(Compact<u16>, Compact<u16>)>, // Vec of (uid, bond)
     pruning_score: Compact<u16>,
 }
 
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct ModuleTagMatch<T: Config> {
+    pub key: T::AccountId,
+    pub uid: Compact<u16>,
+    pub incentive: Compact<u16>,
+}
+
 
 impl<T: Config> Pallet<T> {
 	pub fn get_modules(netuid: u16) -> Vec<ModuleNetworkData<T>> {
@@ -60,13 +72,15 @@ impl<T: Config> Pallet<T> {
         }
 
             
-        let active = Self::get_active_for_uid( netuid, uid as u16 );
         let rank = Self::get_rank_for_uid( netuid, uid as u16 );
+        let trust = Self::get_trust_for_uid( netuid, uid as u16 );
+        let consensus = Self::get_consensus_for_uid( netuid, uid as u16 );
         let emission = Self::get_emission_for_uid( netuid, uid as u16 );
         let incentive = Self::get_incentive_for_uid( netuid, uid as u16 );
         let dividends = Self::get_dividends_for_uid( netuid, uid as u16 );
         let pruning_score = Self::get_pruning_score_for_uid( netuid, uid as u16 );
         let last_update = Self::get_last_update_for_uid( netuid, uid as u16 );
+        let validator_permit = Self::get_validator_permit_for_uid( netuid, uid as u16 );
 
         let weights = <Weights<T>>::get(netuid, uid).iter()
             .filter_map(|(i, w)| if *w > 0 { Some((i.into(), w.into())) } else { None })
@@ -76,24 +90,28 @@ impl<T: Config> Pallet<T> {
             .filter_map(|(i, b)| if *b > 0 { Some((i.into(), b.into())) } else { None })
             .collect::<Vec<(Compact<u16>, Compact<u16>)>>();
         
-        let stake: Compact<u64> = Stake<T>::get(netuid, uid).into();
-            .collect();
+        let stake: Vec<(T::AccountId, Compact<u64>)> = vec![ ( key.clone(), Self::get_total_stake_for_key( &key ).into() ) ];
+        let netuids: Vec<Compact<u16>> = Self::get_registered_networks_for_key( &key ).into_iter().map( |netuid| netuid.into() ).collect();
 
+        // The served information, including the module endpoints.
         let module = Self::get_module_from_key( netuid, &key.clone() );
+        let tags = Self::get_module_tags( netuid, &key );
 
-        let module = ModuleNetworkMetadata {
+        let module = ModuleNetworkData {
             key: key.clone(),
             uid: uid.into(),
-            netuid: netuid.into(),
-            active: active,
-            ip: module.ip,
-            port: module.port,
+            netuids: netuids,
+            module: module,
+            tags: tags,
             stake: stake,
             rank: rank.into(),
+            trust: trust.into(),
+            consensus: consensus.into(),
             emission: emission.into(),
             incentive: incentive.into(),
             dividends: dividends.into(),
             last_update: last_update.into(),
+            validator_permit: validator_permit,
             weights: weights,
             bonds: bonds,
             pruning_score: pruning_score.into()
@@ -102,7 +120,7 @@ impl<T: Config> Pallet<T> {
         return Some(module);
     }
 
-    pub fn get_module_from_uid(netuid: u16, uid: u16) -> Option<Module<T>> {
+    pub fn get_module_from_uid(netuid: u16, uid: u16) -> Option<ModuleNetworkData<T>> {
         if !Self::if_network_exist(netuid) {
             return None;
         }
@@ -111,5 +129,30 @@ impl<T: Config> Pallet<T> {
         module
 	}
 
+    // Returns the modules advertising the tag on the network, sorted by incentive, highest first.
+    pub fn get_modules_by_tag(netuid: u16, tag: Vec<u8>) -> Vec<ModuleTagMatch<T>> {
+        if !Self::if_network_exist(netuid) {
+            return Vec::new();
+        }
+        let tag: Tag<T> = match tag.try_into() {
+            Ok(tag) => tag,
+            Err(_) => return Vec::new(), // Longer than any served tag.
+        };
+
+        let incentive: Vec<u16> = Self::get_incentive( netuid );
+        let mut matches: Vec<(T::AccountId, u16, u16)> = TagIndex::<T>::iter_key_prefix( ( netuid, tag ) )
+            .filter_map( |key| {
+                let uid: u16 = Uids::<T>::get( netuid, &key )?;
+                let module_incentive: u16 = incentive.get( uid as usize ).copied().unwrap_or( 0 );
+                Some( ( key, uid, module_incentive ) )
+            })
+            .collect();
+        matches.sort_by( |(_, uid_a, incentive_a), (_, uid_b, incentive_b)| incentive_b.cmp( incentive_a ).then( uid_a.cmp( uid_b ) ) );
+
+        matches.into_iter()
+            .map( |(key, uid, incentive)| ModuleTagMatch { key, uid: uid.into(), incentive: incentive.into() } )
+            .collect()
+    }
+
 }
 
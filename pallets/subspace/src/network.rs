@@ -20,6 +20,9 @@ pub struct Network {
     max_allowed_uids: Compact<u16>,
     blocks_since_last_step: Compact<u64>,
     tempo: Compact<u16>,
+    rho: Compact<u16>,
+    kappa: Compact<u16>,
+    incentive_ratio: Compact<u16>,
     network_connect: Vec<[u16; 2]>,
     emission_values: Compact<u64>,
 }
@@ -215,12 +218,17 @@ impl<T: Config> Pallet<T> {
         // Make network parameters explicit.
         if !Tempo::<T>::contains_key( netuid ) { Tempo::<T>::insert( netuid, Tempo::<T>::get( netuid ));}
         if !MaxAllowedUids::<T>::contains_key( netuid ) { MaxAllowedUids::<T>::insert( netuid, MaxAllowedUids::<T>::get( netuid ));}
+        if !MaxAllowedValidators::<T>::contains_key( netuid ) { MaxAllowedValidators::<T>::insert( netuid, MaxAllowedValidators::<T>::get( netuid ));}
+        if !BondsMovingAverage::<T>::contains_key( netuid ) { BondsMovingAverage::<T>::insert( netuid, BondsMovingAverage::<T>::get( netuid ));}
         if !ImmunityPeriod::<T>::contains_key( netuid ) { ImmunityPeriod::<T>::insert( netuid, ImmunityPeriod::<T>::get( netuid ));}
         if !ActivityCutoff::<T>::contains_key( netuid ) { ActivityCutoff::<T>::insert( netuid, ActivityCutoff::<T>::get( netuid ));}
         if !EmissionValues::<T>::contains_key( netuid ) { EmissionValues::<T>::insert( netuid, EmissionValues::<T>::get( netuid ));}   
         if !MaxWeightsLimit::<T>::contains_key( netuid ) { MaxWeightsLimit::<T>::insert( netuid, MaxWeightsLimit::<T>::get( netuid ));}
         if !MinAllowedWeights::<T>::contains_key( netuid ) { MinAllowedWeights::<T>::insert( netuid, MinAllowedWeights::<T>::get( netuid )); }
         if !RegistrationsThisInterval::<T>::contains_key( netuid ) { RegistrationsThisInterval::<T>::insert( netuid, RegistrationsThisInterval::<T>::get( netuid ));}
+        if !Rho::<T>::contains_key( netuid ) { Rho::<T>::insert( netuid, Rho::<T>::get( netuid ));}
+        if !Kappa::<T>::contains_key( netuid ) { Kappa::<T>::insert( netuid, Kappa::<T>::get( netuid ));}
+        if !IncentiveRatio::<T>::contains_key( netuid ) { IncentiveRatio::<T>::insert( netuid, IncentiveRatio::<T>::get( netuid ));}
     }
 
     // Explicitly erases all data associated with this network.
@@ -232,9 +240,25 @@ impl<T: Config> Pallet<T> {
         let _ = Keys::<T>::clear_prefix( netuid, u32::max_value(), None );
         let _ = Bonds::<T>::clear_prefix( netuid, u32::max_value(), None );
         let _ = Weights::<T>::clear_prefix( netuid, u32::max_value(), None );
+        let _ = WeightCommits::<T>::clear_prefix( netuid, u32::max_value(), None );
+        let _ = WeightCommitExpiries::<T>::clear_prefix( netuid, u32::max_value(), None );
+        let _ = KeyWeights::<T>::clear_prefix( netuid, u32::max_value(), None );
+        let _ = EpochWeights::<T>::clear_prefix( netuid, u32::max_value(), None );
+        let _ = EpochBonds::<T>::clear_prefix( netuid, u32::max_value(), None );
+        let _ = EpochColumns::<T>::clear_prefix( netuid, u32::max_value(), None );
+        EpochInProgress::<T>::remove( netuid );
+        LoadedEmission::<T>::remove( netuid );
+        LoadedEmissionCursor::<T>::remove( netuid );
+        let _ = LifetimeEmission::<T>::clear_prefix( netuid, u32::max_value(), None );
+        let _ = EmissionHistory::<T>::clear_prefix( netuid, u32::max_value(), None );
+        let _ = ModuleTags::<T>::clear_prefix( netuid, u32::max_value(), None );
+        let _ = TagIndex::<T>::clear_prefix( ( netuid, ), u32::max_value(), None );
 
         Rank::<T>::remove( netuid );
+        Trust::<T>::remove( netuid );
+        Consensus::<T>::remove( netuid );
         Active::<T>::remove( netuid );
+        ValidatorPermits::<T>::remove( netuid );
         Emission::<T>::remove( netuid );
         Incentive::<T>::remove( netuid );
         Dividends::<T>::remove( netuid );
@@ -244,12 +268,27 @@ impl<T: Config> Pallet<T> {
 
         // --- 2. Erase network parameters.
         MaxAllowedUids::<T>::remove( netuid );
+        MaxAllowedValidators::<T>::remove( netuid );
+        BondsMovingAverage::<T>::remove( netuid );
+        NetworkConsensusMechanism::<T>::remove( netuid );
+        WeightsVersionKey::<T>::remove( netuid );
+        WeightsDecayHalfLife::<T>::remove( netuid );
+        InactivePruneWindow::<T>::remove( netuid );
+        InactivePruneMinStake::<T>::remove( netuid );
+        ModulesToPruneAtNextEpoch::<T>::remove( netuid );
         ImmunityPeriod::<T>::remove( netuid );
         ActivityCutoff::<T>::remove( netuid );
         EmissionValues::<T>::remove( netuid );
         MaxWeightsLimit::<T>::remove( netuid );
         MinAllowedWeights::<T>::remove( netuid );
         RegistrationsThisInterval::<T>::remove( netuid );
+        Rho::<T>::remove( netuid );
+        Kappa::<T>::remove( netuid );
+        IncentiveRatio::<T>::remove( netuid );
+        PendingHyperparameterChanges::<T>::remove( netuid );
+        CommitRevealEnabled::<T>::remove( netuid );
+        AllowPrivateIps::<T>::remove( netuid );
+        WeightsRevealWindow::<T>::remove( netuid );
     }
 
 
@@ -309,12 +348,16 @@ impl<T: Config> Pallet<T> {
 
         let difficulty = Self::get_difficulty_as_u64(netuid);
         let immunity_period = Self::get_immunity_period(netuid);
+        let max_allowed_validators = Self::get_max_allowed_validators(netuid);
         let min_allowed_weights = Self::get_min_allowed_weights(netuid);
         let max_weights_limit = Self::get_max_weight_limit(netuid);
         let network_n = Self::get_network_n(netuid);
         let max_allowed_uids = Self::get_max_allowed_uids(netuid);
         let blocks_since_last_step = Self::get_blocks_since_last_step(netuid);
         let tempo = Self::get_tempo(netuid);
+        let rho = Self::get_rho(netuid);
+        let kappa = Self::get_kappa(netuid);
+        let incentive_ratio = Self::get_incentive_ratio(netuid);
         let emission_values = Self::get_emission_value(netuid);
 
 
@@ -326,6 +369,7 @@ impl<T: Config> Pallet<T> {
 
         return Some(Network {
             immunity_period: immunity_period.into(),
+            max_allowed_validators: max_allowed_validators.into(),
             netuid: netuid.into(),
             min_allowed_weights: min_allowed_weights.into(),
             max_weights_limit: max_weights_limit.into(),
@@ -333,6 +377,9 @@ impl<T: Config> Pallet<T> {
             max_allowed_uids: max_allowed_uids.into(),
             blocks_since_last_step: blocks_since_last_step.into(),
             tempo: tempo.into(),
+            rho: rho.into(),
+            kappa: kappa.into(),
+            incentive_ratio: incentive_ratio.into(),
             network_connect: network_connect,
             emission_values: emission_values.into(),
         })
@@ -0,0 +1,215 @@
+use super::*;
+use frame_support::inherent::Vec;
+use frame_support::BoundedVec;
+use frame_support::weights::Weight;
+use frame_support::pallet_prelude::DispatchResult;
+use crate::system::ensure_root;
+
+impl<T: Config> Pallet<T> {
+
+    // ---- The implementation for the extrinsic sudo_schedule_hyperparameter_change.
+    //
+    // # Args:
+    // 	* 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    // 		- Must be sudo.
+    //
+    // 	* 'netuid' (u16):
+    // 		- The u16 network identifier.
+    //
+    // 	* 'change' ( HyperparameterChange ):
+    // 		- The hyperparameter and the value it will take.
+    //
+    // 	* 'apply_at_block' ( u64 ):
+    // 		- The block the change is applied at, or 0 to apply it right before the next epoch.
+    //
+    // # Event:
+    // 	* HyperparameterChangeQueued;
+    // 		- On successfully queueing the change.
+    //
+    // # Raises:
+    // 	* 'NetworkDoesNotExist':
+    // 		- Attempting to schedule a change on a non-existent network.
+    //
+    // 	* 'InvalidScheduleBlock':
+    // 		- The requested block is not in the future.
+    //
+    // 	* 'StorageValueOutOfRange':
+    // 		- The new value is outside the bounds of the hyperparameter.
+    //
+    // 	* 'TooManyScheduledChanges':
+    // 		- The network queue already holds MaxScheduledChanges changes.
+    //
+    pub fn do_sudo_schedule_hyperparameter_change(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        change: HyperparameterChange,
+        apply_at_block: u64
+    ) -> DispatchResult {
+
+        // --- 1. Ensure this is a sudo caller.
+        ensure_root( origin )?;
+
+        // --- 2. Ensure the network exists.
+        ensure!( Self::if_network_exist( netuid ), Error::<T>::NetworkDoesNotExist );
+
+        // --- 3. Ensure a requested block is strictly in the future.
+        ensure!( apply_at_block == 0 || apply_at_block > Self::get_current_block_as_u64(), Error::<T>::InvalidScheduleBlock );
+
+        // --- 4. Ensure the value is within bounds given the current network parameters.
+        ensure!( Self::is_valid_hyperparameter_change( netuid, &change ), Error::<T>::StorageValueOutOfRange );
+
+        // --- 5. Append the change to the network queue.
+        PendingHyperparameterChanges::<T>::try_mutate( netuid, |queue| queue.try_push( ( change.clone(), apply_at_block ) ) )
+            .map_err( |_| Error::<T>::TooManyScheduledChanges )?;
+
+        // --- 6. Emit the queued event.
+        log::info!("HyperparameterChangeQueued( netuid: {:?} change: {:?} apply_at_block: {:?} ) ", netuid, change, apply_at_block );
+        Self::deposit_event( Event::HyperparameterChangeQueued( netuid, change, apply_at_block ) );
+
+        // --- 7. Ok and return.
+        Ok(())
+    }
+
+    // ---- The implementation for the extrinsic sudo_cancel_hyperparameter_change.
+    //
+    // # Args:
+    // 	* 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    // 		- Must be sudo.
+    //
+    // 	* 'netuid' (u16):
+    // 		- The u16 network identifier.
+    //
+    // 	* 'index' ( u32 ):
+    // 		- The position of the change in the network queue.
+    //
+    // # Event:
+    // 	* HyperparameterChangeCancelled;
+    // 		- On successfully removing the change.
+    //
+    // # Raises:
+    // 	* 'ScheduledChangeDoesNotExist':
+    // 		- There is no queued change at this index.
+    //
+    pub fn do_sudo_cancel_hyperparameter_change( origin: T::RuntimeOrigin, netuid: u16, index: u32 ) -> DispatchResult {
+
+        // --- 1. Ensure this is a sudo caller.
+        ensure_root( origin )?;
+
+        // --- 2. Ensure the index points to a queued change.
+        let mut queue: Vec<(HyperparameterChange, u64)> = Self::get_pending_hyperparameter_changes( netuid );
+        ensure!( (index as usize) < queue.len(), Error::<T>::ScheduledChangeDoesNotExist );
+
+        // --- 3. Remove the change, keeping the order of the remaining entries.
+        let ( change, _ ) = queue.remove( index as usize );
+        Self::set_pending_hyperparameter_changes( netuid, queue );
+
+        // --- 4. Emit the cancelled event.
+        log::info!("HyperparameterChangeCancelled( netuid: {:?} change: {:?} ) ", netuid, change );
+        Self::deposit_event( Event::HyperparameterChangeCancelled( netuid, change ) );
+
+        // --- 5. Ok and return.
+        Ok(())
+    }
+
+    // Applies the queued changes which are due on this network. Changes scheduled for a block are applied
+    // once that block is reached, changes scheduled for the next epoch (apply_at_block = 0) are only applied
+    // when 'at_epoch' is set, i.e. right before the network's epoch runs. Returns the weight consumed.
+    //
+    pub fn apply_hyperparameter_changes( netuid: u16, block_number: u64, at_epoch: bool ) -> Weight {
+        let queue: Vec<(HyperparameterChange, u64)> = Self::get_pending_hyperparameter_changes( netuid );
+        if queue.is_empty() { return Weight::zero() } // Nothing queued, the read is part of the network block step.
+        let queued: u32 = queue.len() as u32;
+
+        let mut remaining: Vec<(HyperparameterChange, u64)> = Vec::new();
+        for ( change, apply_at_block ) in queue.into_iter() {
+            let is_due: bool = if apply_at_block == 0 { at_epoch } else { apply_at_block <= block_number };
+            if !is_due {
+                remaining.push( ( change, apply_at_block ) );
+                continue;
+            }
+            // Other parameters may have moved since the change was queued, so the bounds are checked again.
+            if !Self::is_valid_hyperparameter_change( netuid, &change ) {
+                log::info!("HyperparameterChangeCancelled( netuid: {:?} change: {:?} ) out of range", netuid, change );
+                Self::deposit_event( Event::HyperparameterChangeCancelled( netuid, change ) );
+                continue;
+            }
+            Self::apply_hyperparameter_change( netuid, &change );
+            log::info!("HyperparameterChangeApplied( netuid: {:?} change: {:?} ) ", netuid, change );
+            Self::deposit_event( Event::HyperparameterChangeApplied( netuid, change ) );
+        }
+        Self::set_pending_hyperparameter_changes( netuid, remaining );
+        T::WeightInfo::apply_hyperparameter_changes( queued )
+    }
+
+    // Writes the value carried by the change onto the network.
+    //
+    pub fn apply_hyperparameter_change( netuid: u16, change: &HyperparameterChange ) {
+        match *change {
+            HyperparameterChange::Tempo( tempo ) => Self::set_tempo( netuid, tempo ),
+            HyperparameterChange::MaxAllowedUids( max_allowed_uids ) => Self::set_max_allowed_uids( netuid, max_allowed_uids ),
+            HyperparameterChange::ImmunityPeriod( immunity_period ) => Self::set_immunity_period( netuid, immunity_period ),
+            HyperparameterChange::ActivityCutoff( activity_cutoff ) => Self::set_activity_cutoff( netuid, activity_cutoff ),
+            HyperparameterChange::MaxWeightsLimit( max_weight_limit ) => Self::set_max_weight_limit( netuid, max_weight_limit ),
+            HyperparameterChange::MinAllowedWeights( min_allowed_weights ) => Self::set_min_allowed_weights( netuid, min_allowed_weights ),
+            HyperparameterChange::WeightsSetRateLimit( weights_set_rate_limit ) => Self::set_weights_set_rate_limit( netuid, weights_set_rate_limit ),
+            HyperparameterChange::AdjustmentInterval( adjustment_interval ) => Self::set_adjustment_interval( netuid, adjustment_interval ),
+            HyperparameterChange::TargetRegistrationsPerInterval( target ) => Self::set_target_registrations_per_interval( netuid, target ),
+            HyperparameterChange::MaxRegistrationsPerBlock( max_registrations_per_block ) => Self::set_max_registrations_per_block( netuid, max_registrations_per_block ),
+            HyperparameterChange::ServingRateLimit( serving_rate_limit ) => Self::set_serving_rate_limit( netuid, serving_rate_limit ),
+            HyperparameterChange::AllowPrivateIps( allow_private_ips ) => Self::set_allow_private_ips( netuid, allow_private_ips ),
+            HyperparameterChange::CommitRevealEnabled( enabled ) => Self::set_commit_reveal_enabled( netuid, enabled ),
+            HyperparameterChange::WeightsRevealWindow( weights_reveal_window ) => Self::set_weights_reveal_window( netuid, weights_reveal_window ),
+            HyperparameterChange::Rho( rho ) => Self::set_rho( netuid, rho ),
+            HyperparameterChange::Kappa( kappa ) => Self::set_kappa( netuid, kappa ),
+            HyperparameterChange::IncentiveRatio( incentive_ratio ) => Self::set_incentive_ratio( netuid, incentive_ratio ),
+            HyperparameterChange::MaxAllowedValidators( max_allowed_validators ) => Self::set_max_allowed_validators( netuid, max_allowed_validators ),
+            HyperparameterChange::BondsMovingAverage( bonds_moving_average ) => Self::set_bonds_moving_average( netuid, bonds_moving_average ),
+            HyperparameterChange::ConsensusMechanism( consensus_mechanism ) => Self::set_consensus_mechanism( netuid, consensus_mechanism ),
+            HyperparameterChange::WeightsVersionKey( weights_version_key ) => Self::set_weights_version_key( netuid, weights_version_key ),
+            HyperparameterChange::WeightsDecayHalfLife( weights_decay_half_life ) => Self::set_weights_decay_half_life( netuid, weights_decay_half_life ),
+            HyperparameterChange::InactivePruneWindow( inactive_prune_window ) => Self::set_inactive_prune_window( netuid, inactive_prune_window ),
+            HyperparameterChange::InactivePruneMinStake( inactive_prune_min_stake ) => Self::set_inactive_prune_min_stake( netuid, inactive_prune_min_stake ),
+        }
+    }
+
+    // Returns true if the change passes the same bounds checks as its sudo setter.
+    //
+    pub fn is_valid_hyperparameter_change( netuid: u16, change: &HyperparameterChange ) -> bool {
+        match *change {
+            HyperparameterChange::Tempo( tempo ) => Self::if_tempo_is_valid( tempo ),
+            HyperparameterChange::MaxAllowedUids( max_allowed_uids ) => Self::check_max_allowed_uids( netuid, max_allowed_uids ),
+            HyperparameterChange::ImmunityPeriod( immunity_period ) => Self::check_immunity_period( netuid, immunity_period ),
+            HyperparameterChange::ActivityCutoff( activity_cutoff ) => Self::check_activity_cutoff( netuid, activity_cutoff ),
+            HyperparameterChange::MinAllowedWeights( min_allowed_weights ) => Self::check_min_allowed_weights( netuid, min_allowed_weights ),
+            HyperparameterChange::AdjustmentInterval( adjustment_interval ) => Self::check_adjustment_interval( netuid, adjustment_interval ),
+            HyperparameterChange::TargetRegistrationsPerInterval( target ) => Self::check_target_registrations_per_interval( netuid, target ),
+            HyperparameterChange::MaxRegistrationsPerBlock( max_registrations_per_block ) => Self::check_max_registrations_per_block( netuid, max_registrations_per_block ),
+            HyperparameterChange::MaxWeightsLimit( max_weight_limit ) => Self::check_max_weight_limit( netuid, max_weight_limit ),
+            HyperparameterChange::WeightsSetRateLimit( weights_set_rate_limit ) => Self::check_weights_set_rate_limit( netuid, weights_set_rate_limit ),
+            HyperparameterChange::ServingRateLimit( serving_rate_limit ) => Self::check_serving_rate_limit( netuid, serving_rate_limit ),
+            HyperparameterChange::CommitRevealEnabled( enabled ) => Self::check_commit_reveal_enabled( netuid, enabled ),
+            HyperparameterChange::WeightsRevealWindow( weights_reveal_window ) => Self::check_weights_reveal_window( netuid, weights_reveal_window ),
+            HyperparameterChange::Rho( rho ) => Self::check_rho( netuid, rho ),
+            HyperparameterChange::Kappa( kappa ) => Self::check_kappa( netuid, kappa ),
+            HyperparameterChange::ConsensusMechanism( consensus_mechanism ) => Self::check_consensus_mechanism( netuid, consensus_mechanism ),
+            HyperparameterChange::WeightsVersionKey( weights_version_key ) => Self::check_weights_version_key( netuid, weights_version_key ),
+            HyperparameterChange::WeightsDecayHalfLife( weights_decay_half_life ) => Self::check_weights_decay_half_life( netuid, weights_decay_half_life ),
+            HyperparameterChange::AllowPrivateIps( _ ) => true, // Both values are valid.
+            HyperparameterChange::InactivePruneWindow( _ ) | HyperparameterChange::InactivePruneMinStake( _ ) => true, // 0 disables pruning, any other value is valid.
+            HyperparameterChange::MaxAllowedValidators( max_allowed_validators ) => Self::check_max_allowed_validators( netuid, max_allowed_validators ),
+            HyperparameterChange::BondsMovingAverage( bonds_moving_average ) => Self::check_bonds_moving_average( netuid, bonds_moving_average ),
+            HyperparameterChange::IncentiveRatio( incentive_ratio ) => Self::check_incentive_ratio( netuid, incentive_ratio ),
+        }
+    }
+
+    pub fn get_pending_hyperparameter_changes( netuid: u16 ) -> Vec<(HyperparameterChange, u64)> { PendingHyperparameterChanges::<T>::get( netuid ).into_inner() }
+    pub fn set_pending_hyperparameter_changes( netuid: u16, queue: Vec<(HyperparameterChange, u64)> ) {
+        if queue.is_empty() {
+            PendingHyperparameterChanges::<T>::remove( netuid );
+        } else {
+            // The queue only ever shrinks from a stored one, so it stays within MaxScheduledChanges.
+            debug_assert!( queue.len() <= T::MaxScheduledChanges::get() as usize, "{} changes exceed MaxScheduledChanges", queue.len() );
+            PendingHyperparameterChanges::<T>::insert( netuid, BoundedVec::<(HyperparameterChange, u64), T::MaxScheduledChanges>::truncate_from( queue ) );
+        }
+    }
+}
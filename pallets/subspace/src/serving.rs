@@ -1,7 +1,10 @@
 use super::*;
 use frame_support::inherent::Vec;
-use frame_support::sp_std::vec;
+use frame_support::BoundedVec;
+use frame_support::pallet_prelude::DispatchError;
 
+// Longest label of a DNS name, in bytes.
+const MAX_HOSTNAME_LABEL_LENGTH: usize = 63;
 
 impl<T: Config> Pallet<T> {
 
@@ -14,26 +17,18 @@ impl<T: Config> Pallet<T> {
     // 	* 'netuid' (u16):
     // 		- The u16 network identifier.
     //
-    // 	* 'version' (u64):
-    // 		- The commune version identifier.
-    //
-    // 	* 'ip' (u64):
-    // 		- The endpoint ip information as a u128 encoded integer.
-    //
-    // 	* 'port' (u16):
-    // 		- The endpoint port information as a u16 encoded integer.
-    // 
-    // 	* 'ip_type' (u8):
-    // 		- The endpoint ip version as a u8, 4 or 6.
+    // 	* 'name' (Vec<u8>):
+    // 		- The module name, at most MaxNameLength bytes.
     //
-    // 	* 'protocol' (u8):
-    // 		- UDP:1 or TCP:0 
+    // 	* 'endpoints' (Vec<ModuleEndpoint>):
+    // 		- The endpoints the module is served at, at most MaxModuleEndpoints. Each holds its
+    // 		transport (TCP or UDP), ip version (4 or 6), u128 encoded ip, port and optional DNS hostname.
     //
-    // 	* 'placeholder1' (u8):
-    // 		- Placeholder for further extra params.
+    // 	* 'uri' (Vec<u8>):
+    // 		- The module uri, at most MaxUriLength bytes.
     //
-    // 	* 'placeholder2' (u8):
-    // 		- Placeholder for further extra params.
+    // 	* 'tags' (Vec<Vec<u8>>):
+    // 		- The capability tags of the module, at most MaxModuleTags. Replaces the previously served tags.
     //
     // # Event:
     // 	* ModuleServed;
@@ -41,37 +36,67 @@ impl<T: Config> Pallet<T> {
     //
     // # Raises:
     // 	* 'NetworkDoesNotExist':
-    // 		- Attempting to set weights on a non-existent network.
+    // 		- Attempting to serve on a non-existent network.
     //
     // 	* 'NotRegistered':
-    // 		- Attempting to set weights from a non registered account.
+    // 		- Attempting to serve from an account not registered on the network.
+    //
+    // 	* 'NameTooLong':
+    // 		- The name is longer than MaxNameLength.
+    //
+    // 	* 'UriTooLong':
+    // 		- The uri is longer than MaxUriLength.
+    //
+    // 	* 'TooManyEndpoints':
+    // 		- More endpoints than MaxModuleEndpoints.
     //
     // 	* 'InvalidIpType':
-    // 		- The ip type is not 4 or 6.
+    // 		- An endpoint ip type is not 4 or 6.
     //
     // 	* 'InvalidIpAddress':
-    // 		- The numerically encoded ip address does not resolve to a proper ip.
+    // 		- An endpoint numerically encoded ip address does not resolve to a proper ip.
+    //
+    // 	* 'InvalidPort':
+    // 		- An endpoint port is 0.
+    //
+    // 	* 'InvalidHostname':
+    // 		- An endpoint hostname is not a valid DNS name.
+    //
+    // 	* 'TooManyTags':
+    // 		- More tags than MaxModuleTags.
+    //
+    // 	* 'InvalidTag':
+    // 		- A tag is empty, longer than MaxTagLength or holds other than lowercase alphanumerics and inner hyphens.
+    //
+    // 	* 'DuplicateTags':
+    // 		- The same tag is served twice.
     //
     // 	* 'ServingRateLimitExceeded':
-    // 		- Attempting to set prometheus information withing the rate limit min.
+    // 		- Attempting to serve the module within the rate limit min.
     //
     pub fn do_serve_module( 
         origin: T::RuntimeOrigin, 
 		netuid: u16,
         name: Vec<u8>, // contains a name string
-        ip: u128, 
-        port: u16, 
+        endpoints: Vec<ModuleEndpoint>,
         uri: Vec<u8>, // contains a uri string
+        tags: Vec<Vec<u8>>,
     ) -> dispatch::DispatchResult {
         // --- 1. We check the callers (key) signature.
         let key = ensure_signed(origin)?;
 
-        // --- 2. Ensure the key is registered somewhere.
-        ensure!( Self::is_key_registered_on_any_network( &key ), Error::<T>::NotRegistered );  
+        // --- 2. Ensure the key is registered on the network.
+        ensure!( Self::if_network_exist( netuid ), Error::<T>::NetworkDoesNotExist );
+        ensure!( Self::is_key_registered_on_network( netuid, &key ), Error::<T>::NotRegistered );  
         
-        // --- 3. Check the ip signature validity.
-        ensure!( Self::is_valid_ip_address(ip), Error::<T>::InvalidIpAddress );
-  
+        // --- 3. Check the name, uri, endpoints and tags are bounded and the endpoints and tags valid.
+        let name: BoundedVec<u8, T::MaxNameLength> = name.try_into().map_err( |_| Error::<T>::NameTooLong )?;
+        let uri: BoundedVec<u8, T::MaxUriLength> = uri.try_into().map_err( |_| Error::<T>::UriTooLong )?;
+        let endpoints: BoundedVec<ModuleEndpoint, T::MaxModuleEndpoints> = endpoints.try_into().map_err( |_| Error::<T>::TooManyEndpoints )?;
+        for endpoint in endpoints.iter() {
+            Self::validate_endpoint( netuid, endpoint )?;
+        }
+        let tags: TagsOf<T> = Self::validate_tags( tags )?;
 
         // --- 4. Get the previous module information.
         let mut prev_module = Self::get_module_from_key( netuid, &key );
@@ -79,13 +104,13 @@ impl<T: Config> Pallet<T> {
         ensure!( Self::module_passes_rate_limit( netuid, &prev_module, current_block ), Error::<T>::ServingRateLimitExceeded );  
 
         // --- 6. We insert the module meta.
-        prev_module.block = Self::get_current_block_as_u64();
-        prev_module.ip = ip;
-        prev_module.port = port;
+        prev_module.block = current_block;
+        prev_module.endpoints = endpoints;
         prev_module.uri = uri;
         prev_module.name = name;
 
         Modules::<T>::insert( netuid, key.clone(), prev_module );
+        Self::set_module_tags( netuid, &key, tags );
 
         // --- 7. We deposit module served event.
         log::info!("ModuleServed( key:{:?} ) ", key.clone() );
@@ -141,7 +166,7 @@ impl<T: Config> Pallet<T> {
      --==[[  Helper functions   ]]==--
     *********************************/
 
-    pub fn module_passes_rate_limit( netuid: u16, prev_module: &Module, current_block: u64 ) -> bool {
+    pub fn module_passes_rate_limit( netuid: u16, prev_module: &Module<T>, current_block: u64 ) -> bool {
         let rate_limit: u64 = Self::get_serving_rate_limit(netuid);
         let last_serve = prev_module.block;
         return rate_limit == 0 || last_serve == 0 || current_block - last_serve >= rate_limit;
@@ -153,15 +178,15 @@ impl<T: Config> Pallet<T> {
     }
 
 
-    pub fn get_module_from_key( netuid: u16, key: &T::AccountId ) -> Module {
+    pub fn get_module_from_key( netuid: u16, key: &T::AccountId ) -> Module<T> {
         if Self::has_module( netuid, key ) {
             return Modules::<T>::get( netuid, key ).unwrap();
         } else{
             return Module { 
                 block: 0,
-                version: 0,
-                ip: 0,
-                port: 0,
+                name: BoundedVec::default(),
+                endpoints: BoundedVec::default(),
+                uri: BoundedVec::default(),
             }
 
         }
@@ -169,36 +194,252 @@ impl<T: Config> Pallet<T> {
 
 
 
-    // @todo (Parallax 2-1-2021) : Implement exclusion of private IP ranges
-    pub fn is_valid_ip_address(ip: u128) -> bool {
-        let ip_type = Self::get_ip_type(ip);
-        if ip == 0 {
-            return false;
+    pub fn get_module_tags( netuid: u16, key: &T::AccountId ) -> Vec<Vec<u8>> {
+        ModuleTags::<T>::get( netuid, key ).into_iter().map( |tag| tag.into_inner() ).collect()
+    }
+
+    // Replaces the tags of the module and re-indexes it under the new ones.
+    pub fn set_module_tags( netuid: u16, key: &T::AccountId, tags: TagsOf<T> ) {
+        Self::clear_module_tags( netuid, key );
+        if tags.is_empty() { return }
+        for tag in tags.iter() {
+            TagIndex::<T>::insert( ( netuid, tag, key ), true );
+        }
+        ModuleTags::<T>::insert( netuid, key, tags );
+    }
+
+    // Removes the tags of the module and its entries in the tag index.
+    pub fn clear_module_tags( netuid: u16, key: &T::AccountId ) {
+        for tag in ModuleTags::<T>::take( netuid, key ).iter() {
+            TagIndex::<T>::remove( ( netuid, tag, key ) );
+        }
+    }
+
+    // Checks there are at most MaxModuleTags distinct tags, each a valid tag of at most MaxTagLength bytes.
+    pub fn validate_tags( tags: Vec<Vec<u8>> ) -> Result<TagsOf<T>, DispatchError> {
+        ensure!( tags.len() <= T::MaxModuleTags::get() as usize, Error::<T>::TooManyTags );
+        let mut bounded: TagsOf<T> = BoundedVec::default();
+        for tag in tags.into_iter() {
+            ensure!( is_valid_tag( &tag ), Error::<T>::InvalidTag );
+            let tag: Tag<T> = tag.try_into().map_err( |_| Error::<T>::InvalidTag )?;
+            ensure!( !bounded.contains( &tag ), Error::<T>::DuplicateTags );
+            bounded.try_push( tag ).map_err( |_| Error::<T>::TooManyTags )?;
         }
-        if ip_type == 4 {
-            if ip == 0 { return false; }
-            if ip >= u32::MAX as u128 { return false; }
-            if ip == 0x7f000001 { return false; } // Localhost
+        Ok( bounded )
+    }
+
+    // Checks the endpoint ip type, ip address, port and hostname.
+    pub fn validate_endpoint( netuid: u16, endpoint: &ModuleEndpoint ) -> dispatch::DispatchResult {
+        ensure!( Self::is_valid_ip_type( endpoint.ip_type ), Error::<T>::InvalidIpType );
+        ensure!( Self::is_valid_ip_address( netuid, endpoint.ip_type, endpoint.ip ), Error::<T>::InvalidIpAddress );
+        ensure!( endpoint.port != 0, Error::<T>::InvalidPort );
+        if let Some( hostname ) = &endpoint.hostname {
+            ensure!( Self::is_valid_hostname( hostname ), Error::<T>::InvalidHostname );
         }
-        if ip_type == 6 {
-            if ip == 0x0 { return false; }
-            if ip == u128::MAX { return false; }
-            if ip == 1 { return false; } // IPv6 localhost
+        Ok(())
+    }
+
+    // Returns true if the hostname is a DNS name of at most 253 bytes made of dot separated labels of letters,
+    // digits and hyphens, no label empty, longer than 63 bytes or starting or ending with a hyphen.
+    pub fn is_valid_hostname( hostname: &Vec<u8> ) -> bool {
+        if hostname.is_empty() || hostname.len() > MAX_HOSTNAME_LENGTH as usize { return false }
+        hostname.split( |c| *c == b'.' ).all( |label| {
+            !label.is_empty() && label.len() <= MAX_HOSTNAME_LABEL_LENGTH
+                && label.iter().all( |c| c.is_ascii_alphanumeric() || *c == b'-' )
+                && label[ 0 ] != b'-' && label[ label.len() - 1 ] != b'-'
+        })
+    }
+
+    pub fn is_valid_ip_type( ip_type: u8 ) -> bool { ip_type == 4 || ip_type == 6 }
+
+    // Returns true if the u128 encoded ip of type 'ip_type' is a public address. Private and loopback addresses
+    // are only accepted on networks which allow private ips.
+    pub fn is_valid_ip_address( netuid: u16, ip_type: u8, ip: u128 ) -> bool {
+        let allow_private: bool = Self::get_allow_private_ips( netuid );
+        match ip_type {
+            4 => ip <= u32::MAX as u128 && is_valid_ipv4( ip as u32, allow_private ),
+            6 => is_valid_ipv6( ip, allow_private ),
+            _ => false,
         }
-        return true;
     }
+}
+
+// Returns true if the tag is non empty and made of lowercase letters, digits and hyphens, not starting or
+// ending with a hyphen, e.g. b"text-embedding".
+pub fn is_valid_tag( tag: &[u8] ) -> bool {
+    !tag.is_empty()
+        && tag.iter().all( |c| c.is_ascii_lowercase() || c.is_ascii_digit() || *c == b'-' )
+        && tag[ 0 ] != b'-' && tag[ tag.len() - 1 ] != b'-'
+}
+
+// Returns true if the ipv4 address can be served. Unspecified, link-local, multicast, reserved, broadcast and
+// documentation addresses are always rejected, private and loopback addresses unless 'allow_private' is set.
+pub fn is_valid_ipv4( ip: u32, allow_private: bool ) -> bool {
+    let in_range = | prefix: u32, len: u32 | -> bool { ( ip ^ prefix ) >> ( 32 - len ) == 0 };
+    if in_range( 0x0000_0000, 8 ) { return false } // 0.0.0.0/8 unspecified.
+    if in_range( 0xa9fe_0000, 16 ) { return false } // 169.254.0.0/16 link-local.
+    if in_range( 0xe000_0000, 4 ) { return false } // 224.0.0.0/4 multicast.
+    if in_range( 0xf000_0000, 4 ) { return false } // 240.0.0.0/4 reserved, including the 255.255.255.255 broadcast.
+    if in_range( 0xc000_0200, 24 ) || in_range( 0xc633_6400, 24 ) || in_range( 0xcb00_7100, 24 ) { return false } // 192.0.2.0/24, 198.51.100.0/24, 203.0.113.0/24 documentation.
+    let is_private: bool = in_range( 0x7f00_0000, 8 ) // 127.0.0.0/8 loopback.
+        || in_range( 0x0a00_0000, 8 ) // 10.0.0.0/8 private.
+        || in_range( 0xac10_0000, 12 ) // 172.16.0.0/12 private.
+        || in_range( 0xc0a8_0000, 16 ) // 192.168.0.0/16 private.
+        || in_range( 0x6440_0000, 10 ); // 100.64.0.0/10 shared address space.
+    allow_private || !is_private
+}
 
-    fn get_ip_type(ip: u128) -> u8 {
-        // Return the IP type (4 or 6) based on the IP address
-        if ip <= u32::MAX as u128 {
-            return 4;
-        } else if ip <= u128::MAX {
-            return 6;
-        } 
+// Returns true if the ipv6 address can be served. Unspecified, link-local, multicast and documentation addresses
+// are always rejected, unique local and loopback addresses unless 'allow_private' is set. Ipv4-mapped addresses
+// are checked as ipv4.
+pub fn is_valid_ipv6( ip: u128, allow_private: bool ) -> bool {
+    let in_range = | prefix: u128, len: u32 | -> bool { ( ip ^ prefix ) >> ( 128 - len ) == 0 };
+    if ip == 0 { return false } // :: unspecified.
+    if in_range( 0xffff_u128 << 32, 96 ) { return is_valid_ipv4( ip as u32, allow_private ) } // ::ffff:0:0/96 ipv4-mapped.
+    if in_range( 0xfe80_u128 << 112, 10 ) { return false } // fe80::/10 link-local.
+    if in_range( 0xff00_u128 << 112, 8 ) { return false } // ff00::/8 multicast.
+    if in_range( 0x2001_0db8_u128 << 96, 32 ) || in_range( 0x3fff_u128 << 112, 20 ) { return false } // 2001:db8::/32 and 3fff::/20 documentation.
+    let is_private: bool = ip == 1 // ::1 loopback.
+        || in_range( 0xfc00_u128 << 112, 7 ); // fc00::/7 unique local.
+    allow_private || !is_private
+}
 
-        // If the IP address is not IPv4 or IPv6 and not private, raise an error
-        return 0;
-    } 
+#[cfg(test)]
+mod tests {
+    use crate::serving::{is_valid_ipv4, is_valid_ipv6, is_valid_tag};
 
+    fn ipv4( a: u8, b: u8, c: u8, d: u8 ) -> u32 { u32::from_be_bytes( [a, b, c, d] ) }
 
-}
\ No newline at end of file
+    fn ipv6( segments: [u16; 8] ) -> u128 { segments.iter().fold( 0, |ip, segment| ( ip << 16 ) | *segment as u128 ) }
+
+    #[test]
+    fn test_ipv4_public() {
+        assert!( is_valid_ipv4( ipv4( 8, 8, 8, 8 ), false ) );
+        assert!( is_valid_ipv4( ipv4( 99, 235, 128, 81 ), false ) );
+        assert!( is_valid_ipv4( ipv4( 223, 255, 255, 255 ), false ) );
+        assert!( is_valid_ipv4( ipv4( 172, 32, 0, 1 ), false ) ); // Just past 172.16.0.0/12.
+        assert!( is_valid_ipv4( ipv4( 100, 128, 0, 1 ), false ) ); // Just past 100.64.0.0/10.
+    }
+
+    #[test]
+    fn test_ipv4_unspecified() {
+        assert!( !is_valid_ipv4( ipv4( 0, 0, 0, 0 ), false ) );
+        assert!( !is_valid_ipv4( ipv4( 0, 1, 2, 3 ), false ) );
+        assert!( !is_valid_ipv4( ipv4( 0, 0, 0, 0 ), true ) );
+    }
+
+    #[test]
+    fn test_ipv4_loopback() {
+        assert!( !is_valid_ipv4( ipv4( 127, 0, 0, 1 ), false ) );
+        assert!( !is_valid_ipv4( ipv4( 127, 255, 255, 254 ), false ) );
+        assert!( is_valid_ipv4( ipv4( 127, 0, 0, 1 ), true ) );
+    }
+
+    #[test]
+    fn test_ipv4_private() {
+        for ip in [ ipv4( 10, 0, 0, 1 ), ipv4( 10, 255, 255, 255 ), ipv4( 172, 16, 0, 1 ), ipv4( 172, 31, 255, 255 ),
+                    ipv4( 192, 168, 0, 1 ), ipv4( 192, 168, 255, 255 ), ipv4( 100, 64, 0, 1 ), ipv4( 100, 127, 255, 255 ) ] {
+            assert!( !is_valid_ipv4( ip, false ) );
+            assert!( is_valid_ipv4( ip, true ) );
+        }
+    }
+
+    #[test]
+    fn test_ipv4_link_local() {
+        assert!( !is_valid_ipv4( ipv4( 169, 254, 0, 1 ), false ) );
+        assert!( !is_valid_ipv4( ipv4( 169, 254, 255, 255 ), true ) );
+        assert!( is_valid_ipv4( ipv4( 169, 253, 0, 1 ), false ) );
+    }
+
+    #[test]
+    fn test_ipv4_multicast() {
+        assert!( !is_valid_ipv4( ipv4( 224, 0, 0, 1 ), false ) );
+        assert!( !is_valid_ipv4( ipv4( 239, 255, 255, 255 ), true ) );
+    }
+
+    #[test]
+    fn test_ipv4_documentation() {
+        for ip in [ ipv4( 192, 0, 2, 1 ), ipv4( 198, 51, 100, 1 ), ipv4( 203, 0, 113, 255 ) ] {
+            assert!( !is_valid_ipv4( ip, false ) );
+            assert!( !is_valid_ipv4( ip, true ) );
+        }
+        assert!( is_valid_ipv4( ipv4( 192, 0, 3, 1 ), false ) );
+    }
+
+    #[test]
+    fn test_ipv4_reserved_and_broadcast() {
+        assert!( !is_valid_ipv4( ipv4( 240, 0, 0, 1 ), false ) );
+        assert!( !is_valid_ipv4( ipv4( 255, 255, 255, 255 ), true ) );
+    }
+
+    #[test]
+    fn test_ipv6_public() {
+        assert!( is_valid_ipv6( ipv6( [0x2a00, 0x1450, 0x4001, 0x0830, 0, 0, 0, 0x200e] ), false ) );
+        assert!( is_valid_ipv6( ipv6( [0x2001, 0x0db9, 0, 0, 0, 0, 0, 1] ), false ) ); // Just past 2001:db8::/32.
+        assert!( is_valid_ipv6( ipv6( [0xfec0, 0, 0, 0, 0, 0, 0, 1] ), false ) ); // Just past fe80::/10.
+    }
+
+    #[test]
+    fn test_ipv6_unspecified() {
+        assert!( !is_valid_ipv6( 0, false ) );
+        assert!( !is_valid_ipv6( 0, true ) );
+    }
+
+    #[test]
+    fn test_ipv6_loopback() {
+        assert!( !is_valid_ipv6( 1, false ) );
+        assert!( is_valid_ipv6( 1, true ) );
+    }
+
+    #[test]
+    fn test_ipv6_unique_local() {
+        for ip in [ ipv6( [0xfc00, 0, 0, 0, 0, 0, 0, 1] ), ipv6( [0xfdff, 0xffff, 0, 0, 0, 0, 0, 1] ) ] {
+            assert!( !is_valid_ipv6( ip, false ) );
+            assert!( is_valid_ipv6( ip, true ) );
+        }
+    }
+
+    #[test]
+    fn test_ipv6_link_local() {
+        assert!( !is_valid_ipv6( ipv6( [0xfe80, 0, 0, 0, 0, 0, 0, 1] ), false ) );
+        assert!( !is_valid_ipv6( ipv6( [0xfebf, 0xffff, 0, 0, 0, 0, 0, 1] ), true ) );
+    }
+
+    #[test]
+    fn test_ipv6_multicast() {
+        assert!( !is_valid_ipv6( ipv6( [0xff02, 0, 0, 0, 0, 0, 0, 1] ), false ) );
+        assert!( !is_valid_ipv6( u128::MAX, true ) );
+    }
+
+    #[test]
+    fn test_ipv6_documentation() {
+        assert!( !is_valid_ipv6( ipv6( [0x2001, 0x0db8, 0, 0, 0, 0, 0, 1] ), false ) );
+        assert!( !is_valid_ipv6( ipv6( [0x3fff, 0x0fff, 0, 0, 0, 0, 0, 1] ), true ) );
+        assert!( is_valid_ipv6( ipv6( [0x3fff, 0x1000, 0, 0, 0, 0, 0, 1] ), false ) ); // Just past 3fff::/20.
+    }
+
+    #[test]
+    fn test_ipv6_ipv4_mapped() {
+        assert!( is_valid_ipv6( ipv6( [0, 0, 0, 0, 0, 0xffff, 0x0808, 0x0808] ), false ) ); // ::ffff:8.8.8.8
+        assert!( !is_valid_ipv6( ipv6( [0, 0, 0, 0, 0, 0xffff, 0x7f00, 0x0001] ), false ) ); // ::ffff:127.0.0.1
+        assert!( is_valid_ipv6( ipv6( [0, 0, 0, 0, 0, 0xffff, 0x0a00, 0x0001] ), true ) ); // ::ffff:10.0.0.1
+    }
+
+    #[test]
+    fn test_tag_valid() {
+        assert!( is_valid_tag( b"text-embedding" ) );
+        assert!( is_valid_tag( b"llm" ) );
+        assert!( is_valid_tag( b"gpt-4" ) );
+        assert!( is_valid_tag( b"7b" ) );
+    }
+
+    #[test]
+    fn test_tag_invalid() {
+        assert!( !is_valid_tag( b"" ) );
+        assert!( !is_valid_tag( b"-llm" ) );
+        assert!( !is_valid_tag( b"llm-" ) );
+        assert!( !is_valid_tag( b"Text-Embedding" ) );
+        assert!( !is_valid_tag( b"text embedding" ) );
+        assert!( !is_valid_tag( b"text_embedding" ) );
+    }
+}
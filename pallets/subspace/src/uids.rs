@@ -24,6 +24,8 @@ impl<T: Config> Pallet<T> {
         // 2. Remove previous set memberships.
         Uids::<T>::remove( netuid, old_key.clone() ); 
         IsNetworkMember::<T>::remove( old_key.clone(), netuid );
+        KeyWeights::<T>::remove( netuid, old_key.clone() );
+        Self::clear_module_tags( netuid, &old_key );
         Keys::<T>::remove( netuid, uid_to_replace ); 
 
         // 3. Create new set memberships.
@@ -44,15 +46,17 @@ impl<T: Config> Pallet<T> {
         // 2. Get and increase the uid count.
         NetworkworkN::<T>::insert( netuid, next_uid + 1 );
 
-        // 3. Expand Yuma Consensus with new position.
-        Rank::<T>::mutate(netuid, |v| v.push(0) );
-        Trust::<T>::mutate(netuid, |v| v.push(0) );
-        Active::<T>::mutate(netuid, |v| v.push( true ) );
-        Emission::<T>::mutate(netuid, |v| v.push(0) );
-        Incentive::<T>::mutate(netuid, |v| v.push(0) );
-        Dividends::<T>::mutate(netuid, |v| v.push(0) );
-        LastUpdate::<T>::mutate(netuid, |v| v.push( block_number ) );
-        PruningScores::<T>::mutate(netuid, |v| v.push(0) );
+        // 3. Expand Yuma Consensus with new position. The uid count never exceeds MaxAllowedUidsUpperBound, the pushes always fit.
+        Rank::<T>::mutate( netuid, |v| Self::push_uid_value( v, 0 ) );
+        Trust::<T>::mutate( netuid, |v| Self::push_uid_value( v, 0 ) );
+        Consensus::<T>::mutate( netuid, |v| Self::push_uid_value( v, 0 ) );
+        Active::<T>::mutate( netuid, |v| Self::push_uid_value( v, true ) );
+        ValidatorPermits::<T>::mutate( netuid, |v| Self::push_uid_value( v, false ) );
+        Emission::<T>::mutate( netuid, |v| Self::push_uid_value( v, 0 ) );
+        Incentive::<T>::mutate( netuid, |v| Self::push_uid_value( v, 0 ) );
+        Dividends::<T>::mutate( netuid, |v| Self::push_uid_value( v, 0 ) );
+        LastUpdate::<T>::mutate( netuid, |v| Self::push_uid_value( v, block_number ) );
+        PruningScores::<T>::mutate( netuid, |v| Self::push_uid_value( v, 0 ) );
  
         // 4. Insert new account information.
         Keys::<T>::insert( netuid, next_uid, new_key.clone() ); // Make key - uid association.
@@ -61,6 +65,163 @@ impl<T: Config> Pallet<T> {
         IsNetworkMember::<T>::insert( new_key.clone(), netuid, true ); // Fill network is member.
     }
 
+    // Removes the module under this uid from the network. The last uid is moved into the freed slot so that
+    // uids stay contiguous, and every weight and bond pointing at the moved uid is rewritten to its new position.
+    // An epoch in progress refers to uids by position, so modules are only removed between epochs.
+    pub fn remove_module( netuid: u16, uid_to_remove: u16 ) {
+        let n: u16 = Self::get_network_n( netuid );
+        if uid_to_remove >= n { return } // Nothing to remove.
+        if Self::is_epoch_in_progress( netuid ) { return } // Uids cannot move under the epoch.
+        let last_uid: u16 = n - 1;
+
+        // 1. Remove the key memberships of the removed module.
+        let old_key: T::AccountId = Keys::<T>::get( netuid, uid_to_remove );
+        log::debug!("remove_module( netuid: {:?} | uid_to_remove: {:?} | old_key: {:?} ) ", netuid, uid_to_remove, old_key );
+        Uids::<T>::remove( netuid, old_key.clone() );
+        IsNetworkMember::<T>::remove( old_key.clone(), netuid );
+        Modules::<T>::remove( netuid, old_key.clone() );
+        KeyWeights::<T>::remove( netuid, old_key.clone() );
+        Self::clear_module_tags( netuid, &old_key );
+
+        // 2. Move the last uid into the freed slot.
+        if uid_to_remove != last_uid {
+            let last_key: T::AccountId = Keys::<T>::get( netuid, last_uid );
+            Keys::<T>::insert( netuid, uid_to_remove, last_key.clone() );
+            Uids::<T>::insert( netuid, last_key, uid_to_remove );
+            BlockAtRegistration::<T>::insert( netuid, uid_to_remove, Self::get_module_block_at_registration( netuid, last_uid ) );
+            // Swapped rather than copied, an absent row stays absent.
+            Weights::<T>::swap( netuid, uid_to_remove, netuid, last_uid );
+            Bonds::<T>::swap( netuid, uid_to_remove, netuid, last_uid );
+        }
+        Keys::<T>::remove( netuid, last_uid );
+        BlockAtRegistration::<T>::remove( netuid, last_uid );
+        Weights::<T>::remove( netuid, last_uid );
+        Bonds::<T>::remove( netuid, last_uid );
+
+        // 3. Shrink Yuma Consensus by the removed position.
+        Rank::<T>::mutate(netuid, |v| if (uid_to_remove as usize) < v.len() { v.swap_remove( uid_to_remove as usize ); } );
+        Trust::<T>::mutate(netuid, |v| if (uid_to_remove as usize) < v.len() { v.swap_remove( uid_to_remove as usize ); } );
+        Consensus::<T>::mutate(netuid, |v| if (uid_to_remove as usize) < v.len() { v.swap_remove( uid_to_remove as usize ); } );
+        Active::<T>::mutate(netuid, |v| if (uid_to_remove as usize) < v.len() { v.swap_remove( uid_to_remove as usize ); } );
+        ValidatorPermits::<T>::mutate(netuid, |v| if (uid_to_remove as usize) < v.len() { v.swap_remove( uid_to_remove as usize ); } );
+        Emission::<T>::mutate(netuid, |v| if (uid_to_remove as usize) < v.len() { v.swap_remove( uid_to_remove as usize ); } );
+        Incentive::<T>::mutate(netuid, |v| if (uid_to_remove as usize) < v.len() { v.swap_remove( uid_to_remove as usize ); } );
+        Dividends::<T>::mutate(netuid, |v| if (uid_to_remove as usize) < v.len() { v.swap_remove( uid_to_remove as usize ); } );
+        LastUpdate::<T>::mutate(netuid, |v| if (uid_to_remove as usize) < v.len() { v.swap_remove( uid_to_remove as usize ); } );
+        PruningScores::<T>::mutate(netuid, |v| if (uid_to_remove as usize) < v.len() { v.swap_remove( uid_to_remove as usize ); } );
+
+        // 4. Drop the weights, bonds and flags pointing at the removed uid and re-point those of the moved uid.
+        // Rows without either uid are left untouched, so absent rows are never written back as empty entries.
+        for uid_i in 0..last_uid {
+            let _ = Weights::<T>::try_mutate_exists( netuid, uid_i, |row| Self::remap_uid_row( row, uid_to_remove, last_uid ) );
+            let _ = Bonds::<T>::try_mutate_exists( netuid, uid_i, |row| Self::remap_uid_row( row, uid_to_remove, last_uid ) );
+        }
+        if ModulesToPruneAtNextEpoch::<T>::contains_key( netuid ) {
+            ModulesToPruneAtNextEpoch::<T>::mutate( netuid, |flagged| {
+                flagged.retain( |uid| *uid != uid_to_remove );
+                flagged.iter_mut().for_each( |uid| if *uid == last_uid { *uid = uid_to_remove } );
+            });
+        }
+
+        // 5. Decrease the uid count.
+        NetworkworkN::<T>::insert( netuid, last_uid );
+
+        log::info!("ModuleDeregistered( netuid:{:?} uid:{:?} key:{:?} ) ", netuid, uid_to_remove, old_key );
+        Self::deposit_event( Event::ModuleDeregistered( netuid, uid_to_remove, old_key ) );
+    }
+
+    // Drops the entries of the row pointing at the removed uid and re-points those of the moved uid.
+    // Fails when the row holds neither, so that the caller skips the write. A row left empty is removed.
+    fn remap_uid_row( row: &mut Option<UidVec<T, (u16, u16)>>, uid_to_remove: u16, last_uid: u16 ) -> Result<(), ()> {
+        let entries: &mut UidVec<T, (u16, u16)> = row.as_mut().ok_or(())?;
+        if !entries.iter().any( |(j, _)| *j == uid_to_remove || *j == last_uid ) { return Err(()) }
+        entries.retain( |(j, _)| *j != uid_to_remove );
+        entries.iter_mut().for_each( |(j, _)| if *j == last_uid { *j = uid_to_remove } );
+        if entries.is_empty() { *row = None; }
+        Ok(())
+    }
+
+    // Deregisters the modules with the lowest pruning scores while the network holds more uids than its max allowed
+    // uids, as many as fit in 'ref_time_budget', and returns the ref time consumed. Only called while no epoch is in
+    // progress on the network, so that the removals do not have to complete one.
+    pub fn shrink_network( netuid: u16, ref_time_budget: u64 ) -> u64 {
+        let max_allowed_uids: u16 = Self::get_max_allowed_uids( netuid );
+        let mut used: u64 = 0;
+        while Self::get_network_n( netuid ) > max_allowed_uids {
+            let remove_ref_time: u64 = T::WeightInfo::remove_module( Self::get_network_n( netuid ) as u32 ).ref_time();
+            if !Self::is_within_epoch_budget( remove_ref_time, ref_time_budget.saturating_sub( used ) ) { break } // Out of budget for this block.
+            let uid_to_prune: u16 = Self::get_module_to_prune( netuid );
+            Self::remove_module( netuid, uid_to_prune );
+            used = used.saturating_add( remove_ref_time );
+        }
+        used.saturating_add( T::DbWeight::get().reads( 2 ).ref_time() )
+    }
+
+    // Shrinks the network down to its max allowed uids and then deregisters the flagged modules, within
+    // 'ref_time_budget'. Returns the ref time consumed. Nothing is pruned while an epoch is in progress.
+    pub fn prune_modules( netuid: u16, current_block: u64, ref_time_budget: u64 ) -> u64 {
+        if Self::is_epoch_in_progress( netuid ) { return T::DbWeight::get().reads( 1 ).ref_time() }
+        let used: u64 = Self::shrink_network( netuid, ref_time_budget );
+        used.saturating_add( Self::prune_flagged_modules( netuid, current_block, ref_time_budget.saturating_sub( used ) ) )
+    }
+
+    // Returns true if the module has been inactive for longer than the network prune window past the activity
+    // cutoff, is out of its immunity period and holds less stake than the network prune min stake.
+    pub fn is_module_prunable( netuid: u16, uid: u16, current_block: u64 ) -> bool {
+        let inactive_prune_window: u64 = Self::get_inactive_prune_window( netuid );
+        if inactive_prune_window == 0 { return false } // Pruning is disabled on this network.
+        if uid >= Self::get_network_n( netuid ) { return false }
+        let inactive_since: u64 = Self::get_last_update_for_uid( netuid, uid ).saturating_add( Self::get_activity_cutoff( netuid ) as u64 );
+        if current_block <= inactive_since.saturating_add( inactive_prune_window ) { return false }
+        let block_at_registration: u64 = Self::get_module_block_at_registration( netuid, uid );
+        if current_block.saturating_sub( block_at_registration ) < Self::get_immunity_period( netuid ) as u64 { return false }
+        Self::get_stake_for_uid_and_network( netuid, uid ) < Self::get_inactive_prune_min_stake( netuid )
+    }
+
+    // Flags the inactive modules which can be pruned, they are deregistered within the epoch budget of the blocks
+    // before the next epoch starts.
+    pub fn flag_modules_to_prune( netuid: u16, current_block: u64 ) {
+        let active: Vec<bool> = Self::get_active( netuid );
+        let flagged: Vec<u16> = (0..Self::get_network_n( netuid ))
+            .filter( |uid| !active.get( *uid as usize ).copied().unwrap_or( false ) )
+            .filter( |uid| Self::is_module_prunable( netuid, *uid, current_block ) )
+            .collect();
+        if flagged.is_empty() {
+            ModulesToPruneAtNextEpoch::<T>::remove( netuid );
+            return
+        }
+        log::info!("ModulesFlaggedForPruning( netuid:{:?} uids:{:?} ) ", netuid, flagged );
+        Self::deposit_event( Event::ModulesFlaggedForPruning( netuid, flagged.clone() ) );
+        ModulesToPruneAtNextEpoch::<T>::insert( netuid, Self::to_uid_vec( flagged ) );
+    }
+
+    // Deregisters the flagged modules which are still prunable, as many as fit in 'ref_time_budget', and returns
+    // the ref time consumed. The uids left over stay flagged for the next blocks. Uids are removed from the highest
+    // down so that the module moved into each freed slot is never one still to remove. Only called while no epoch
+    // is in progress on the network, so that the removals do not have to complete one.
+    pub fn prune_flagged_modules( netuid: u16, current_block: u64, ref_time_budget: u64 ) -> u64 {
+        if !ModulesToPruneAtNextEpoch::<T>::contains_key( netuid ) { return T::DbWeight::get().reads( 1 ).ref_time() }
+        let mut used: u64 = 0;
+        let mut flagged: Vec<u16> = ModulesToPruneAtNextEpoch::<T>::get( netuid ).into_inner();
+        flagged.sort_unstable();
+        flagged.dedup();
+        while let Some( &uid ) = flagged.last() {
+            let remove_ref_time: u64 = T::WeightInfo::remove_module( Self::get_network_n( netuid ) as u32 ).ref_time();
+            if !Self::is_within_epoch_budget( remove_ref_time, ref_time_budget.saturating_sub( used ) ) { break } // Out of budget for this block.
+            flagged.pop();
+            if !Self::is_module_prunable( netuid, uid, current_block ) { continue } // Active again, staked or replaced.
+            Self::remove_module( netuid, uid );
+            used = used.saturating_add( remove_ref_time );
+        }
+        if flagged.is_empty() {
+            ModulesToPruneAtNextEpoch::<T>::remove( netuid );
+        } else {
+            // A subset of a stored list, it stays within MaxUidsOf.
+            ModulesToPruneAtNextEpoch::<T>::insert( netuid, Self::to_uid_vec( flagged ) );
+        }
+        used.saturating_add( T::DbWeight::get().reads_writes( 1, 1 ).ref_time() )
+    }
+
     // Returns true if the uid is set on the network.
     //
     pub fn is_uid_exist_on_network(netuid: u16, uid: u16) -> bool {
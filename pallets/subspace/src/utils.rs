@@ -27,30 +27,41 @@ impl<T: Config> Pallet<T> {
     // ==============================
 	// ==== YumaConsensus params ====
 	// ==============================
-    pub fn get_rank( netuid:u16 ) -> Vec<u16> { Rank::<T>::get( netuid ) }
-    pub fn get_active( netuid:u16 ) -> Vec<bool> { Active::<T>::get( netuid ) }
-    pub fn get_emission( netuid:u16 ) -> Vec<u64> { Emission::<T>::get( netuid ) }
-    pub fn get_incentive( netuid:u16 ) -> Vec<u16> { Incentive::<T>::get( netuid ) }
-    pub fn get_dividends( netuid:u16 ) -> Vec<u16> { Dividends::<T>::get( netuid ) }
-    pub fn get_last_update( netuid:u16 ) -> Vec<u64> { LastUpdate::<T>::get( netuid ) }
-    pub fn get_pruning_score( netuid:u16 ) -> Vec<u16> { PruningScores::<T>::get( netuid ) }
+    pub fn get_rank( netuid:u16 ) -> Vec<u16> { Rank::<T>::get( netuid ).into_inner() }
+    pub fn get_trust( netuid:u16 ) -> Vec<u16> { Trust::<T>::get( netuid ).into_inner() }
+    pub fn get_consensus( netuid:u16 ) -> Vec<u16> { Consensus::<T>::get( netuid ).into_inner() }
+    pub fn get_active( netuid:u16 ) -> Vec<bool> { Active::<T>::get( netuid ).into_inner() }
+    pub fn get_validator_permit( netuid:u16 ) -> Vec<bool> { ValidatorPermits::<T>::get( netuid ).into_inner() }
+    pub fn get_emission( netuid:u16 ) -> Vec<u64> { Emission::<T>::get( netuid ).into_inner() }
+    pub fn get_incentive( netuid:u16 ) -> Vec<u16> { Incentive::<T>::get( netuid ).into_inner() }
+    pub fn get_dividends( netuid:u16 ) -> Vec<u16> { Dividends::<T>::get( netuid ).into_inner() }
+    pub fn get_last_update( netuid:u16 ) -> Vec<u64> { LastUpdate::<T>::get( netuid ).into_inner() }
+    pub fn get_pruning_score( netuid:u16 ) -> Vec<u16> { PruningScores::<T>::get( netuid ).into_inner() }
 
     // ==================================
 	// ==== YumaConsensus UID params ====
 	// ==================================
     pub fn set_last_update_for_uid( netuid:u16, uid: u16, last_update: u64 ) { 
-        let mut updated_last_update_vec = Self::get_last_update( netuid ); 
-        if (uid as usize) < updated_last_update_vec.len() { 
-            updated_last_update_vec[uid as usize] = last_update;
-            LastUpdate::<T>::insert( netuid, updated_last_update_vec );
-        }  
+        LastUpdate::<T>::mutate( netuid, |v| if let Some( updated ) = v.get_mut( uid as usize ) { *updated = last_update } );
     }
     pub fn set_active_for_uid( netuid:u16, uid: u16, active: bool ) { 
-        let mut updated_active_vec = Self::get_active( netuid ); 
-        if (uid as usize) < updated_active_vec.len() { 
-            updated_active_vec[uid as usize] = active;
-            Active::<T>::insert( netuid, updated_active_vec );
-        }  
+        Active::<T>::mutate( netuid, |v| if let Some( updated ) = v.get_mut( uid as usize ) { *updated = active } );
+    }
+    pub fn set_incentive_for_uid( netuid:u16, uid: u16, incentive: u16 ) { 
+        Incentive::<T>::mutate( netuid, |v| if let Some( updated ) = v.get_mut( uid as usize ) { *updated = incentive } );
+    }
+
+    // Bounds a per-uid vector or weights row. A network never holds more than MaxAllowedUidsUpperBound uids and rows
+    // never repeat a uid, so nothing is ever dropped.
+    pub fn to_uid_vec<V>( values: Vec<V> ) -> UidVec<T, V> {
+        debug_assert!( values.len() <= MaxUidsOf::<T>::get() as usize, "{} values exceed MaxUidsOf", values.len() );
+        UidVec::<T, V>::truncate_from( values )
+    }
+
+    // Appends the value of a new uid, or row entry, to a per-uid vector which has room for every uid of the network.
+    pub fn push_uid_value<V>( values: &mut UidVec<T, V>, value: V ) {
+        let pushed: bool = values.try_push( value ).is_ok();
+        debug_assert!( pushed, "per-uid vector is full at MaxUidsOf" );
     }
     pub fn set_pruning_score_for_uid( netuid:u16, uid: u16, pruning_score: u16 ) {
         log::info!("netuid = {:?}", netuid);
@@ -62,7 +73,9 @@ impl<T: Config> Pallet<T> {
 
     pub fn get_rank_for_uid( netuid:u16, uid: u16) -> u16 { let vec = Rank::<T>::get( netuid ); if (uid as usize) < vec.len() { return vec[uid as usize] } else{ return 0 } }
     pub fn get_trust_for_uid( netuid:u16, uid: u16) -> u16 { let vec = Trust::<T>::get( netuid ); if (uid as usize) < vec.len() { return vec[uid as usize] } else{ return 0 } }
+    pub fn get_consensus_for_uid( netuid:u16, uid: u16) -> u16 { let vec = Consensus::<T>::get( netuid ); if (uid as usize) < vec.len() { return vec[uid as usize] } else{ return 0 } }
     pub fn get_emission_for_uid( netuid:u16, uid: u16) -> u64 {let vec =  Emission::<T>::get( netuid ); if (uid as usize) < vec.len() { return vec[uid as usize] } else{ return 0 } }
+    pub fn get_validator_permit_for_uid( netuid:u16, uid: u16) -> bool { let vec = ValidatorPermits::<T>::get( netuid ); if (uid as usize) < vec.len() { return vec[uid as usize] } else{ return false } }
     pub fn get_active_for_uid( netuid:u16, uid: u16) -> bool { let vec = Active::<T>::get( netuid ); if (uid as usize) < vec.len() { return vec[uid as usize] } else{ return false } }
     pub fn get_incentive_for_uid( netuid:u16, uid: u16) -> u16 { let vec = Incentive::<T>::get( netuid ); if (uid as usize) < vec.len() { return vec[uid as usize] } else{ return 0 } }
     pub fn get_dividends_for_uid( netuid:u16, uid: u16) -> u16 { let vec = Dividends::<T>::get( netuid ); if (uid as usize) < vec.len() { return vec[uid as usize] } else{ return 0 } }
@@ -112,32 +125,109 @@ impl<T: Config> Pallet<T> {
         Ok(()) 
     }
 
+	// ========================
+	// Configure the treasury cut
+	pub fn get_treasury_cut() -> u16 { TreasuryCut::<T>::get() }
+    pub fn set_treasury_cut( treasury_cut: u16 ) { TreasuryCut::<T>::put( treasury_cut ) }
+    pub fn check_treasury_cut( treasury_cut: u16 ) -> bool { treasury_cut <= 100 }
+    pub fn do_sudo_set_treasury_cut( origin: T::RuntimeOrigin, treasury_cut: u16 ) -> DispatchResult { 
+        ensure_root( origin )?;
+        ensure!( Self::check_treasury_cut( treasury_cut ), Error::<T>::StorageValueOutOfRange );
+        Self::set_treasury_cut( treasury_cut );
+        log::info!("TreasuryCutSet( treasury_cut: {:?} ) ", treasury_cut );
+        Self::deposit_event( Event::TreasuryCutSet( treasury_cut ) );
+        Ok(()) 
+    }
+
     pub fn get_serving_rate_limit( netuid: u16 ) -> u64 { ServingRateLimit::<T>::get(netuid) }
     pub fn set_serving_rate_limit( netuid: u16, serving_rate_limit: u64 ) { ServingRateLimit::<T>::insert( netuid, serving_rate_limit ) }
+    pub fn check_serving_rate_limit( _netuid: u16, serving_rate_limit: u64 ) -> bool { serving_rate_limit <= T::ServingRateLimitUpperBound::get() }
     pub fn do_sudo_set_serving_rate_limit( origin: T::RuntimeOrigin, netuid: u16, serving_rate_limit: u64 ) -> DispatchResult { 
         ensure_root( origin )?;
+        ensure!( Self::check_serving_rate_limit( netuid, serving_rate_limit ), Error::<T>::StorageValueOutOfRange );
         Self::set_serving_rate_limit( netuid, serving_rate_limit );
         log::info!("ServingRateLimitSet( serving_rate_limit: {:?} ) ", serving_rate_limit );
         Self::deposit_event( Event::ServingRateLimitSet( netuid, serving_rate_limit ) );
         Ok(()) 
     }
 
+    pub fn get_allow_private_ips( netuid: u16 ) -> bool { AllowPrivateIps::<T>::get( netuid ) }
+    pub fn set_allow_private_ips( netuid: u16, allow_private_ips: bool ) { AllowPrivateIps::<T>::insert( netuid, allow_private_ips ) }
+    pub fn do_sudo_set_allow_private_ips( origin: T::RuntimeOrigin, netuid: u16, allow_private_ips: bool ) -> DispatchResult { 
+        ensure_root( origin )?;
+        ensure!(Self::if_network_exist(netuid), Error::<T>::NetworkDoesNotExist);
+        Self::set_allow_private_ips( netuid, allow_private_ips );
+        log::info!("AllowPrivateIpsSet( netuid: {:?} allow_private_ips: {:?} ) ", netuid, allow_private_ips );
+        Self::deposit_event( Event::AllowPrivateIpsSet( netuid, allow_private_ips ) );
+        Ok(()) 
+    }
+
     pub fn get_weights_set_rate_limit( netuid: u16) -> u64 { WeightsSetRateLimit::<T>::get( netuid ) }
     pub fn set_weights_set_rate_limit( netuid: u16, weights_set_rate_limit: u64 ) { WeightsSetRateLimit::<T>::insert( netuid, weights_set_rate_limit ); }
+    // Validators must be able to set weights again before they count as inactive.
+    pub fn check_weights_set_rate_limit( netuid: u16, weights_set_rate_limit: u64 ) -> bool { weights_set_rate_limit < Self::get_activity_cutoff( netuid ) as u64 }
     pub fn do_sudo_set_weights_set_rate_limit( origin: T::RuntimeOrigin, netuid: u16, weights_set_rate_limit: u64 ) -> DispatchResult { 
         ensure_root( origin )?;
         ensure!(Self::if_network_exist(netuid), Error::<T>::NetworkDoesNotExist);
+        ensure!( Self::check_weights_set_rate_limit( netuid, weights_set_rate_limit ), Error::<T>::StorageValueOutOfRange );
         Self::set_weights_set_rate_limit( netuid, weights_set_rate_limit );
         log::info!("WeightsSetRateLimitSet( netuid: {:?} weights_set_rate_limit: {:?} ) ", netuid, weights_set_rate_limit);
         Self::deposit_event( Event::WeightsSetRateLimitSet( netuid, weights_set_rate_limit) );
         Ok(()) 
     }
 
+    pub fn get_weights_version_key( netuid: u16 ) -> u64 { WeightsVersionKey::<T>::get( netuid ) }
+    pub fn set_weights_version_key( netuid: u16, weights_version_key: u64 ) { WeightsVersionKey::<T>::insert( netuid, weights_version_key ); }
+    // The version key only moves forward, outdated validators are never admitted again.
+    pub fn check_weights_version_key( netuid: u16, weights_version_key: u64 ) -> bool { weights_version_key >= Self::get_weights_version_key( netuid ) }
+    pub fn do_sudo_set_weights_version_key( origin: T::RuntimeOrigin, netuid: u16, weights_version_key: u64 ) -> DispatchResult {
+        ensure_root( origin )?;
+        ensure!(Self::if_network_exist(netuid), Error::<T>::NetworkDoesNotExist);
+        ensure!( Self::check_weights_version_key( netuid, weights_version_key ), Error::<T>::StorageValueOutOfRange );
+        Self::set_weights_version_key( netuid, weights_version_key );
+        log::info!("WeightsVersionKeySet( netuid: {:?} weights_version_key: {:?} ) ", netuid, weights_version_key );
+        Self::deposit_event( Event::WeightsVersionKeySet( netuid, weights_version_key ) );
+        Ok(())
+    }
+
+    pub fn get_weights_decay_half_life( netuid: u16 ) -> u64 { WeightsDecayHalfLife::<T>::get( netuid ) }
+    pub fn set_weights_decay_half_life( netuid: u16, weights_decay_half_life: u64 ) { WeightsDecayHalfLife::<T>::insert( netuid, weights_decay_half_life ); }
+    // Either disabled, or the weights set during the last tempo keep at least half of their value.
+    pub fn check_weights_decay_half_life( netuid: u16, weights_decay_half_life: u64 ) -> bool { weights_decay_half_life == 0 || weights_decay_half_life >= Self::get_tempo( netuid ) as u64 }
+    pub fn do_sudo_set_weights_decay_half_life( origin: T::RuntimeOrigin, netuid: u16, weights_decay_half_life: u64 ) -> DispatchResult {
+        // Read by the epoch, so the change waits for the next epoch boundary.
+        Self::do_sudo_schedule_hyperparameter_change( origin, netuid, HyperparameterChange::WeightsDecayHalfLife( weights_decay_half_life ), 0 )
+    }
+
+    pub fn get_inactive_prune_window( netuid: u16 ) -> u64 { InactivePruneWindow::<T>::get( netuid ) }
+    pub fn set_inactive_prune_window( netuid: u16, inactive_prune_window: u64 ) { InactivePruneWindow::<T>::insert( netuid, inactive_prune_window ); }
+    pub fn do_sudo_set_inactive_prune_window( origin: T::RuntimeOrigin, netuid: u16, inactive_prune_window: u64 ) -> DispatchResult {
+        ensure_root( origin )?;
+        ensure!(Self::if_network_exist(netuid), Error::<T>::NetworkDoesNotExist);
+        Self::set_inactive_prune_window( netuid, inactive_prune_window );
+        log::info!("InactivePruneWindowSet( netuid: {:?} inactive_prune_window: {:?} ) ", netuid, inactive_prune_window );
+        Self::deposit_event( Event::InactivePruneWindowSet( netuid, inactive_prune_window ) );
+        Ok(())
+    }
+
+    pub fn get_inactive_prune_min_stake( netuid: u16 ) -> u64 { InactivePruneMinStake::<T>::get( netuid ) }
+    pub fn set_inactive_prune_min_stake( netuid: u16, inactive_prune_min_stake: u64 ) { InactivePruneMinStake::<T>::insert( netuid, inactive_prune_min_stake ); }
+    pub fn do_sudo_set_inactive_prune_min_stake( origin: T::RuntimeOrigin, netuid: u16, inactive_prune_min_stake: u64 ) -> DispatchResult {
+        ensure_root( origin )?;
+        ensure!(Self::if_network_exist(netuid), Error::<T>::NetworkDoesNotExist);
+        Self::set_inactive_prune_min_stake( netuid, inactive_prune_min_stake );
+        log::info!("InactivePruneMinStakeSet( netuid: {:?} inactive_prune_min_stake: {:?} ) ", netuid, inactive_prune_min_stake );
+        Self::deposit_event( Event::InactivePruneMinStakeSet( netuid, inactive_prune_min_stake ) );
+        Ok(())
+    }
+
     pub fn get_adjustment_interval( netuid: u16) -> u16 { AdjustmentInterval::<T>::get( netuid ) }
     pub fn set_adjustment_interval( netuid: u16, adjustment_interval: u16 ) { AdjustmentInterval::<T>::insert( netuid, adjustment_interval ); }
+    pub fn check_adjustment_interval( _netuid: u16, adjustment_interval: u16 ) -> bool { adjustment_interval >= T::AdjustmentIntervalLowerBound::get() }
     pub fn do_sudo_set_adjustment_interval( origin: T::RuntimeOrigin, netuid: u16, adjustment_interval: u16 ) -> DispatchResult { 
         ensure_root( origin )?;
         ensure!(Self::if_network_exist(netuid), Error::<T>::NetworkDoesNotExist);
+        ensure!( Self::check_adjustment_interval( netuid, adjustment_interval ), Error::<T>::StorageValueOutOfRange );
         Self::set_adjustment_interval( netuid, adjustment_interval );
         log::info!("AdjustmentIntervalSet( netuid: {:?} adjustment_interval: {:?} ) ", netuid, adjustment_interval);
         Self::deposit_event( Event::AdjustmentIntervalSet( netuid, adjustment_interval) );
@@ -179,20 +269,22 @@ impl<T: Config> Pallet<T> {
     }
     pub fn get_max_weight_limit( netuid: u16) -> u16 { MaxWeightsLimit::<T>::get( netuid ) }    
     pub fn set_max_weight_limit( netuid: u16, max_weight_limit: u16 ) { MaxWeightsLimit::<T>::insert( netuid, max_weight_limit ); }
+    // A row of min allowed weights, normalized to u16::MAX, must still fit under the limit.
+    pub fn check_max_weight_limit( netuid: u16, max_weight_limit: u16 ) -> bool {
+        ( max_weight_limit as u64 ).saturating_mul( Self::get_min_allowed_weights( netuid ).max( 1 ) as u64 ) >= u16::MAX as u64
+    }
     pub fn do_sudo_set_max_weight_limit( origin:T::RuntimeOrigin, netuid: u16, max_weight_limit: u16 ) -> DispatchResult {
-        ensure_root( origin )?;
-        ensure!( Self::if_network_exist(netuid), Error::<T>::NetworkDoesNotExist );
-        Self::set_max_weight_limit( netuid, max_weight_limit );
-        log::info!("MaxWeightLimitSet( netuid: {:?} max_weight_limit: {:?} ) ", netuid, max_weight_limit);
-        Self::deposit_event( Event::MaxWeightLimitSet( netuid, max_weight_limit ) );
-        Ok(())
+        // Bounds the weights the epoch reads, so the change waits for the next epoch boundary.
+        Self::do_sudo_schedule_hyperparameter_change( origin, netuid, HyperparameterChange::MaxWeightsLimit( max_weight_limit ), 0 )
     }
 
     pub fn get_immunity_period(netuid: u16 ) -> u16 { ImmunityPeriod::<T>::get( netuid ) }
     pub fn set_immunity_period( netuid: u16, immunity_period: u16 ) { ImmunityPeriod::<T>::insert( netuid, immunity_period ); }
+    pub fn check_immunity_period( _netuid: u16, immunity_period: u16 ) -> bool { immunity_period <= T::ImmunityPeriodUpperBound::get() }
     pub fn do_sudo_set_immunity_period( origin:T::RuntimeOrigin, netuid: u16, immunity_period: u16 ) -> DispatchResult {
         ensure_root( origin )?;
         ensure!(Self::if_network_exist(netuid), Error::<T>::NetworkDoesNotExist);
+        ensure!( Self::check_immunity_period( netuid, immunity_period ), Error::<T>::StorageValueOutOfRange );
         Self::set_immunity_period( netuid, immunity_period );
         log::info!("ImmunityPeriodSet( netuid: {:?} immunity_period: {:?} ) ", netuid, immunity_period);
         Self::deposit_event(Event::ImmunityPeriodSet(netuid, immunity_period));
@@ -201,46 +293,100 @@ impl<T: Config> Pallet<T> {
 
 
             
+    pub fn get_rho( netuid: u16 ) -> u16 { Rho::<T>::get( netuid ) }
+    pub fn set_rho( netuid: u16, rho: u16 ) { Rho::<T>::insert( netuid, rho ); }
+    // The recommended sigmoid steepness range, 0 < rho <= 40.
+    pub fn check_rho( _netuid: u16, rho: u16 ) -> bool { rho > 0 && rho <= 40 }
+    pub fn do_sudo_set_rho( origin:T::RuntimeOrigin, netuid: u16, rho: u16 ) -> DispatchResult {
+        // Read by the epoch, so the change waits for the next epoch boundary.
+        Self::do_sudo_schedule_hyperparameter_change( origin, netuid, HyperparameterChange::Rho( rho ), 0 )
+    }
+
+    pub fn get_kappa( netuid: u16 ) -> u16 { Kappa::<T>::get( netuid ) }
+    pub fn set_kappa( netuid: u16, kappa: u16 ) { Kappa::<T>::insert( netuid, kappa ); }
+    // The consensus majority is a ratio strictly between 0 and 1.
+    pub fn check_kappa( _netuid: u16, kappa: u16 ) -> bool { kappa > 0 && kappa < u16::MAX }
+    pub fn do_sudo_set_kappa( origin:T::RuntimeOrigin, netuid: u16, kappa: u16 ) -> DispatchResult {
+        // Read by the epoch, so the change waits for the next epoch boundary.
+        Self::do_sudo_schedule_hyperparameter_change( origin, netuid, HyperparameterChange::Kappa( kappa ), 0 )
+    }
+
+    pub fn get_incentive_ratio( netuid: u16 ) -> u16 { IncentiveRatio::<T>::get( netuid ) }
+    pub fn set_incentive_ratio( netuid: u16, incentive_ratio: u16 ) { IncentiveRatio::<T>::insert( netuid, incentive_ratio ); }
+    pub fn check_incentive_ratio( _netuid: u16, incentive_ratio: u16 ) -> bool { incentive_ratio <= 100 }
+    pub fn do_sudo_set_incentive_ratio( origin:T::RuntimeOrigin, netuid: u16, incentive_ratio: u16 ) -> DispatchResult {
+        // Read by the epoch, so the change waits for the next epoch boundary.
+        Self::do_sudo_schedule_hyperparameter_change( origin, netuid, HyperparameterChange::IncentiveRatio( incentive_ratio ), 0 )
+    }
+
+    pub fn get_consensus_mechanism( netuid: u16 ) -> ConsensusMechanismKind { NetworkConsensusMechanism::<T>::get( netuid ) }
+    pub fn set_consensus_mechanism( netuid: u16, consensus_mechanism: ConsensusMechanismKind ) { NetworkConsensusMechanism::<T>::insert( netuid, consensus_mechanism ); }
+    // The mechanisms reading weights need validators to set them.
+    pub fn check_consensus_mechanism( netuid: u16, consensus_mechanism: ConsensusMechanismKind ) -> bool {
+        consensus_mechanism == ConsensusMechanismKind::Stake || Self::get_max_allowed_validators( netuid ) > 0
+    }
+    pub fn do_sudo_set_consensus_mechanism( origin:T::RuntimeOrigin, netuid: u16, consensus_mechanism: ConsensusMechanismKind ) -> DispatchResult {
+        // Read by the epoch, so the change waits for the next epoch boundary.
+        Self::do_sudo_schedule_hyperparameter_change( origin, netuid, HyperparameterChange::ConsensusMechanism( consensus_mechanism ), 0 )
+    }
+
     pub fn get_min_allowed_weights( netuid:u16 ) -> u16 { MinAllowedWeights::<T>::get( netuid ) }
     pub fn set_min_allowed_weights( netuid: u16, min_allowed_weights: u16 ) { MinAllowedWeights::<T>::insert( netuid, min_allowed_weights ); }
+    pub fn check_min_allowed_weights( netuid: u16, min_allowed_weights: u16 ) -> bool { min_allowed_weights <= Self::get_max_allowed_uids( netuid ) }
     pub fn do_sudo_set_min_allowed_weights( origin:T::RuntimeOrigin, netuid: u16, min_allowed_weights: u16 ) -> DispatchResult {
-        ensure_root( origin )?;
-        ensure!(Self::if_network_exist(netuid), Error::<T>::NetworkDoesNotExist);
-        Self::set_min_allowed_weights( netuid, min_allowed_weights );
-        log::info!("MinAllowedWeightSet( netuid: {:?} min_allowed_weights: {:?} ) ", netuid, min_allowed_weights);
-        Self::deposit_event( Event::MinAllowedWeightSet( netuid, min_allowed_weights) );
-        Ok(())
+        // Bounds the weights the epoch reads, so the change waits for the next epoch boundary.
+        Self::do_sudo_schedule_hyperparameter_change( origin, netuid, HyperparameterChange::MinAllowedWeights( min_allowed_weights ), 0 )
     }
 
     pub fn get_max_allowed_uids( netuid: u16 ) -> u16  { MaxAllowedUids::<T>::get( netuid ) }
     pub fn set_max_allowed_uids(netuid: u16, max_allowed: u16) { MaxAllowedUids::<T>::insert( netuid, max_allowed ); }
+    pub fn check_max_allowed_uids( netuid: u16, max_allowed_uids: u16 ) -> bool {
+        if max_allowed_uids < T::MaxAllowedUidsLowerBound::get() || max_allowed_uids > T::MaxAllowedUidsUpperBound::get() { return false; }
+        // The other size dependent parameters must still fit within the new size.
+        if Self::get_min_allowed_weights( netuid ) > max_allowed_uids { return false; }
+        if Self::get_max_registrations_per_block( netuid ) > max_allowed_uids { return false; }
+        if Self::get_max_allowed_validators( netuid ) > max_allowed_uids { return false; }
+        return true;
+    }
     pub fn do_sudo_set_max_allowed_uids( origin:T::RuntimeOrigin, netuid: u16, max_allowed_uids: u16 ) -> DispatchResult {
-        ensure_root( origin )?;
-        ensure!( Self::if_network_exist(netuid), Error::<T>::NetworkDoesNotExist );
-        ensure!(Self::get_max_allowed_uids(netuid)< max_allowed_uids, Error::<T>::MaxAllowedUIdsNotAllowed);
-        Self::set_max_allowed_uids( netuid, max_allowed_uids );
-        log::info!("MaxAllowedUidsSet( netuid: {:?} max_allowed_uids: {:?} ) ", netuid, max_allowed_uids);
-        Self::deposit_event( Event::MaxAllowedUidsSet( netuid, max_allowed_uids) );
-        Ok(())
+        // Read by the epoch, so the change waits for the next epoch boundary.
+        Self::do_sudo_schedule_hyperparameter_change( origin, netuid, HyperparameterChange::MaxAllowedUids( max_allowed_uids ), 0 )
     }
 
             
+    pub fn get_max_allowed_validators( netuid: u16 ) -> u16 { MaxAllowedValidators::<T>::get( netuid ) }
+    pub fn set_max_allowed_validators( netuid: u16, max_allowed_validators: u16 ) { MaxAllowedValidators::<T>::insert( netuid, max_allowed_validators ); }
+    pub fn check_max_allowed_validators( netuid: u16, max_allowed_validators: u16 ) -> bool {
+        if max_allowed_validators > Self::get_max_allowed_uids( netuid ) { return false; }
+        // The mechanisms reading weights need validators to set them.
+        max_allowed_validators > 0 || Self::get_consensus_mechanism( netuid ) == ConsensusMechanismKind::Stake
+    }
+    pub fn do_sudo_set_max_allowed_validators( origin:T::RuntimeOrigin, netuid: u16, max_allowed_validators: u16 ) -> DispatchResult {
+        // Read by the epoch, so the change waits for the next epoch boundary.
+        Self::do_sudo_schedule_hyperparameter_change( origin, netuid, HyperparameterChange::MaxAllowedValidators( max_allowed_validators ), 0 )
+    }
+
     pub fn get_activity_cutoff( netuid: u16 ) -> u16  { ActivityCutoff::<T>::get( netuid ) }
     pub fn set_activity_cutoff( netuid: u16, activity_cutoff: u16 ) { ActivityCutoff::<T>::insert( netuid, activity_cutoff ); }
+    pub fn check_activity_cutoff( netuid: u16, activity_cutoff: u16 ) -> bool {
+        if activity_cutoff < T::ActivityCutoffLowerBound::get() || activity_cutoff > T::ActivityCutoffUpperBound::get() { return false; }
+        // The weights rate limit and reveal window must still fit within the new cutoff.
+        if Self::get_weights_set_rate_limit( netuid ) >= activity_cutoff as u64 { return false; }
+        if Self::get_weights_reveal_window( netuid ) > activity_cutoff as u64 { return false; }
+        return true;
+    }
     pub fn do_sudo_set_activity_cutoff( origin:T::RuntimeOrigin, netuid: u16, activity_cutoff: u16 ) -> DispatchResult {
-        ensure_root( origin )?;
-        ensure!(Self::if_network_exist(netuid), Error::<T>::NetworkDoesNotExist);
-        Self::set_activity_cutoff( netuid, activity_cutoff );
-        log::info!("ActivityCutoffSet( netuid: {:?} activity_cutoff: {:?} ) ", netuid, activity_cutoff);
-        Self::deposit_event( Event::ActivityCutoffSet( netuid, activity_cutoff) );
-        Ok(())
+        // Read by the epoch, so the change waits for the next epoch boundary.
+        Self::do_sudo_schedule_hyperparameter_change( origin, netuid, HyperparameterChange::ActivityCutoff( activity_cutoff ), 0 )
     }
             
     pub fn get_target_registrations_per_interval( netuid: u16 ) -> u16 { TargetRegistrationsPerInterval::<T>::get( netuid ) }
     pub fn set_target_registrations_per_interval( netuid: u16, target_registrations_per_interval: u16 ) { TargetRegistrationsPerInterval::<T>::insert( netuid, target_registrations_per_interval ); }
+    pub fn check_target_registrations_per_interval( netuid: u16, target_registrations_per_interval: u16 ) -> bool { target_registrations_per_interval <= Self::get_max_allowed_uids( netuid ) }
     pub fn do_sudo_set_target_registrations_per_interval( origin:T::RuntimeOrigin, netuid: u16, target_registrations_per_interval: u16 ) -> DispatchResult {
         ensure_root( origin )?;
         ensure!(Self::if_network_exist(netuid), Error::<T>::NetworkDoesNotExist);
+        ensure!( Self::check_target_registrations_per_interval( netuid, target_registrations_per_interval ), Error::<T>::StorageValueOutOfRange );
         Self::set_target_registrations_per_interval( netuid, target_registrations_per_interval );
         log::info!("RegistrationPerIntervalSet( netuid: {:?} target_registrations_per_interval: {:?} ) ", netuid, target_registrations_per_interval );
         Self::deposit_event( Event::RegistrationPerIntervalSet( netuid, target_registrations_per_interval) );
@@ -252,17 +398,47 @@ impl<T: Config> Pallet<T> {
             
     pub fn get_bonds_moving_average( netuid: u16 ) -> u64 { BondsMovingAverage::<T>::get( netuid ) }
     pub fn set_bonds_moving_average( netuid: u16, bonds_moving_average: u64 ) { BondsMovingAverage::<T>::insert( netuid, bonds_moving_average ); }
+    pub fn check_bonds_moving_average( _netuid: u16, bonds_moving_average: u64 ) -> bool { bonds_moving_average <= T::BondsMovingAverageUpperBound::get() }
     pub fn do_sudo_set_bonds_moving_average( origin:T::RuntimeOrigin, netuid: u16, bonds_moving_average: u64 ) -> DispatchResult {
+        // Read by the epoch, so the change waits for the next epoch boundary.
+        Self::do_sudo_schedule_hyperparameter_change( origin, netuid, HyperparameterChange::BondsMovingAverage( bonds_moving_average ), 0 )
+    }
+
+    pub fn is_commit_reveal_enabled( netuid: u16 ) -> bool { CommitRevealEnabled::<T>::get( netuid ) }
+    pub fn set_commit_reveal_enabled( netuid: u16, enabled: bool ) { CommitRevealEnabled::<T>::insert( netuid, enabled ); }
+    // Commitments can only be enabled with a reveal window they can be revealed in.
+    pub fn check_commit_reveal_enabled( netuid: u16, enabled: bool ) -> bool { !enabled || Self::check_weights_reveal_window( netuid, Self::get_weights_reveal_window( netuid ) ) }
+    pub fn do_sudo_set_commit_reveal_enabled( origin:T::RuntimeOrigin, netuid: u16, enabled: bool ) -> DispatchResult {
+        ensure_root( origin )?;
+        ensure!(Self::if_network_exist(netuid), Error::<T>::NetworkDoesNotExist);
+        ensure!( Self::check_commit_reveal_enabled( netuid, enabled ), Error::<T>::StorageValueOutOfRange );
+        Self::set_commit_reveal_enabled( netuid, enabled );
+        log::info!("CommitRevealEnabledSet( netuid: {:?} enabled: {:?} ) ", netuid, enabled );
+        Self::deposit_event( Event::CommitRevealEnabledSet( netuid, enabled ) );
+        Ok(())
+    }
+
+    pub fn get_weights_reveal_window( netuid: u16 ) -> u64 { WeightsRevealWindow::<T>::get( netuid ) }
+    pub fn set_weights_reveal_window( netuid: u16, weights_reveal_window: u64 ) { WeightsRevealWindow::<T>::insert( netuid, weights_reveal_window ); }
+    // Commitments must be revealable, and revealed before their validator counts as inactive.
+    pub fn check_weights_reveal_window( netuid: u16, weights_reveal_window: u64 ) -> bool {
+        weights_reveal_window > 0 && weights_reveal_window <= Self::get_activity_cutoff( netuid ) as u64
+    }
+    pub fn do_sudo_set_weights_reveal_window( origin:T::RuntimeOrigin, netuid: u16, weights_reveal_window: u64 ) -> DispatchResult {
         ensure_root( origin )?;
         ensure!(Self::if_network_exist(netuid), Error::<T>::NetworkDoesNotExist);
-        Self::set_bonds_moving_average( netuid, bonds_moving_average );
-        log::info!("BondsMovingAverageSet( netuid: {:?} bonds_moving_average: {:?} ) ", netuid, bonds_moving_average );
-        Self::deposit_event( Event::BondsMovingAverageSet( netuid, bonds_moving_average ) );
+        ensure!( Self::check_weights_reveal_window( netuid, weights_reveal_window ), Error::<T>::StorageValueOutOfRange );
+        Self::set_weights_reveal_window( netuid, weights_reveal_window );
+        log::info!("WeightsRevealWindowSet( netuid: {:?} weights_reveal_window: {:?} ) ", netuid, weights_reveal_window );
+        Self::deposit_event( Event::WeightsRevealWindowSet( netuid, weights_reveal_window ) );
         Ok(())
     }
 
     pub fn get_max_registrations_per_block( netuid: u16 ) -> u16 { MaxRegistrationsPerBlock::<T>::get( netuid ) }
     pub fn set_max_registrations_per_block( netuid: u16, max_registrations_per_block: u16 ) { MaxRegistrationsPerBlock::<T>::insert( netuid, max_registrations_per_block ); }
+    pub fn check_max_registrations_per_block( netuid: u16, max_registrations_per_block: u16 ) -> bool {
+        max_registrations_per_block <= T::MaxRegistrationsPerBlockUpperBound::get() && max_registrations_per_block <= Self::get_max_allowed_uids( netuid )
+    }
     pub fn do_sudo_set_max_registrations_per_block(
         origin: T::RuntimeOrigin, 
         netuid: u16, 
@@ -270,6 +446,7 @@ impl<T: Config> Pallet<T> {
     ) -> DispatchResult {
         ensure_root( origin )?;
         ensure!(Self::if_network_exist(netuid), Error::<T>::NetworkDoesNotExist);
+        ensure!( Self::check_max_registrations_per_block( netuid, max_registrations_per_block ), Error::<T>::StorageValueOutOfRange );
         Self::set_max_registrations_per_block( netuid, max_registrations_per_block );
         log::info!("MaxRegistrationsPerBlock( netuid: {:?} max_registrations_per_block: {:?} ) ", netuid, max_registrations_per_block );
         Self::deposit_event( Event::MaxRegistrationsPerBlockSet( netuid, max_registrations_per_block) );
@@ -0,0 +1,927 @@
+//! Weights for pallet_subspace
+//!
+//! Follows the layout of the frame-benchmarking weight template. Component names:
+//! `w` is the number of weights in a call or the weight entries walked by an epoch phase,
+//! `n` the network size, `r` the rows and `c` the columns handled by an epoch step, `t` the
+//! emission tuples drained, `k` the weight commitments expired and `q` the queued hyperparameter
+//! changes walked. `remove_module` is measured with every weights and bonds row pointing at both
+//! the removed and the moved uid, so `n` covers the worst case of the remap of those rows.
+//!
+//! These values are NOT benchmark output yet. The storage accesses are counted from the code and
+//! the ref times are estimates set by hand, so they must be regenerated before they are relied on.
+//! They carry no proof size. Until they do, the block step adds the bound of the epoch state,
+//! the largest value it reads, on top of them.
+//!
+//! Regenerate on reference hardware with:
+//! ./target/release/node-subspace benchmark pallet --chain=local --steps=50 --repeat=20
+//! --pallet=pallet_subspace --extrinsic=* --execution=wasm --wasm-execution=compiled
+//! --output=./pallets/subspace/src/weight_info.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use frame_support::sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_subspace.
+pub trait WeightInfo {
+	fn set_weights(w: u32, ) -> Weight;
+	fn set_weights_by_key(w: u32, ) -> Weight;
+	fn patch_weights(w: u32, ) -> Weight;
+	fn commit_weights() -> Weight;
+	fn expire_weight_commits(k: u32, ) -> Weight;
+	fn reveal_weights(w: u32, ) -> Weight;
+	fn add_stake() -> Weight;
+	fn remove_stake() -> Weight;
+	fn serve_module(e: u32, g: u32, ) -> Weight;
+	fn register() -> Weight;
+	fn sudo_set_serving_rate_limit() -> Weight;
+	fn sudo_set_allow_private_ips() -> Weight;
+	fn sudo_set_tx_rate_limit() -> Weight;
+	fn sudo_set_treasury_cut() -> Weight;
+	fn sudo_set_weights_set_rate_limit() -> Weight;
+	fn sudo_set_weights_version_key() -> Weight;
+	fn sudo_set_weights_decay_half_life() -> Weight;
+	fn sudo_set_inactive_prune_window() -> Weight;
+	fn sudo_set_inactive_prune_min_stake() -> Weight;
+	fn sudo_set_bonds_moving_average() -> Weight;
+	fn sudo_set_max_allowed_validators() -> Weight;
+	fn sudo_set_difficulty() -> Weight;
+	fn sudo_set_adjustment_interval() -> Weight;
+	fn sudo_set_target_registrations_per_interval() -> Weight;
+	fn sudo_set_activity_cutoff() -> Weight;
+	fn sudo_set_max_allowed_uids() -> Weight;
+	fn sudo_set_min_allowed_weights() -> Weight;
+	fn sudo_set_immunity_period() -> Weight;
+	fn sudo_set_max_weight_limit() -> Weight;
+	fn sudo_set_max_registrations_per_block() -> Weight;
+	fn sudo_set_rho() -> Weight;
+	fn sudo_set_kappa() -> Weight;
+	fn sudo_set_incentive_ratio() -> Weight;
+	fn sudo_set_consensus_mechanism() -> Weight;
+	fn sudo_schedule_hyperparameter_change() -> Weight;
+	fn sudo_cancel_hyperparameter_change() -> Weight;
+	fn sudo_set_commit_reveal_enabled() -> Weight;
+	fn sudo_set_weights_reveal_window() -> Weight;
+	fn pay_treasury() -> Weight;
+	fn block_step_network() -> Weight;
+	fn apply_hyperparameter_changes(q: u32, ) -> Weight;
+	fn drain_emission(t: u32, ) -> Weight;
+	fn remove_module(n: u32, ) -> Weight;
+	fn start_epoch(n: u32, ) -> Weight;
+	fn epoch_load(r: u32, w: u32, ) -> Weight;
+	fn epoch_consensus(c: u32, w: u32, ) -> Weight;
+	fn epoch_rank(r: u32, w: u32, ) -> Weight;
+	fn epoch_ema(r: u32, w: u32, ) -> Weight;
+	fn epoch_bonds(r: u32, w: u32, ) -> Weight;
+	fn epoch_emission(n: u32, ) -> Weight;
+	fn epoch_write_back(n: u32, ) -> Weight;
+}
+
+/// Weights for pallet_subspace using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Subspace Weights (r:1 w:1)
+	// Storage: Subspace LastUpdate (r:1 w:1)
+	// Storage: Subspace Uids (r:1 w:0)
+	// Storage: Subspace WeightsVersionKey (r:1 w:0)
+	fn set_weights(w: u32, ) -> Weight {
+		Weight::from_ref_time(20_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_473_000 as u64).saturating_mul(w as u64))
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(w as u64)))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: Subspace Weights (r:1 w:1)
+	// Storage: Subspace KeyWeights (r:1 w:1)
+	// Storage: Subspace LastUpdate (r:1 w:1)
+	// Storage: Subspace Uids (r:1 w:0)
+	fn set_weights_by_key(w: u32, ) -> Weight {
+		Weight::from_ref_time(24_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_473_000 as u64).saturating_mul(w as u64))
+			.saturating_add(T::DbWeight::get().reads(16 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(w as u64)))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: Subspace Weights (r:1 w:1)
+	// Storage: Subspace LastUpdate (r:1 w:1)
+	// Storage: Subspace Uids (r:1 w:0)
+	// Storage: Subspace KeyWeights (r:1 w:1)
+	fn patch_weights(w: u32, ) -> Weight {
+		Weight::from_ref_time(22_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_473_000 as u64).saturating_mul(w as u64))
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(w as u64)))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: Subspace CommitRevealEnabled (r:1 w:0)
+	// Storage: Subspace Uids (r:1 w:0)
+	// Storage: Subspace LastUpdate (r:1 w:0)
+	// Storage: Subspace WeightsSetRateLimit (r:1 w:0)
+	// Storage: Subspace WeightsRevealWindow (r:1 w:0)
+	// Storage: Subspace WeightCommitExpiries (r:1 w:1)
+	// Storage: Subspace WeightCommits (r:1 w:1)
+	fn commit_weights() -> Weight {
+		Weight::from_ref_time(24_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: Subspace WeightCommitExpiries (r:1 w:1)
+	// Storage: Subspace WeightsRevealWindow (r:1 w:0)
+	// Storage: Subspace WeightCommits (r:k w:k)
+	fn expire_weight_commits(k: u32, ) -> Weight {
+		Weight::from_ref_time(3_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(9_000_000 as u64).saturating_mul(k as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(k as u64)))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(k as u64)))
+	}
+	// Storage: Subspace WeightCommits (r:1 w:1)
+	// Storage: Subspace Weights (r:1 w:1)
+	// Storage: Subspace LastUpdate (r:1 w:1)
+	fn reveal_weights(w: u32, ) -> Weight {
+		Weight::from_ref_time(26_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_473_000 as u64).saturating_mul(w as u64))
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(w as u64)))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: Subspace Stake (r:1 w:1)
+	// Storage: Subspace TotalStake (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn add_stake() -> Weight {
+		Weight::from_ref_time(65_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	// Storage: Subspace Stake (r:1 w:1)
+	// Storage: Subspace TotalStake (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn remove_stake() -> Weight {
+		Weight::from_ref_time(66_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	// Storage: Subspace Uids (r:1 w:0)
+	// Storage: Subspace AllowPrivateIps (r:1 w:0)
+	// Storage: Subspace Modules (r:1 w:1)
+	// Storage: Subspace ModuleTags (r:1 w:1)
+	// Storage: Subspace TagIndex (r:0 w:2g)
+	fn serve_module(e: u32, g: u32, ) -> Weight {
+		Weight::from_ref_time(19_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(1_200_000 as u64).saturating_mul(e as u64))
+			.saturating_add(Weight::from_ref_time(900_000 as u64).saturating_mul(g as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(g as u64)))
+	}
+	// Storage: Subspace N (r:1 w:1)
+	// Storage: Subspace Keys (r:1 w:1)
+	// Storage: Subspace Uids (r:1 w:1)
+	fn register() -> Weight {
+		Weight::from_ref_time(91_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(27 as u64))
+			.saturating_add(T::DbWeight::get().writes(22 as u64))
+	}
+	// Storage: Subspace ServingRateLimit (r:0 w:1)
+	fn sudo_set_serving_rate_limit() -> Weight {
+		Weight::from_ref_time(10_000_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace AllowPrivateIps (r:0 w:1)
+	fn sudo_set_allow_private_ips() -> Weight {
+		Weight::from_ref_time(13_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace TxRateLimit (r:0 w:1)
+	fn sudo_set_tx_rate_limit() -> Weight {
+		Weight::from_ref_time(10_000_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace TreasuryCut (r:0 w:1)
+	fn sudo_set_treasury_cut() -> Weight {
+		Weight::from_ref_time(10_000_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace WeightsSetRateLimit (r:0 w:1)
+	fn sudo_set_weights_set_rate_limit() -> Weight {
+		Weight::from_ref_time(15_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace WeightsVersionKey (r:0 w:1)
+	fn sudo_set_weights_version_key() -> Weight {
+		Weight::from_ref_time(14_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace PendingHyperparameterChanges (r:1 w:1)
+	fn sudo_set_weights_decay_half_life() -> Weight {
+		Weight::from_ref_time(16_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace InactivePruneWindow (r:0 w:1)
+	fn sudo_set_inactive_prune_window() -> Weight {
+		Weight::from_ref_time(14_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace InactivePruneMinStake (r:0 w:1)
+	fn sudo_set_inactive_prune_min_stake() -> Weight {
+		Weight::from_ref_time(14_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace PendingHyperparameterChanges (r:1 w:1)
+	fn sudo_set_bonds_moving_average() -> Weight {
+		Weight::from_ref_time(16_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace MaxAllowedUids (r:1 w:0)
+	// Storage: Subspace PendingHyperparameterChanges (r:1 w:1)
+	fn sudo_set_max_allowed_validators() -> Weight {
+		Weight::from_ref_time(16_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	fn sudo_set_difficulty() -> Weight {
+		Weight::from_ref_time(13_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace AdjustmentInterval (r:0 w:1)
+	fn sudo_set_adjustment_interval() -> Weight {
+		Weight::from_ref_time(14_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace TargetRegistrationsPerInterval (r:0 w:1)
+	fn sudo_set_target_registrations_per_interval() -> Weight {
+		Weight::from_ref_time(14_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace PendingHyperparameterChanges (r:1 w:1)
+	fn sudo_set_activity_cutoff() -> Weight {
+		Weight::from_ref_time(15_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace N (r:1 w:0)
+	// Storage: Subspace MaxAllowedValidators (r:1 w:0)
+	// Storage: Subspace PendingHyperparameterChanges (r:1 w:1)
+	fn sudo_set_max_allowed_uids() -> Weight {
+		Weight::from_ref_time(20_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace PendingHyperparameterChanges (r:1 w:1)
+	fn sudo_set_min_allowed_weights() -> Weight {
+		Weight::from_ref_time(15_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace ImmunityPeriod (r:0 w:1)
+	fn sudo_set_immunity_period() -> Weight {
+		Weight::from_ref_time(13_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace PendingHyperparameterChanges (r:1 w:1)
+	fn sudo_set_max_weight_limit() -> Weight {
+		Weight::from_ref_time(15_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace MaxRegistrationsPerBlock (r:0 w:1)
+	fn sudo_set_max_registrations_per_block() -> Weight {
+		Weight::from_ref_time(15_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace PendingHyperparameterChanges (r:1 w:1)
+	fn sudo_set_rho() -> Weight {
+		Weight::from_ref_time(16_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace PendingHyperparameterChanges (r:1 w:1)
+	fn sudo_set_kappa() -> Weight {
+		Weight::from_ref_time(16_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace PendingHyperparameterChanges (r:1 w:1)
+	fn sudo_set_incentive_ratio() -> Weight {
+		Weight::from_ref_time(16_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace PendingHyperparameterChanges (r:1 w:1)
+	fn sudo_set_consensus_mechanism() -> Weight {
+		Weight::from_ref_time(16_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace PendingHyperparameterChanges (r:1 w:1)
+	fn sudo_schedule_hyperparameter_change() -> Weight {
+		Weight::from_ref_time(15_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace PendingHyperparameterChanges (r:1 w:1)
+	fn sudo_cancel_hyperparameter_change() -> Weight {
+		Weight::from_ref_time(15_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace CommitRevealEnabled (r:0 w:1)
+	fn sudo_set_commit_reveal_enabled() -> Weight {
+		Weight::from_ref_time(13_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace WeightsRevealWindow (r:0 w:1)
+	fn sudo_set_weights_reveal_window() -> Weight {
+		Weight::from_ref_time(13_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace TreasuryCut (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	fn pay_treasury() -> Weight {
+		Weight::from_ref_time(20_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace LastAdjustmentBlock (r:1 w:0)
+	// Storage: Subspace PendingEmission (r:1 w:1)
+	// Storage: Subspace BlocksSinceLastStep (r:1 w:1)
+	// Storage: Subspace PendingHyperparameterChanges (r:1 w:0)
+	// Storage: Subspace EpochInProgress (r:1 w:0)
+	fn block_step_network() -> Weight {
+		Weight::from_ref_time(15_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(12 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	// Storage: Subspace PendingHyperparameterChanges (r:1 w:1)
+	// Storage: Subspace MinAllowedWeights (r:q w:0)
+	// Storage: Subspace MaxAllowedUids (r:q w:q)
+	fn apply_hyperparameter_changes(q: u32, ) -> Weight {
+		Weight::from_ref_time(4_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(6_000_000 as u64).saturating_mul(q as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().reads((3 as u64).saturating_mul(q as u64)))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(q as u64)))
+	}
+	// Storage: Subspace LoadedEmission (r:1 w:1)
+	// Storage: Subspace Stake (r:1 w:1)
+	// Storage: Subspace TotalStake (r:1 w:1)
+	// Storage: Subspace LifetimeEmission (r:1 w:1)
+	// Storage: Subspace EmissionHistory (r:1 w:1)
+	fn drain_emission(t: u32, ) -> Weight {
+		Weight::from_ref_time(3_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(41_000_000 as u64).saturating_mul(t as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().reads((4 as u64).saturating_mul(t as u64)))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes((4 as u64).saturating_mul(t as u64)))
+	}
+	// Storage: Subspace Keys (r:2 w:2)
+	// Storage: Subspace Uids (r:0 w:2)
+	// Storage: Subspace Weights (r:n w:n)
+	// Storage: Subspace Bonds (r:n w:n)
+	// Storage: Subspace ModulesToPruneAtNextEpoch (r:1 w:1)
+	fn remove_module(n: u32, ) -> Weight {
+		Weight::from_ref_time(60_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(4_000_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(19 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(21 as u64))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: Subspace Keys (r:n w:0)
+	// Storage: Subspace Stake (r:n w:0)
+	// Storage: Subspace EpochInProgress (r:0 w:1)
+	fn start_epoch(n: u32, ) -> Weight {
+		Weight::from_ref_time(5_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(200_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace Weights (r:r w:0)
+	// Storage: Subspace KeyWeights (r:r w:0)
+	// Storage: Subspace Bonds (r:r w:0)
+	// Storage: Subspace EpochWeights (r:0 w:r)
+	// Storage: Subspace EpochBonds (r:0 w:r)
+	// Storage: Subspace EpochColumns (r:w w:w)
+	fn epoch_load(r: u32, w: u32, ) -> Weight {
+		Weight::from_ref_time(2_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(1_000_000 as u64).saturating_mul(r as u64))
+			.saturating_add(Weight::from_ref_time(60_000 as u64).saturating_mul(w as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().reads((4 as u64).saturating_mul(r as u64)))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(r as u64)))
+			.saturating_add(T::DbWeight::get().reads_writes(w as u64, w as u64))
+	}
+	// Storage: Subspace EpochColumns (r:c w:c)
+	fn epoch_consensus(c: u32, w: u32, ) -> Weight {
+		Weight::from_ref_time(1_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(500_000 as u64).saturating_mul(c as u64))
+			.saturating_add(Weight::from_ref_time(15_000 as u64).saturating_mul(w as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(c as u64)))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(c as u64)))
+	}
+	// Storage: Subspace EpochWeights (r:r w:0)
+	fn epoch_rank(r: u32, w: u32, ) -> Weight {
+		Weight::from_ref_time(1_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(400_000 as u64).saturating_mul(r as u64))
+			.saturating_add(Weight::from_ref_time(10_000 as u64).saturating_mul(w as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(r as u64)))
+	}
+	// Storage: Subspace EpochWeights (r:r w:0)
+	// Storage: Subspace EpochBonds (r:r w:r)
+	fn epoch_ema(r: u32, w: u32, ) -> Weight {
+		Weight::from_ref_time(1_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(600_000 as u64).saturating_mul(r as u64))
+			.saturating_add(Weight::from_ref_time(25_000 as u64).saturating_mul(w as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(r as u64)))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(r as u64)))
+	}
+	// Storage: Subspace EpochBonds (r:r w:r)
+	// Storage: Subspace EpochWeights (r:0 w:r)
+	// Storage: Subspace Bonds (r:r w:r)
+	fn epoch_bonds(r: u32, w: u32, ) -> Weight {
+		Weight::from_ref_time(1_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(500_000 as u64).saturating_mul(r as u64))
+			.saturating_add(Weight::from_ref_time(20_000 as u64).saturating_mul(w as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(r as u64)))
+			.saturating_add(T::DbWeight::get().writes((3 as u64).saturating_mul(r as u64)))
+	}
+	fn epoch_emission(n: u32, ) -> Weight {
+		Weight::from_ref_time(1_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(100_000 as u64).saturating_mul(n as u64))
+	}
+	// Storage: Subspace Emission (r:1 w:1)
+	// Storage: Subspace Incentive (r:1 w:1)
+	// Storage: Subspace Dividends (r:1 w:1)
+	fn epoch_write_back(n: u32, ) -> Weight {
+		Weight::from_ref_time(5_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(150_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(T::DbWeight::get().writes(9 as u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Subspace Weights (r:1 w:1)
+	// Storage: Subspace LastUpdate (r:1 w:1)
+	// Storage: Subspace Uids (r:1 w:0)
+	// Storage: Subspace WeightsVersionKey (r:1 w:0)
+	fn set_weights(w: u32, ) -> Weight {
+		Weight::from_ref_time(20_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_473_000 as u64).saturating_mul(w as u64))
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(w as u64)))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: Subspace Weights (r:1 w:1)
+	// Storage: Subspace KeyWeights (r:1 w:1)
+	// Storage: Subspace LastUpdate (r:1 w:1)
+	// Storage: Subspace Uids (r:1 w:0)
+	fn set_weights_by_key(w: u32, ) -> Weight {
+		Weight::from_ref_time(24_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_473_000 as u64).saturating_mul(w as u64))
+			.saturating_add(RocksDbWeight::get().reads(16 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(w as u64)))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: Subspace Weights (r:1 w:1)
+	// Storage: Subspace LastUpdate (r:1 w:1)
+	// Storage: Subspace Uids (r:1 w:0)
+	// Storage: Subspace KeyWeights (r:1 w:1)
+	fn patch_weights(w: u32, ) -> Weight {
+		Weight::from_ref_time(22_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_473_000 as u64).saturating_mul(w as u64))
+			.saturating_add(RocksDbWeight::get().reads(10 as u64))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(w as u64)))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: Subspace CommitRevealEnabled (r:1 w:0)
+	// Storage: Subspace Uids (r:1 w:0)
+	// Storage: Subspace LastUpdate (r:1 w:0)
+	// Storage: Subspace WeightsSetRateLimit (r:1 w:0)
+	// Storage: Subspace WeightsRevealWindow (r:1 w:0)
+	// Storage: Subspace WeightCommitExpiries (r:1 w:1)
+	// Storage: Subspace WeightCommits (r:1 w:1)
+	fn commit_weights() -> Weight {
+		Weight::from_ref_time(24_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: Subspace WeightCommitExpiries (r:1 w:1)
+	// Storage: Subspace WeightsRevealWindow (r:1 w:0)
+	// Storage: Subspace WeightCommits (r:k w:k)
+	fn expire_weight_commits(k: u32, ) -> Weight {
+		Weight::from_ref_time(3_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(9_000_000 as u64).saturating_mul(k as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(k as u64)))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(k as u64)))
+	}
+	// Storage: Subspace WeightCommits (r:1 w:1)
+	// Storage: Subspace Weights (r:1 w:1)
+	// Storage: Subspace LastUpdate (r:1 w:1)
+	fn reveal_weights(w: u32, ) -> Weight {
+		Weight::from_ref_time(26_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_473_000 as u64).saturating_mul(w as u64))
+			.saturating_add(RocksDbWeight::get().reads(10 as u64))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(w as u64)))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: Subspace Stake (r:1 w:1)
+	// Storage: Subspace TotalStake (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn add_stake() -> Weight {
+		Weight::from_ref_time(65_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+	}
+	// Storage: Subspace Stake (r:1 w:1)
+	// Storage: Subspace TotalStake (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn remove_stake() -> Weight {
+		Weight::from_ref_time(66_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+	}
+	// Storage: Subspace Modules (r:1 w:1)
+	// Storage: Subspace ModuleTags (r:1 w:1)
+	// Storage: Subspace TagIndex (r:0 w:2g)
+	fn serve_module(e: u32, g: u32, ) -> Weight {
+		Weight::from_ref_time(19_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(1_200_000 as u64).saturating_mul(e as u64))
+			.saturating_add(Weight::from_ref_time(900_000 as u64).saturating_mul(g as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(g as u64)))
+	}
+	// Storage: Subspace N (r:1 w:1)
+	// Storage: Subspace Keys (r:1 w:1)
+	// Storage: Subspace Uids (r:1 w:1)
+	fn register() -> Weight {
+		Weight::from_ref_time(91_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(27 as u64))
+			.saturating_add(RocksDbWeight::get().writes(22 as u64))
+	}
+	// Storage: Subspace ServingRateLimit (r:0 w:1)
+	fn sudo_set_serving_rate_limit() -> Weight {
+		Weight::from_ref_time(10_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace AllowPrivateIps (r:0 w:1)
+	fn sudo_set_allow_private_ips() -> Weight {
+		Weight::from_ref_time(13_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace TxRateLimit (r:0 w:1)
+	fn sudo_set_tx_rate_limit() -> Weight {
+		Weight::from_ref_time(10_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace TreasuryCut (r:0 w:1)
+	fn sudo_set_treasury_cut() -> Weight {
+		Weight::from_ref_time(10_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace WeightsSetRateLimit (r:0 w:1)
+	fn sudo_set_weights_set_rate_limit() -> Weight {
+		Weight::from_ref_time(15_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace WeightsVersionKey (r:0 w:1)
+	fn sudo_set_weights_version_key() -> Weight {
+		Weight::from_ref_time(14_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace PendingHyperparameterChanges (r:1 w:1)
+	fn sudo_set_weights_decay_half_life() -> Weight {
+		Weight::from_ref_time(16_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace InactivePruneWindow (r:0 w:1)
+	fn sudo_set_inactive_prune_window() -> Weight {
+		Weight::from_ref_time(14_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace InactivePruneMinStake (r:0 w:1)
+	fn sudo_set_inactive_prune_min_stake() -> Weight {
+		Weight::from_ref_time(14_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace PendingHyperparameterChanges (r:1 w:1)
+	fn sudo_set_bonds_moving_average() -> Weight {
+		Weight::from_ref_time(16_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace MaxAllowedUids (r:1 w:0)
+	// Storage: Subspace PendingHyperparameterChanges (r:1 w:1)
+	fn sudo_set_max_allowed_validators() -> Weight {
+		Weight::from_ref_time(16_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	fn sudo_set_difficulty() -> Weight {
+		Weight::from_ref_time(13_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace AdjustmentInterval (r:0 w:1)
+	fn sudo_set_adjustment_interval() -> Weight {
+		Weight::from_ref_time(14_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace TargetRegistrationsPerInterval (r:0 w:1)
+	fn sudo_set_target_registrations_per_interval() -> Weight {
+		Weight::from_ref_time(14_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace PendingHyperparameterChanges (r:1 w:1)
+	fn sudo_set_activity_cutoff() -> Weight {
+		Weight::from_ref_time(15_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace N (r:1 w:0)
+	// Storage: Subspace MaxAllowedValidators (r:1 w:0)
+	// Storage: Subspace PendingHyperparameterChanges (r:1 w:1)
+	fn sudo_set_max_allowed_uids() -> Weight {
+		Weight::from_ref_time(20_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace PendingHyperparameterChanges (r:1 w:1)
+	fn sudo_set_min_allowed_weights() -> Weight {
+		Weight::from_ref_time(15_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace ImmunityPeriod (r:0 w:1)
+	fn sudo_set_immunity_period() -> Weight {
+		Weight::from_ref_time(13_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace PendingHyperparameterChanges (r:1 w:1)
+	fn sudo_set_max_weight_limit() -> Weight {
+		Weight::from_ref_time(15_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace MaxRegistrationsPerBlock (r:0 w:1)
+	fn sudo_set_max_registrations_per_block() -> Weight {
+		Weight::from_ref_time(15_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace PendingHyperparameterChanges (r:1 w:1)
+	fn sudo_set_rho() -> Weight {
+		Weight::from_ref_time(16_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace PendingHyperparameterChanges (r:1 w:1)
+	fn sudo_set_kappa() -> Weight {
+		Weight::from_ref_time(16_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace PendingHyperparameterChanges (r:1 w:1)
+	fn sudo_set_incentive_ratio() -> Weight {
+		Weight::from_ref_time(16_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace PendingHyperparameterChanges (r:1 w:1)
+	fn sudo_set_consensus_mechanism() -> Weight {
+		Weight::from_ref_time(16_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace PendingHyperparameterChanges (r:1 w:1)
+	fn sudo_schedule_hyperparameter_change() -> Weight {
+		Weight::from_ref_time(15_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace PendingHyperparameterChanges (r:1 w:1)
+	fn sudo_cancel_hyperparameter_change() -> Weight {
+		Weight::from_ref_time(15_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace CommitRevealEnabled (r:0 w:1)
+	fn sudo_set_commit_reveal_enabled() -> Weight {
+		Weight::from_ref_time(13_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace NetworksAdded (r:1 w:0)
+	// Storage: Subspace WeightsRevealWindow (r:0 w:1)
+	fn sudo_set_weights_reveal_window() -> Weight {
+		Weight::from_ref_time(13_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace TreasuryCut (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	fn pay_treasury() -> Weight {
+		Weight::from_ref_time(20_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace LastAdjustmentBlock (r:1 w:0)
+	// Storage: Subspace PendingEmission (r:1 w:1)
+	// Storage: Subspace BlocksSinceLastStep (r:1 w:1)
+	// Storage: Subspace PendingHyperparameterChanges (r:1 w:0)
+	// Storage: Subspace EpochInProgress (r:1 w:0)
+	fn block_step_network() -> Weight {
+		Weight::from_ref_time(15_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(12 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+	}
+	// Storage: Subspace PendingHyperparameterChanges (r:1 w:1)
+	// Storage: Subspace MinAllowedWeights (r:q w:0)
+	// Storage: Subspace MaxAllowedUids (r:q w:q)
+	fn apply_hyperparameter_changes(q: u32, ) -> Weight {
+		Weight::from_ref_time(4_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(6_000_000 as u64).saturating_mul(q as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+			.saturating_add(RocksDbWeight::get().reads((3 as u64).saturating_mul(q as u64)))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(q as u64)))
+	}
+	// Storage: Subspace LoadedEmission (r:1 w:1)
+	// Storage: Subspace Stake (r:1 w:1)
+	// Storage: Subspace TotalStake (r:1 w:1)
+	// Storage: Subspace LifetimeEmission (r:1 w:1)
+	// Storage: Subspace EmissionHistory (r:1 w:1)
+	fn drain_emission(t: u32, ) -> Weight {
+		Weight::from_ref_time(3_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(41_000_000 as u64).saturating_mul(t as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().reads((4 as u64).saturating_mul(t as u64)))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes((4 as u64).saturating_mul(t as u64)))
+	}
+	// Storage: Subspace Keys (r:2 w:2)
+	// Storage: Subspace Uids (r:0 w:2)
+	// Storage: Subspace Weights (r:n w:n)
+	// Storage: Subspace Bonds (r:n w:n)
+	// Storage: Subspace ModulesToPruneAtNextEpoch (r:1 w:1)
+	fn remove_module(n: u32, ) -> Weight {
+		Weight::from_ref_time(60_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(4_000_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(19 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(21 as u64))
+			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: Subspace Keys (r:n w:0)
+	// Storage: Subspace Stake (r:n w:0)
+	// Storage: Subspace EpochInProgress (r:0 w:1)
+	fn start_epoch(n: u32, ) -> Weight {
+		Weight::from_ref_time(5_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(200_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(10 as u64))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Subspace Weights (r:r w:0)
+	// Storage: Subspace KeyWeights (r:r w:0)
+	// Storage: Subspace Bonds (r:r w:0)
+	// Storage: Subspace EpochWeights (r:0 w:r)
+	// Storage: Subspace EpochBonds (r:0 w:r)
+	// Storage: Subspace EpochColumns (r:w w:w)
+	fn epoch_load(r: u32, w: u32, ) -> Weight {
+		Weight::from_ref_time(2_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(1_000_000 as u64).saturating_mul(r as u64))
+			.saturating_add(Weight::from_ref_time(60_000 as u64).saturating_mul(w as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().reads((4 as u64).saturating_mul(r as u64)))
+			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(r as u64)))
+			.saturating_add(RocksDbWeight::get().reads_writes(w as u64, w as u64))
+	}
+	// Storage: Subspace EpochColumns (r:c w:c)
+	fn epoch_consensus(c: u32, w: u32, ) -> Weight {
+		Weight::from_ref_time(1_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(500_000 as u64).saturating_mul(c as u64))
+			.saturating_add(Weight::from_ref_time(15_000 as u64).saturating_mul(w as u64))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(c as u64)))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(c as u64)))
+	}
+	// Storage: Subspace EpochWeights (r:r w:0)
+	fn epoch_rank(r: u32, w: u32, ) -> Weight {
+		Weight::from_ref_time(1_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(400_000 as u64).saturating_mul(r as u64))
+			.saturating_add(Weight::from_ref_time(10_000 as u64).saturating_mul(w as u64))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(r as u64)))
+	}
+	// Storage: Subspace EpochWeights (r:r w:0)
+	// Storage: Subspace EpochBonds (r:r w:r)
+	fn epoch_ema(r: u32, w: u32, ) -> Weight {
+		Weight::from_ref_time(1_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(600_000 as u64).saturating_mul(r as u64))
+			.saturating_add(Weight::from_ref_time(25_000 as u64).saturating_mul(w as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(r as u64)))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(r as u64)))
+	}
+	// Storage: Subspace EpochBonds (r:r w:r)
+	// Storage: Subspace EpochWeights (r:0 w:r)
+	// Storage: Subspace Bonds (r:r w:r)
+	fn epoch_bonds(r: u32, w: u32, ) -> Weight {
+		Weight::from_ref_time(1_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(500_000 as u64).saturating_mul(r as u64))
+			.saturating_add(Weight::from_ref_time(20_000 as u64).saturating_mul(w as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(r as u64)))
+			.saturating_add(RocksDbWeight::get().writes((3 as u64).saturating_mul(r as u64)))
+	}
+	fn epoch_emission(n: u32, ) -> Weight {
+		Weight::from_ref_time(1_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(100_000 as u64).saturating_mul(n as u64))
+	}
+	// Storage: Subspace Emission (r:1 w:1)
+	// Storage: Subspace Incentive (r:1 w:1)
+	// Storage: Subspace Dividends (r:1 w:1)
+	fn epoch_write_back(n: u32, ) -> Weight {
+		Weight::from_ref_time(5_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(150_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(9 as u64))
+			.saturating_add(RocksDbWeight::get().writes(9 as u64))
+	}
+}
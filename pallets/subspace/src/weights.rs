@@ -1,6 +1,10 @@
 use super::*;
 use frame_support::sp_std::vec;
 use sp_std::vec::Vec;
+use sp_core::H256;
+use sp_io::hashing::blake2_256;
+use frame_support::weights::Weight;
+use frame_support::pallet_prelude::DispatchError;
 
 impl<T: Config> Pallet<T> {
 
@@ -20,6 +24,9 @@ impl<T: Config> Pallet<T> {
     // 	* 'values' ( Vec<u16> ):
     // 		- The values of the weights to set on the chain.
     //
+    // 	* 'version_key' ( u64 ):
+    // 		- The network version key of the validator software setting the weights.
+    //
     // # Event:
     // 	* WeightsSet;
     // 		- On successfully setting the weights on chain.
@@ -31,7 +38,12 @@ impl<T: Config> Pallet<T> {
     // 	* 'NotRegistered':
     // 		- Attempting to set weights from a non registered account.
     //
-
+    // 	* 'CommitRevealRequired':
+    // 		- The network only accepts weights through commit_weights and reveal_weights.
+    //
+    // 	* 'IncorrectNetworkVersionKey':
+    // 		- The version key is below the one required by the network.
+    //
     // 	* 'SettingWeightsTooFast':
     // 		- Attempting to set weights faster than the weights_set_rate_limit.
     //
@@ -53,68 +65,482 @@ impl<T: Config> Pallet<T> {
     // 	* 'MaxWeightExceeded':
     // 		- Attempting to set weights with max value exceeding limit.
     //
-    pub fn do_set_weights( origin: T::RuntimeOrigin, netuid: u16, uids: Vec<u16>, values: Vec<u16> ) -> dispatch::DispatchResult{
+    pub fn do_set_weights( origin: T::RuntimeOrigin, netuid: u16, uids: Vec<u16>, values: Vec<u16>, version_key: u64 ) -> dispatch::DispatchResult{
 
         // --- 1. Check the caller's signature. This is the key of a registered account.
         let key = ensure_signed( origin )?;
-        log::info!("do_set_weights( origin:{:?} netuid:{:?}, uids:{:?}, values:{:?})", key, netuid, uids, values );
-        // --- 2. Check to see if this is a valid network.
+        log::info!("do_set_weights( origin:{:?} netuid:{:?}, uids:{:?}, values:{:?}, version_key:{:?})", key, netuid, uids, values, version_key );
+
+        // --- 2. Networks running commit-reveal only accept weights through reveal_weights.
+        ensure!( !Self::is_commit_reveal_enabled( netuid ), Error::<T>::CommitRevealRequired );
+
+        // --- 3. Ensure the validator runs at least the version required by the network.
+        ensure!( Self::check_version_key( netuid, version_key ), Error::<T>::IncorrectNetworkVersionKey );
+
+        // --- 4. Check the weights against the network requirements.
+        let current_block: u64 = Self::get_current_block_as_u64();
+        let ( module_uid, zipped_weights ) = Self::validate_weights( netuid, &key, uids, values, current_block )?;
+
+        // --- 5. Sink the weights to storage.
+        Self::store_weights( netuid, module_uid, zipped_weights, current_block );
+
+        // --- 6. Return ok.
+        Ok(())
+    }
+
+    // Runs every check required to set weights for key on the network and returns the module uid along with
+    // the normalized (uid, weight) pairs ready to be written.
+    //
+    pub fn validate_weights( netuid: u16, key: &T::AccountId, uids: Vec<u16>, values: Vec<u16>, current_block: u64 ) -> Result<(u16, Vec<(u16, u16)>), DispatchError> {
+
+        // --- 1. Check to see if this is a valid network.
         ensure!( Self::if_network_exist( netuid ), Error::<T>::NetworkDoesNotExist );
 
-        // --- 3. Check to see if the key is registered to the passed network.
-        ensure!( Self::is_key_registered_on_network( netuid, &key ), Error::<T>::NotRegistered );
+        // --- 2. Check to see if the key is registered to the passed network.
+        ensure!( Self::is_key_registered_on_network( netuid, key ), Error::<T>::NotRegistered );
 
-        // --- 4. Check that the length of uid list and value list are equal for this network.
+        // --- 3. Check that the length of uid list and value list are equal for this network.
         ensure!( Self::uids_match_values( &uids, &values ), Error::<T>::WeightVecNotEqualSize );
 
-        // --- 5. Check to see if the number of uids is within the max allowed uids for this network.
+        // --- 4. Check to see if the number of uids is within the max allowed uids for this network.
         ensure!( Self::check_len_uids_within_allowed( netuid, &uids ), Error::<T>::TooManyUids);
 
-        // --- 6. Get the module uid of associated key on network netuid.
-        let module_uid;
-        match Self::get_uid_for_net_and_key( netuid, &key ) { Ok(k) => module_uid = k, Err(e) => panic!("Error: {:?}", e) } 
+        // --- 5. Get the module uid of associated key on network netuid.
+        let module_uid: u16 = Self::get_uid_for_net_and_key( netuid, key )?;
 
-        // --- 8. Ensure the uid is not setting weights faster than the weights_set_rate_limit.
-        let current_block: u64 = Self::get_current_block_as_u64();
+        // --- 6. Ensure the uid is not setting weights faster than the weights_set_rate_limit.
         ensure!( Self::check_rate_limit( netuid, module_uid, current_block ), Error::<T>::SettingWeightsTooFast );
 
-        // --- 10. Ensure the passed uids contain no duplicates.
+        // --- 7. Ensure the passed uids contain no duplicates.
         ensure!( !Self::has_duplicate_uids( &uids ), Error::<T>::DuplicateUids );
 
-        // --- 11. Ensure that the passed uids are valid for the network.
+        // --- 8. Ensure that the passed uids are valid for the network.
         ensure!( !Self::contains_invalid_uids( netuid, &uids ), Error::<T>::InvalidUid );
 
-        // --- 12. Ensure that the weights have the required length.
+        // --- 9. Ensure that the weights have the required length.
         ensure!( Self::check_length( netuid, module_uid, &uids, &values ), Error::<T>::NotSettingEnoughWeights );
 
-        // --- 13. Normalize the weights.
+        // --- 10. Normalize the weights.
         let normalized_values = Self::normalize_weights( values );
 
-        // --- 14. Ensure the weights are max weight limited 
+        // --- 11. Ensure the weights are max weight limited 
         ensure!( Self::max_weight_limited( netuid, module_uid, &uids, &normalized_values ), Error::<T>::MaxWeightExceeded );
 
-        // --- 15. Zip weights for sinking to storage map.
+        // --- 12. Zip weights for sinking to storage map.
         let mut zipped_weights: Vec<( u16, u16 )> = vec![];
         for ( uid, val ) in uids.iter().zip(normalized_values.iter()) { zipped_weights.push((*uid, *val)) }
 
-        // --- 16. Set weights under netuid, uid double map entry.
-        Weights::<T>::insert( netuid, module_uid, zipped_weights );
+        Ok(( module_uid, zipped_weights ))
+    }
+
+    // ---- The implementation for the extrinsic set_weights_by_key.
+    //
+    // # Args:
+    // 	* 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    // 		- The signature of the calling key.
+    //
+    // 	* 'netuid' (u16):
+    // 		- The u16 network identifier.
+    //
+    // 	* 'dest_keys' ( Vec<T::AccountId> ):
+    // 		- The keys of the modules receiving the weights.
+    //
+    // 	* 'values' ( Vec<u16> ):
+    // 		- The values of the weights to set on the chain.
+    //
+    // 	* 'version_key' ( u64 ):
+    // 		- The network version key of the validator software setting the weights.
+    //
+    // # Event:
+    // 	* WeightsSet;
+    // 		- On successfully setting the weights on chain.
+    //
+    // # Raises:
+    // 	* 'InvalidTargetKey':
+    // 		- One of the keys is not registered on the network.
+    //
+    // 	* Any of the errors raised by set_weights.
+    //
+    pub fn do_set_weights_by_key( origin: T::RuntimeOrigin, netuid: u16, dest_keys: Vec<T::AccountId>, values: Vec<u16>, version_key: u64 ) -> dispatch::DispatchResult {
+
+        // --- 1. Check the caller's signature. This is the key of a registered account.
+        let key = ensure_signed( origin )?;
+        log::info!("do_set_weights_by_key( origin:{:?} netuid:{:?}, dest_keys:{:?}, values:{:?}, version_key:{:?})", key, netuid, dest_keys, values, version_key );
+
+        // --- 2. Networks running commit-reveal only accept weights through reveal_weights.
+        ensure!( !Self::is_commit_reveal_enabled( netuid ), Error::<T>::CommitRevealRequired );
+
+        // --- 3. Ensure the validator runs at least the version required by the network.
+        ensure!( Self::check_version_key( netuid, version_key ), Error::<T>::IncorrectNetworkVersionKey );
+
+        // --- 4. Resolve the target keys to their current uids.
+        let mut uids: Vec<u16> = vec![];
+        for dest_key in dest_keys.iter() {
+            uids.push( Uids::<T>::get( netuid, dest_key ).ok_or( Error::<T>::InvalidTargetKey )? );
+        }
+
+        // --- 5. Check the weights against the network requirements.
+        let current_block: u64 = Self::get_current_block_as_u64();
+        let ( module_uid, zipped_weights ) = Self::validate_weights( netuid, &key, uids, values, current_block )?;
+
+        // --- 6. Sink the resolved weights, then keep the key addressed row for the epoch.
+        let key_weights: Vec<(T::AccountId, u16)> = dest_keys.into_iter().zip( zipped_weights.iter().map( |(_, value)| *value ) ).collect();
+        Self::store_weights( netuid, module_uid, zipped_weights, current_block );
+        KeyWeights::<T>::insert( netuid, &key, Self::to_uid_vec( key_weights ) );
+
+        // --- 7. Return ok.
+        Ok(())
+    }
+
+    // ---- The implementation for the extrinsic patch_weights.
+    //
+    // # Args:
+    // 	* 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    // 		- The signature of the calling key.
+    //
+    // 	* 'netuid' (u16):
+    // 		- The u16 network identifier.
+    //
+    // 	* 'upserts' ( Vec<(u16, u16)> ):
+    // 		- The ( uid, value ) pairs inserted or overwritten in the current row. Values are on the same
+    // 		u16 scale as the stored row, which sums to u16::MAX.
+    //
+    // 	* 'removals' ( Vec<u16> ):
+    // 		- The uids removed from the current row.
+    //
+    // 	* 'version_key' ( u64 ):
+    // 		- The network version key of the validator software setting the weights.
+    //
+    // # Event:
+    // 	* WeightsSet;
+    // 		- On successfully setting the merged weights on chain.
+    //
+    // # Raises:
+    // 	* 'NetworkDoesNotExist':
+    // 		- Attempting to patch weights on a non-existent network.
+    //
+    // 	* 'DuplicateUids':
+    // 		- A uid appears twice across the upserts and removals.
+    //
+    // 	* 'EmptyWeights':
+    // 		- The removals leave no weight in the row.
+    //
+    // 	* 'WeightsSetByKey':
+    // 		- The current row was set by target key, patching it by uid would lose the key addressing.
+    //
+    // 	* Any of the errors raised by set_weights, checked against the merged row.
+    //
+    pub fn do_patch_weights( origin: T::RuntimeOrigin, netuid: u16, upserts: Vec<(u16, u16)>, removals: Vec<u16>, version_key: u64 ) -> dispatch::DispatchResult {
+
+        // --- 1. Check the caller's signature. This is the key of a registered account.
+        let key = ensure_signed( origin )?;
+        log::info!("do_patch_weights( origin:{:?} netuid:{:?}, upserts:{:?}, removals:{:?}, version_key:{:?})", key, netuid, upserts, removals, version_key );
+
+        // --- 2. Networks running commit-reveal only accept weights through reveal_weights.
+        ensure!( !Self::is_commit_reveal_enabled( netuid ), Error::<T>::CommitRevealRequired );
+
+        // --- 3. Ensure the validator runs at least the version required by the network.
+        ensure!( Self::check_version_key( netuid, version_key ), Error::<T>::IncorrectNetworkVersionKey );
+
+        // --- 4. Ensure each uid is touched at most once by the patch.
+        let mut touched: Vec<u16> = upserts.iter().map( |(uid, _)| *uid ).collect();
+        touched.extend( removals.iter() );
+        ensure!( !Self::has_duplicate_uids( &touched ), Error::<T>::DuplicateUids );
 
-        // --- 17. Set the activity for the weights on this network.
+        // --- 5. Merge the patch into the current row, which must be addressed by uid.
+        ensure!( Self::if_network_exist( netuid ), Error::<T>::NetworkDoesNotExist );
+        let module_uid: u16 = Self::get_uid_for_net_and_key( netuid, &key )?;
+        ensure!( !KeyWeights::<T>::contains_key( netuid, &key ), Error::<T>::WeightsSetByKey );
+        let mut row: Vec<(u16, u16)> = Weights::<T>::get( netuid, module_uid ).into_inner();
+        row.retain( |(uid, _)| !touched.contains( uid ) );
+        row.extend( upserts.into_iter() );
+        ensure!( !row.is_empty(), Error::<T>::EmptyWeights );
+        let ( uids, values ): ( Vec<u16>, Vec<u16> ) = row.into_iter().unzip();
+
+        // --- 6. Renormalize and check the merged row against the network requirements.
+        let current_block: u64 = Self::get_current_block_as_u64();
+        let ( module_uid, zipped_weights ) = Self::validate_weights( netuid, &key, uids, values, current_block )?;
+
+        // --- 7. Sink the weights to storage.
+        Self::store_weights( netuid, module_uid, zipped_weights, current_block );
+
+        // --- 8. Return ok.
+        Ok(())
+    }
+
+    // Returns the weights row the key set by target key, with every target key resolved to its current uid,
+    // or None if the key set its weights by uid. Targets which are no longer registered on the network are dropped.
+    //
+    pub fn get_key_weights_row( netuid: u16, key: &T::AccountId ) -> Option<Vec<(u16, u16)>> {
+        let key_weights: Vec<(T::AccountId, u16)> = KeyWeights::<T>::get( netuid, key )?.into_inner();
+        Some( key_weights.iter()
+            .filter_map( |( dest_key, value )| Uids::<T>::get( netuid, dest_key ).map( |uid_j| ( uid_j, *value ) ) )
+            .collect() )
+    }
+
+    // Returns the weights row the uid set by uid, ( uid, value ) pairs summing to u16::MAX.
+    //
+    pub fn get_weights_for_uid( netuid: u16, uid: u16 ) -> Vec<(u16, u16)> {
+        Weights::<T>::get( netuid, uid ).into_inner()
+    }
+
+    // Returns true if a weights row is stored for the uid.
+    //
+    pub fn has_weights_for_uid( netuid: u16, uid: u16 ) -> bool {
+        Weights::<T>::contains_key( netuid, uid )
+    }
+
+    // Writes already validated weights for the module, marks it as updated and emits WeightsSet.
+    //
+    pub fn store_weights( netuid: u16, module_uid: u16, zipped_weights: Vec<(u16, u16)>, current_block: u64 ) {
+
+        // --- 1. Set weights under netuid, uid double map entry, replacing any row previously set by key.
+        Weights::<T>::insert( netuid, module_uid, Self::to_uid_vec( zipped_weights ) );
+        KeyWeights::<T>::remove( netuid, Keys::<T>::get( netuid, module_uid ) );
+
+        // --- 2. Set the activity for the weights on this network.
         Self::set_last_update_for_uid( netuid, module_uid, current_block );
 
-        // --- 18. Emit the tracking event.
+        // --- 3. Emit the tracking event.
         log::info!("WeightsSet( netuid:{:?}, module_uid:{:?} )", netuid, module_uid );
         Self::deposit_event( Event::WeightsSet( netuid, module_uid ) );
+    }
+
+    // ---- The implementation for the extrinsic set_weights_multi.
+    //
+    // # Args:
+    // 	* 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    // 		- The signature of the calling key.
+    //
+    // 	* 'network_weights' ( Vec<(u16, Vec<u16>, Vec<u16>)> ):
+    // 		- The ( netuid, uids, values ) weights to set on each network.
+    //
+    // 	* 'version_key' ( u64 ):
+    // 		- The network version key of the validator software setting the weights.
+    //
+    // # Event:
+    // 	* WeightsSet;
+    // 		- One per network on successfully setting all the weights on chain.
+    //
+    // # Raises:
+    // 	* 'DuplicateNetuids':
+    // 		- The same network appears more than once in the batch.
+    //
+    // 	* Any of the errors raised by set_weights. Nothing is written unless every entry is valid.
+    //
+    pub fn do_set_weights_multi( origin: T::RuntimeOrigin, network_weights: Vec<(u16, Vec<u16>, Vec<u16>)>, version_key: u64 ) -> dispatch::DispatchResult {
+
+        // --- 1. Check the caller's signature. This is the key of a registered account.
+        let key = ensure_signed( origin )?;
+        log::info!("do_set_weights_multi( origin:{:?} network_weights:{:?}, version_key:{:?})", key, network_weights, version_key );
+
+        // --- 2. Ensure each network appears only once, the rate limit would not catch a second entry.
+        let netuids: Vec<u16> = network_weights.iter().map( |( netuid, _, _ )| *netuid ).collect();
+        ensure!( !Self::has_duplicate_uids( &netuids ), Error::<T>::DuplicateNetuids );
+
+        // --- 3. Check every entry before writing any, so the batch is applied atomically.
+        let current_block: u64 = Self::get_current_block_as_u64();
+        let mut validated: Vec<(u16, u16, Vec<(u16, u16)>)> = vec![];
+        for ( netuid, uids, values ) in network_weights.into_iter() {
+            ensure!( !Self::is_commit_reveal_enabled( netuid ), Error::<T>::CommitRevealRequired );
+            ensure!( Self::check_version_key( netuid, version_key ), Error::<T>::IncorrectNetworkVersionKey );
+            let ( module_uid, zipped_weights ) = Self::validate_weights( netuid, &key, uids, values, current_block )?;
+            validated.push( ( netuid, module_uid, zipped_weights ) );
+        }
+
+        // --- 4. Sink the weights to storage.
+        for ( netuid, module_uid, zipped_weights ) in validated.into_iter() {
+            Self::store_weights( netuid, module_uid, zipped_weights, current_block );
+        }
+
+        // --- 5. Return ok.
+        Ok(())
+    }
+
+    // ---- The implementation for the extrinsic commit_weights.
+    //
+    // # Args:
+    // 	* 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    // 		- The signature of the calling key.
+    //
+    // 	* 'netuid' (u16):
+    // 		- The u16 network identifier.
+    //
+    // 	* 'commit_hash' ( H256 ):
+    // 		- blake2_256 of the SCALE encoded ( key, netuid, uids, values, salt ) to be revealed later.
+    //
+    // # Event:
+    // 	* WeightsCommitted;
+    // 		- On successfully storing the commitment.
+    //
+    // # Raises:
+    // 	* 'NetworkDoesNotExist':
+    // 		- Attempting to commit weights on a non-existent network.
+    //
+    // 	* 'CommitRevealDisabled':
+    // 		- The network does not run commit-reveal.
+    //
+    // 	* 'NotRegistered':
+    // 		- Attempting to commit weights from a non registered account.
+    //
+    // 	* 'SettingWeightsTooFast':
+    // 		- Attempting to commit weights faster than the weights_set_rate_limit.
+    //
+    pub fn do_commit_weights( origin: T::RuntimeOrigin, netuid: u16, commit_hash: H256 ) -> dispatch::DispatchResult {
+
+        // --- 1. Check the caller's signature.
+        let key = ensure_signed( origin )?;
+        log::info!("do_commit_weights( origin:{:?} netuid:{:?}, commit_hash:{:?} )", key, netuid, commit_hash );
+
+        // --- 2. Check to see if this is a valid network running commit-reveal.
+        ensure!( Self::if_network_exist( netuid ), Error::<T>::NetworkDoesNotExist );
+        ensure!( Self::is_commit_reveal_enabled( netuid ), Error::<T>::CommitRevealDisabled );
+
+        // --- 3. Check to see if the key is registered to the passed network.
+        let module_uid: u16 = Self::get_uid_for_net_and_key( netuid, &key )?;
+
+        // --- 4. Ensure the key is not committing faster than it may set weights.
+        let current_block: u64 = Self::get_current_block_as_u64();
+        ensure!( Self::check_commit_rate_limit( netuid, module_uid, &key, current_block ), Error::<T>::SettingWeightsTooFast );
+
+        // --- 5. Queue the expiry of the commitment after the reveal window.
+        Self::queue_weight_commit_expiry( netuid, &key, current_block.saturating_add( Self::get_weights_reveal_window( netuid ) ).saturating_add( 1 ) )?;
+
+        // --- 6. Store the commitment, replacing any previous unrevealed one.
+        WeightCommits::<T>::insert( netuid, &key, ( commit_hash, current_block ) );
+
+        // --- 7. Emit the tracking event.
+        log::info!("WeightsCommitted( netuid:{:?}, module_uid:{:?} )", netuid, module_uid );
+        Self::deposit_event( Event::WeightsCommitted( netuid, module_uid ) );
+
+        // --- 8. Return ok.
+        Ok(())
+    }
+
+    // ---- The implementation for the extrinsic reveal_weights.
+    //
+    // # Args:
+    // 	* 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    // 		- The signature of the calling key.
+    //
+    // 	* 'netuid' (u16):
+    // 		- The u16 network identifier.
+    //
+    // 	* 'uids' ( Vec<u16> ):
+    // 		- The uids of the weights to be set on the chain.
+    //
+    // 	* 'values' ( Vec<u16> ):
+    // 		- The values of the weights to set on the chain.
+    //
+    // 	* 'salt' ( Vec<u8> ):
+    // 		- The salt used when computing the commit hash.
+    //
+    // 	* 'version_key' ( u64 ):
+    // 		- The network version key of the validator software revealing the weights.
+    //
+    // # Event:
+    // 	* WeightsSet;
+    // 		- On successfully revealing the weights.
+    //
+    // # Raises:
+    // 	* 'IncorrectNetworkVersionKey':
+    // 		- The version key is below the one required by the network.
+    //
+    // 	* 'NoWeightsCommit':
+    // 		- The caller has no pending commitment on this network.
+    //
+    // 	* 'RevealTooEarly':
+    // 		- Attempting to reveal in the same block as the commitment.
+    //
+    // 	* 'RevealWindowExpired':
+    // 		- The commitment is older than the network reveal window.
+    //
+    // 	* 'InvalidWeightsReveal':
+    // 		- The revealed weights and salt do not hash to the commitment.
+    //
+    // 	* Any of the errors raised by set_weights.
+    //
+    pub fn do_reveal_weights( origin: T::RuntimeOrigin, netuid: u16, uids: Vec<u16>, values: Vec<u16>, salt: Vec<u8>, version_key: u64 ) -> dispatch::DispatchResult {
+
+        // --- 1. Check the caller's signature.
+        let key = ensure_signed( origin )?;
+        log::info!("do_reveal_weights( origin:{:?} netuid:{:?}, uids:{:?}, values:{:?}, version_key:{:?} )", key, netuid, uids, values, version_key );
+
+        // --- 2. Check to see if this is a valid network running commit-reveal.
+        ensure!( Self::if_network_exist( netuid ), Error::<T>::NetworkDoesNotExist );
+        ensure!( Self::is_commit_reveal_enabled( netuid ), Error::<T>::CommitRevealDisabled );
+
+        // --- 2.1 Check that the validator software is up to date with the network.
+        ensure!( Self::check_version_key( netuid, version_key ), Error::<T>::IncorrectNetworkVersionKey );
+
+        // --- 3. Get the pending commitment and check it is inside the reveal window.
+        let ( commit_hash, commit_block ) = WeightCommits::<T>::get( netuid, &key ).ok_or( Error::<T>::NoWeightsCommit )?;
+        let current_block: u64 = Self::get_current_block_as_u64();
+        ensure!( current_block > commit_block, Error::<T>::RevealTooEarly );
+        ensure!( current_block <= commit_block.saturating_add( Self::get_weights_reveal_window( netuid ) ), Error::<T>::RevealWindowExpired );
+
+        // --- 4. Ensure the revealed weights match the commitment.
+        ensure!( Self::hash_weights_commit( &key, netuid, &uids, &values, &salt ) == commit_hash, Error::<T>::InvalidWeightsReveal );
 
-        // --- 19. Return ok.
+        // --- 5. Check the weights against the network requirements.
+        let ( module_uid, zipped_weights ) = Self::validate_weights( netuid, &key, uids, values, current_block )?;
+
+        // --- 6. Consume the commitment and sink the weights to storage.
+        WeightCommits::<T>::remove( netuid, &key );
+        Self::store_weights( netuid, module_uid, zipped_weights, current_block );
+
+        // --- 7. Return ok.
         Ok(())
     }
 
+    // Returns the commitment a validator must submit for the passed weights and salt.
+    //
+    pub fn hash_weights_commit( key: &T::AccountId, netuid: u16, uids: &Vec<u16>, values: &Vec<u16>, salt: &Vec<u8> ) -> H256 {
+        H256::from( blake2_256( &( key, netuid, uids, values, salt ).encode() ) )
+    }
+
+    // Removes the commitments on this network which can no longer be revealed.
+    //
+    // Drops the commitments queued to expire at this block which were not revealed, and returns the weight consumed.
+    // Commitments made before a larger reveal window was set are queued again at their new expiry block.
+    //
+    pub fn expire_weight_commits( netuid: u16, current_block: u64 ) -> Weight {
+        let keys: UidVec<T, T::AccountId> = WeightCommitExpiries::<T>::take( netuid, current_block );
+        let reveal_window: u64 = Self::get_weights_reveal_window( netuid );
+        for key in keys.iter() {
+            // Revealed commitments are gone, they were replaced if the commit block moved on.
+            let commit_block: u64 = match WeightCommits::<T>::get( netuid, key ) {
+                Some( ( _, commit_block ) ) => commit_block,
+                None => continue
+            };
+            let expiry_block: u64 = commit_block.saturating_add( reveal_window ).saturating_add( 1 );
+            if expiry_block > current_block && Self::queue_weight_commit_expiry( netuid, key, expiry_block ).is_ok() { continue }
+            WeightCommits::<T>::remove( netuid, key );
+            log::info!("WeightsCommitExpired( netuid:{:?}, key:{:?} )", netuid, key );
+            Self::deposit_event( Event::WeightsCommitExpired( netuid, key.clone() ) );
+        }
+        T::WeightInfo::expire_weight_commits( keys.len() as u32 )
+    }
+
+    // Queues the commitment of the key to expire at 'expiry_block', once per block.
+    //
+    pub fn queue_weight_commit_expiry( netuid: u16, key: &T::AccountId, expiry_block: u64 ) -> dispatch::DispatchResult {
+        WeightCommitExpiries::<T>::try_mutate( netuid, expiry_block, |keys| -> dispatch::DispatchResult {
+            if !keys.contains( key ) { keys.try_push( key.clone() ).map_err( |_| Error::<T>::TooManyWeightCommits )?; }
+            Ok(())
+        })
+    }
+
     // ==========================
 	// ==== Helper functions ====
 	// ==========================
     
+    // Returns true if the passed version key is at least the version required by the network.
+    //
+    pub fn check_version_key( netuid: u16, version_key: u64 ) -> bool {
+        version_key >= Self::get_weights_version_key( netuid )
+    }
+
     // Checks if the module has set weights within the weights_set_rate_limit.
     //
     pub fn check_rate_limit( netuid: u16, module_uid: u16, current_block: u64 ) -> bool {
@@ -128,6 +554,16 @@ impl<T: Config> Pallet<T> {
         return false;
     }
 
+    // Checks that the module waits out the weights_set_rate_limit since its last weights and its last commitment.
+    //
+    pub fn check_commit_rate_limit( netuid: u16, module_uid: u16, key: &T::AccountId, current_block: u64 ) -> bool {
+        if !Self::check_rate_limit( netuid, module_uid, current_block ) { return false; }
+        match WeightCommits::<T>::get( netuid, key ) {
+            Some( ( _, commit_block ) ) => current_block - commit_block >= Self::get_weights_set_rate_limit( netuid ),
+            None => true
+        }
+    }
+
     // Checks for any invalid uids on this network.
     pub fn contains_invalid_uids( netuid: u16, uids: &Vec<u16> ) -> bool {
         for uid in uids {
@@ -188,8 +624,11 @@ impl<T: Config> Pallet<T> {
         let max_weight_limit: u16 = Self::get_max_weight_limit( netuid );
         if max_weight_limit == u16::MAX { return true; }
     
-        // Check if the weights max value is less than or equal to the limit.
-        let max: u16 = *weights.iter().max().unwrap();
+        // Check if the weights max value is less than or equal to the limit. No weights exceed nothing.
+        let max: u16 = match weights.iter().max() {
+            Some( max ) => *max,
+            None => return true
+        };
         if max <= max_weight_limit { return true; }
         
         // The check has failed.
@@ -0,0 +1,84 @@
+mod mock;
+use mock::*;
+use sp_core::U256;
+
+/********************************************
+	block_step::drain_emission() tests
+*********************************************/
+
+#[test]
+fn test_tuples_to_drain_this_block() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 0;
+		let tempo: u16 = 10;
+		// Block 0 is 9 blocks away from the epoch of network 0.
+		assert_eq!( SubspaceModule::tuples_to_drain_this_block( netuid, tempo, 0, 0 ), 0 );
+		assert_eq!( SubspaceModule::tuples_to_drain_this_block( netuid, tempo, 0, 1 ), 1 );
+		assert_eq!( SubspaceModule::tuples_to_drain_this_block( netuid, tempo, 0, 3 ), 1 );
+		assert_eq!( SubspaceModule::tuples_to_drain_this_block( netuid, tempo, 0, 7 ), 2 );
+		assert_eq!( SubspaceModule::tuples_to_drain_this_block( netuid, tempo, 0, 100 ), 25 );
+		// Right before the epoch, everything is drained.
+		assert_eq!( SubspaceModule::tuples_to_drain_this_block( netuid, tempo, 9, 5 ), 5 );
+		// Fewer tuples than half the tempo still drain one per block.
+		for block in 0..9 {
+			assert!( SubspaceModule::tuples_to_drain_this_block( netuid, tempo, block, 2 ) >= 1 );
+		}
+	});
+}
+
+#[test]
+fn test_drain_emission_across_tempo() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 0;
+		let tempo: u16 = 10;
+		add_network( netuid, tempo );
+		let tuples: Vec<(U256, u64)> = (1..=3).map( |key| ( U256::from( key ), 100 ) ).collect();
+		SubspaceModule::load_emission_tuples( netuid, tuples.clone() );
+
+		// One tuple is paid per block, in order, and the cursor follows.
+		for block in 0..3u64 {
+			SubspaceModule::drain_emission( block );
+			for ( i, ( key, amount ) ) in tuples.iter().enumerate() {
+				let expected: u64 = if ( i as u64 ) <= block { *amount } else { 0 };
+				assert_eq!( SubspaceModule::get_lifetime_emission( netuid, key ), expected );
+			}
+			if block < 2 {
+				assert!( SubspaceModule::has_loaded_emission_tuples( netuid ) );
+				assert_eq!( SubspaceModule::get_loaded_emission_cursor( netuid ), block as u32 + 1 );
+			}
+		}
+
+		// All paid within the tempo, the tuples and the cursor are dropped.
+		assert!( !SubspaceModule::has_loaded_emission_tuples( netuid ) );
+		assert_eq!( SubspaceModule::get_loaded_emission_cursor( netuid ), 0 );
+	});
+}
+
+#[test]
+fn test_drain_emission_spread_over_half_tempo() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 0;
+		let tempo: u16 = 10;
+		add_network( netuid, tempo );
+		let tuples: Vec<(U256, u64)> = (1..=12).map( |key| ( U256::from( key ), 10 ) ).collect();
+		SubspaceModule::load_emission_tuples( netuid, tuples.clone() );
+
+		// Every block pays its share until the tuples run out, before the next epoch of the network.
+		let mut paid: u32 = 0;
+		let mut block: u64 = 0;
+		while SubspaceModule::has_loaded_emission_tuples( netuid ) {
+			let n_remaining: usize = tuples.len() - paid as usize;
+			let due: usize = SubspaceModule::tuples_to_drain_this_block( netuid, tempo, block, n_remaining ).min( n_remaining );
+			assert!( due >= 1 );
+			SubspaceModule::drain_emission( block );
+			paid += due as u32;
+			if SubspaceModule::has_loaded_emission_tuples( netuid ) {
+				assert_eq!( SubspaceModule::get_loaded_emission_cursor( netuid ), paid );
+			}
+			block += 1;
+		}
+		assert_eq!( paid as usize, tuples.len() );
+		assert!( block <= tempo as u64 );
+		assert!( tuples.iter().all( |(key, amount)| SubspaceModule::get_lifetime_emission( netuid, key ) == *amount ) );
+	});
+}
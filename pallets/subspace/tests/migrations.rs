@@ -0,0 +1,38 @@
+mod mock;
+use mock::*;
+use frame_support::assert_ok;
+use frame_support::traits::{GetStorageVersion, Hooks, StorageVersion};
+use frame_system::Config;
+use sp_core::U256;
+
+/********************************************
+	migrations::migrate_to_v1() tests
+*********************************************/
+
+#[test]
+fn test_migrate_to_v1() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 0;
+		add_network( netuid, 10 );
+		SubspaceModule::set_weights_set_rate_limit( netuid, 0 );
+		register_module( netuid, U256::from( 0 ), 1_000 );
+		register_module( netuid, U256::from( 1 ), 1_000 );
+		let origin = <<Test as Config>::RuntimeOrigin>::signed( U256::from( 0 ) );
+		assert_ok!( SubspaceModule::set_weights( origin, netuid, vec![ 1 ], vec![ 1 ], 0 ) );
+		assert_ok!( SubspaceModule::serve_module( <<Test as Config>::RuntimeOrigin>::signed( U256::from( 1 ) ), netuid, b"module".to_vec(), vec![], b"uri".to_vec(), vec![] ) );
+		let weights: Vec<(u16, u16)> = SubspaceModule::get_weights_for_uid( netuid, 0 );
+		StorageVersion::new( 0 ).put::<SubspaceModule>();
+
+		// The modules are cleared, the bounded values are kept and the version is recorded.
+		SubspaceModule::on_runtime_upgrade();
+		assert_eq!( SubspaceModule::on_chain_storage_version(), StorageVersion::new( 1 ) );
+		assert!( !SubspaceModule::has_module( netuid, &U256::from( 1 ) ) );
+		assert_eq!( SubspaceModule::get_weights_for_uid( netuid, 0 ), weights );
+		assert_eq!( SubspaceModule::get_network_n( netuid ), 2 );
+
+		// Run again, the migration does nothing.
+		assert_ok!( SubspaceModule::serve_module( <<Test as Config>::RuntimeOrigin>::signed( U256::from( 1 ) ), netuid, b"module".to_vec(), vec![], b"uri".to_vec(), vec![] ) );
+		SubspaceModule::on_runtime_upgrade();
+		assert!( SubspaceModule::has_module( netuid, &U256::from( 1 ) ) );
+	});
+}
@@ -0,0 +1,206 @@
+use frame_support::{assert_ok, parameter_types, traits::{Everything, Hooks}, weights::Weight};
+use frame_system as system;
+use frame_system::Config;
+use sp_core::{H256, U256};
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Config<T>, Storage, Event<T>},
+		SubspaceModule: pallet_subspace::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+#[allow(dead_code)]
+pub type SubspaceCall = pallet_subspace::Call<Test>;
+
+#[allow(dead_code)]
+pub type BalanceCall = pallet_balances::Call<Test>;
+
+#[allow(dead_code)]
+pub type AccountId = U256;
+
+// Balance of an account.
+#[allow(dead_code)]
+pub type Balance = u64;
+
+// An index to a block.
+#[allow(dead_code)]
+pub type BlockNumber = u64;
+
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type MaxLocks = MaxLocks;
+	type WeightInfo = ();
+	type MaxReserves = MaxReserves;
+	type ReserveIdentifier = ();
+}
+
+impl system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = U256;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 42;
+	pub const ExistentialDeposit: Balance = 1;
+	pub const MaxLocks: u32 = 50;
+	pub const MaxReserves: u32 = 50;
+	pub TreasuryAccount: U256 = U256::from( 999_999 );
+}
+
+parameter_types! {
+	pub const InitialMinAllowedWeights: u16 = 0;
+	pub const InitialEmissionValue: u16 = 0;
+	pub const InitialMaxWeightsLimit: u16 = u16::MAX;
+	pub const InitialIssuance: u64 = 0;
+	pub const InitialBlockEmission: u64 = 1_000_000_000;
+	pub const MaxSupply: u64 = 21_000_000_000_000_000;
+	pub const InitialTreasuryCut: u16 = 0;
+	pub const InitialTempo: u16 = 0;
+	pub const InitialAdjustmentInterval: u16 = 100;
+	pub const InitialBondsMovingAverage: u64 = 900_000;
+	pub const InitialRho: u16 = 10;
+	pub const InitialKappa: u16 = 32_767;
+	pub const InitialIncentiveRatio: u16 = 50;
+	pub const InitialTargetRegistrationsPerInterval: u16 = 2;
+	pub const InitialMaxAllowedUids: u16 = 2;
+	pub const InitialMaxAllowedValidators: u16 = 100;
+	pub const InitialImmunityPeriod: u16 = 2;
+	pub const InitialActivityCutoff: u16 = 5000;
+	pub const InitialMaxRegistrationsPerBlock: u16 = 3;
+	pub const InitialPruningScore: u16 = u16::MAX;
+	pub const InitialServingRateLimit: u64 = 0;
+	pub const InitialTxRateLimit: u64 = 0;
+	pub const MaxAllowedUidsLowerBound: u16 = 1;
+	pub const MaxAllowedUidsUpperBound: u16 = 4096;
+	pub const ImmunityPeriodUpperBound: u16 = 16384;
+	pub const ActivityCutoffLowerBound: u16 = 100;
+	pub const ActivityCutoffUpperBound: u16 = 50000;
+	pub const BondsMovingAverageUpperBound: u64 = 1_000_000;
+	pub const AdjustmentIntervalLowerBound: u16 = 1;
+	pub const MaxRegistrationsPerBlockUpperBound: u16 = 256;
+	pub const ServingRateLimitUpperBound: u64 = 7200;
+	pub const EpochWeightPerBlock: Weight = Weight::from_ref_time( 1_000_000_000_000 );
+	pub const MaxEmissionHistory: u32 = 32;
+	pub const MaxModuleEndpoints: u32 = 8;
+	pub const MaxNameLength: u32 = 64;
+	pub const MaxUriLength: u32 = 256;
+	pub const MaxScheduledChanges: u32 = 32;
+	pub const MaxModuleTags: u32 = 8;
+	pub const MaxTagLength: u32 = 32;
+}
+
+impl pallet_subspace::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type WeightInfo = ();
+	type InitialIssuance = InitialIssuance;
+	type InitialBlockEmission = InitialBlockEmission;
+	type MaxSupply = MaxSupply;
+	type TreasuryAccount = TreasuryAccount;
+	type InitialTreasuryCut = InitialTreasuryCut;
+	type InitialMinAllowedWeights = InitialMinAllowedWeights;
+	type InitialEmissionValue = InitialEmissionValue;
+	type InitialMaxWeightsLimit = InitialMaxWeightsLimit;
+	type InitialTempo = InitialTempo;
+	type InitialAdjustmentInterval = InitialAdjustmentInterval;
+	type InitialBondsMovingAverage = InitialBondsMovingAverage;
+	type InitialRho = InitialRho;
+	type InitialKappa = InitialKappa;
+	type InitialIncentiveRatio = InitialIncentiveRatio;
+	type InitialTargetRegistrationsPerInterval = InitialTargetRegistrationsPerInterval;
+	type InitialMaxAllowedUids = InitialMaxAllowedUids;
+	type InitialMaxAllowedValidators = InitialMaxAllowedValidators;
+	type InitialImmunityPeriod = InitialImmunityPeriod;
+	type InitialActivityCutoff = InitialActivityCutoff;
+	type InitialMaxRegistrationsPerBlock = InitialMaxRegistrationsPerBlock;
+	type InitialPruningScore = InitialPruningScore;
+	type InitialServingRateLimit = InitialServingRateLimit;
+	type InitialTxRateLimit = InitialTxRateLimit;
+	type MaxAllowedUidsLowerBound = MaxAllowedUidsLowerBound;
+	type MaxAllowedUidsUpperBound = MaxAllowedUidsUpperBound;
+	type ImmunityPeriodUpperBound = ImmunityPeriodUpperBound;
+	type ActivityCutoffLowerBound = ActivityCutoffLowerBound;
+	type ActivityCutoffUpperBound = ActivityCutoffUpperBound;
+	type BondsMovingAverageUpperBound = BondsMovingAverageUpperBound;
+	type AdjustmentIntervalLowerBound = AdjustmentIntervalLowerBound;
+	type MaxRegistrationsPerBlockUpperBound = MaxRegistrationsPerBlockUpperBound;
+	type ServingRateLimitUpperBound = ServingRateLimitUpperBound;
+	type EpochWeightPerBlock = EpochWeightPerBlock;
+	type MaxEmissionHistory = MaxEmissionHistory;
+	type MaxModuleEndpoints = MaxModuleEndpoints;
+	type MaxNameLength = MaxNameLength;
+	type MaxUriLength = MaxUriLength;
+	type MaxScheduledChanges = MaxScheduledChanges;
+	type MaxModuleTags = MaxModuleTags;
+	type MaxTagLength = MaxTagLength;
+}
+
+// Build genesis storage according to the mock runtime.
+#[allow(dead_code)]
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	sp_tracing::try_init_simple();
+	frame_system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+}
+
+#[allow(dead_code)]
+pub fn run_to_block( n: u64 ) {
+	while System::block_number() < n {
+		SubspaceModule::on_finalize( System::block_number() );
+		System::on_finalize( System::block_number() );
+		System::set_block_number( System::block_number() + 1 );
+		System::on_initialize( System::block_number() );
+		SubspaceModule::on_initialize( System::block_number() );
+	}
+}
+
+#[allow(dead_code)]
+pub fn register_module( netuid: u16, key: U256, stake: u64 ) {
+	assert_ok!( SubspaceModule::do_registration( <<Test as Config>::RuntimeOrigin>::signed( key ), netuid ) );
+	SubspaceModule::increase_stake_on_account( &key, stake );
+}
+
+#[allow(dead_code)]
+pub fn add_network( netuid: u16, tempo: u16 ) {
+	SubspaceModule::init_new_network( netuid, tempo );
+}
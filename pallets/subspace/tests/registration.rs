@@ -0,0 +1,104 @@
+mod mock;
+use mock::*;
+use frame_support::assert_ok;
+use frame_system::Config;
+use sp_core::U256;
+
+/********************************************
+	registration::do_registration() tests
+*********************************************/
+
+#[test]
+fn test_registration_into_full_network_during_epoch() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 0;
+		let tempo: u16 = 10;
+		add_network( netuid, tempo );
+		SubspaceModule::set_max_allowed_uids( netuid, 2 );
+		SubspaceModule::set_immunity_period( netuid, 0 );
+		register_module( netuid, U256::from( 1 ), 1_000 );
+		register_module( netuid, U256::from( 2 ), 1_000 );
+		assert_eq!( SubspaceModule::get_network_n( netuid ), 2 );
+
+		// Start the epoch without advancing it.
+		SubspaceModule::start_epoch( netuid, 1_000 );
+		assert!( SubspaceModule::is_epoch_in_progress( netuid ) );
+
+		// The network is full, the new key replaces a module.
+		let new_key: U256 = U256::from( 3 );
+		let uid: u16 = SubspaceModule::get_module_to_prune( netuid );
+		let old_key: U256 = SubspaceModule::get_key_for_net_and_uid( netuid, uid ).unwrap();
+		register_module( netuid, new_key, 0 );
+		assert_eq!( SubspaceModule::get_uid_for_net_and_key( netuid, &new_key ).unwrap(), uid );
+		assert!( !SubspaceModule::is_key_registered_on_network( netuid, &old_key ) );
+		assert_eq!( SubspaceModule::get_network_n( netuid ), 2 );
+
+		// The registration did not run the epoch.
+		assert!( SubspaceModule::is_epoch_in_progress( netuid ) );
+		assert!( !SubspaceModule::has_loaded_emission_tuples( netuid ) );
+
+		// Once complete, the epoch pays the keys of its snapshot and leaves the values of the replaced uid alone.
+		SubspaceModule::advance_epoch( netuid, u64::MAX );
+		assert!( !SubspaceModule::is_epoch_in_progress( netuid ) );
+		let tuples: Vec<(U256, u64)> = SubspaceModule::get_loaded_emission_tuples( netuid );
+		assert_eq!( tuples.len(), 2 );
+		assert!( tuples.iter().any( |(key, emission)| *key == old_key && *emission > 0 ) );
+		assert!( tuples.iter().all( |(key, _)| *key != new_key ) );
+		assert_eq!( SubspaceModule::get_emission_for_uid( netuid, uid ), 0 );
+		assert!( SubspaceModule::get_emission_for_uid( netuid, 1 - uid ) > 0 );
+	});
+}
+
+#[test]
+fn test_no_module_removed_during_epoch() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 0;
+		add_network( netuid, 10 );
+		SubspaceModule::set_max_allowed_uids( netuid, 2 );
+		register_module( netuid, U256::from( 1 ), 1_000 );
+		register_module( netuid, U256::from( 2 ), 1_000 );
+
+		// Uids only move between epochs.
+		SubspaceModule::start_epoch( netuid, 1_000 );
+		SubspaceModule::remove_module( netuid, 0 );
+		assert_eq!( SubspaceModule::get_network_n( netuid ), 2 );
+
+		SubspaceModule::advance_epoch( netuid, u64::MAX );
+		SubspaceModule::remove_module( netuid, 0 );
+		assert_eq!( SubspaceModule::get_network_n( netuid ), 1 );
+		assert_eq!( SubspaceModule::get_uid_for_net_and_key( netuid, &U256::from( 2 ) ).unwrap(), 0 );
+	});
+}
+
+#[test]
+fn test_remove_module_remaps_weights() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 0;
+		add_network( netuid, 10 );
+		SubspaceModule::set_max_allowed_uids( netuid, 4 );
+		SubspaceModule::set_max_registrations_per_block( netuid, 4 );
+		SubspaceModule::set_weights_set_rate_limit( netuid, 0 );
+		for key in 0..4 {
+			register_module( netuid, U256::from( key ), 1_000 );
+		}
+		let set_weights = | key: u64, uids: Vec<u16> | {
+			let values: Vec<u16> = vec![ 1; uids.len() ];
+			assert_ok!( SubspaceModule::set_weights( <<Test as Config>::RuntimeOrigin>::signed( U256::from( key ) ), netuid, uids, values, 0 ) );
+		};
+		set_weights( 0, vec![ 1 ] );
+		set_weights( 2, vec![ 3 ] );
+		set_weights( 3, vec![ 0, 1 ] );
+		let weights_uids = | uid: u16 | -> Vec<u16> { SubspaceModule::get_weights_for_uid( netuid, uid ).iter().map( |(uid_j, _)| *uid_j ).collect() };
+
+		// Uid 1 has no weights, uid 3 moves into its slot.
+		SubspaceModule::remove_module( netuid, 1 );
+		assert_eq!( SubspaceModule::get_network_n( netuid ), 3 );
+		// The row left empty is dropped rather than stored empty.
+		assert!( !SubspaceModule::has_weights_for_uid( netuid, 0 ) );
+		// The moved row loses its entry to the removed uid.
+		assert_eq!( weights_uids( 1 ), vec![ 0 ] );
+		// The entry to the moved uid follows it.
+		assert_eq!( weights_uids( 2 ), vec![ 1 ] );
+		assert!( !SubspaceModule::has_weights_for_uid( netuid, 3 ) );
+	});
+}
@@ -0,0 +1,78 @@
+mod mock;
+use mock::*;
+use frame_support::{assert_ok, assert_noop};
+use frame_system::Config;
+use pallet_subspace::{Error, HyperparameterChange, ConsensusMechanismKind};
+
+/********************************************
+	sudo setters of the epoch parameters
+*********************************************/
+
+#[test]
+fn test_epoch_parameter_setters_wait_for_the_epoch() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 0;
+		add_network( netuid, 10 );
+		SubspaceModule::set_max_allowed_uids( netuid, 100 );
+		SubspaceModule::set_min_allowed_weights( netuid, 4 );
+		let root = <<Test as Config>::RuntimeOrigin>::root();
+		let rho: u16 = SubspaceModule::get_rho( netuid );
+		let max_allowed_uids: u16 = SubspaceModule::get_max_allowed_uids( netuid );
+		let activity_cutoff: u16 = SubspaceModule::get_activity_cutoff( netuid );
+
+		assert_ok!( SubspaceModule::sudo_set_max_weight_limit( root.clone(), netuid, 30_000 ) );
+		assert_ok!( SubspaceModule::sudo_set_min_allowed_weights( root.clone(), netuid, 3 ) );
+		assert_ok!( SubspaceModule::sudo_set_max_allowed_validators( root.clone(), netuid, 10 ) );
+		assert_ok!( SubspaceModule::sudo_set_max_allowed_uids( root.clone(), netuid, 50 ) );
+		assert_ok!( SubspaceModule::sudo_set_bonds_moving_average( root.clone(), netuid, 500_000 ) );
+		assert_ok!( SubspaceModule::sudo_set_activity_cutoff( root.clone(), netuid, 1_000 ) );
+		assert_ok!( SubspaceModule::sudo_set_rho( root.clone(), netuid, rho + 1 ) );
+		assert_ok!( SubspaceModule::sudo_set_kappa( root.clone(), netuid, 1_000 ) );
+		assert_ok!( SubspaceModule::sudo_set_incentive_ratio( root.clone(), netuid, 70 ) );
+		assert_ok!( SubspaceModule::sudo_set_consensus_mechanism( root.clone(), netuid, ConsensusMechanismKind::Stake ) );
+		assert_ok!( SubspaceModule::sudo_set_weights_decay_half_life( root, netuid, 100 ) );
+
+		// Nothing moved, every change waits for the next epoch.
+		let queue: Vec<(HyperparameterChange, u64)> = SubspaceModule::get_pending_hyperparameter_changes( netuid );
+		assert_eq!( queue.len(), 11 );
+		assert!( queue.iter().all( |(_, apply_at_block)| *apply_at_block == 0 ) );
+		assert_eq!( SubspaceModule::get_rho( netuid ), rho );
+		assert_eq!( SubspaceModule::get_max_allowed_uids( netuid ), max_allowed_uids );
+		assert_eq!( SubspaceModule::get_activity_cutoff( netuid ), activity_cutoff );
+
+		// A block step between epochs leaves them queued.
+		SubspaceModule::apply_hyperparameter_changes( netuid, 1, false );
+		assert_eq!( SubspaceModule::get_pending_hyperparameter_changes( netuid ).len(), 11 );
+
+		// Right before the epoch, they all apply, in the order they were queued.
+		SubspaceModule::apply_hyperparameter_changes( netuid, 1, true );
+		assert!( SubspaceModule::get_pending_hyperparameter_changes( netuid ).is_empty() );
+		assert_eq!( SubspaceModule::get_max_weight_limit( netuid ), 30_000 );
+		assert_eq!( SubspaceModule::get_min_allowed_weights( netuid ), 3 );
+		assert_eq!( SubspaceModule::get_max_allowed_uids( netuid ), 50 );
+		assert_eq!( SubspaceModule::get_max_allowed_validators( netuid ), 10 );
+		assert_eq!( SubspaceModule::get_bonds_moving_average( netuid ), 500_000 );
+		assert_eq!( SubspaceModule::get_activity_cutoff( netuid ), 1_000 );
+		assert_eq!( SubspaceModule::get_rho( netuid ), rho + 1 );
+		assert_eq!( SubspaceModule::get_kappa( netuid ), 1_000 );
+		assert_eq!( SubspaceModule::get_incentive_ratio( netuid ), 70 );
+		assert_eq!( SubspaceModule::get_consensus_mechanism( netuid ), ConsensusMechanismKind::Stake );
+		assert_eq!( SubspaceModule::get_weights_decay_half_life( netuid ), 100 );
+	});
+}
+
+#[test]
+fn test_epoch_parameter_setters_check_bounds() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 0;
+		add_network( netuid, 10 );
+		let root = <<Test as Config>::RuntimeOrigin>::root();
+		let signed = <<Test as Config>::RuntimeOrigin>::signed( sp_core::U256::from( 1 ) );
+
+		assert_noop!( SubspaceModule::sudo_set_rho( root.clone(), netuid, 0 ), Error::<Test>::StorageValueOutOfRange );
+		assert_noop!( SubspaceModule::sudo_set_max_allowed_uids( root.clone(), netuid, 0 ), Error::<Test>::StorageValueOutOfRange );
+		assert_noop!( SubspaceModule::sudo_set_activity_cutoff( root.clone(), 1, 1_000 ), Error::<Test>::NetworkDoesNotExist );
+		assert_noop!( SubspaceModule::sudo_set_kappa( signed, netuid, 1_000 ), sp_runtime::DispatchError::BadOrigin );
+		assert!( SubspaceModule::get_pending_hyperparameter_changes( netuid ).is_empty() );
+	});
+}
@@ -0,0 +1,103 @@
+mod mock;
+use mock::*;
+use frame_support::assert_ok;
+use frame_system::Config;
+use sp_core::U256;
+
+/********************************************
+	serving::set_module_tags() and module::get_modules_by_tag() tests
+*********************************************/
+
+fn serve_with_tags( netuid: u16, key: u64, tags: Vec<&[u8]> ) {
+	let origin = <<Test as Config>::RuntimeOrigin>::signed( U256::from( key ) );
+	let tags: Vec<Vec<u8>> = tags.into_iter().map( |tag| tag.to_vec() ).collect();
+	assert_ok!( SubspaceModule::serve_module( origin, netuid, b"module".to_vec(), vec![], b"uri".to_vec(), tags ) );
+}
+
+// Returns the ( key, uid ) of the modules matching the tag, in the returned order.
+fn matches( netuid: u16, tag: &[u8] ) -> Vec<(U256, u16)> {
+	SubspaceModule::get_modules_by_tag( netuid, tag.to_vec() ).into_iter().map( |m| ( m.key, m.uid.0 ) ).collect()
+}
+
+#[test]
+fn test_get_modules_by_tag_sorted_by_incentive() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 0;
+		add_network( netuid, 10 );
+		SubspaceModule::set_max_allowed_uids( netuid, 4 );
+		SubspaceModule::set_max_registrations_per_block( netuid, 4 );
+		for key in 0..4 {
+			register_module( netuid, U256::from( key ), 1_000 );
+		}
+		serve_with_tags( netuid, 0, vec![ b"llm" ] );
+		serve_with_tags( netuid, 1, vec![ b"llm", b"vision" ] );
+		serve_with_tags( netuid, 2, vec![ b"llm" ] );
+		serve_with_tags( netuid, 3, vec![ b"vision" ] );
+		SubspaceModule::set_incentive_for_uid( netuid, 0, 10 );
+		SubspaceModule::set_incentive_for_uid( netuid, 1, 50 );
+		SubspaceModule::set_incentive_for_uid( netuid, 2, 50 );
+		SubspaceModule::set_incentive_for_uid( netuid, 3, 90 );
+
+		// Highest incentive first, ties broken by the lowest uid.
+		assert_eq!( matches( netuid, b"llm" ), vec![ ( U256::from( 1 ), 1 ), ( U256::from( 2 ), 2 ), ( U256::from( 0 ), 0 ) ] );
+		assert_eq!( matches( netuid, b"vision" ), vec![ ( U256::from( 3 ), 3 ), ( U256::from( 1 ), 1 ) ] );
+		let incentives: Vec<u16> = SubspaceModule::get_modules_by_tag( netuid, b"llm".to_vec() ).into_iter().map( |m| m.incentive.0 ).collect();
+		assert_eq!( incentives, vec![ 50, 50, 10 ] );
+
+		// Unknown tags, tags longer than any served one and unknown networks match nothing.
+		assert!( matches( netuid, b"audio" ).is_empty() );
+		assert!( matches( netuid, &[ b'a'; 1_000 ] ).is_empty() );
+		assert!( matches( 1, b"llm" ).is_empty() );
+	});
+}
+
+#[test]
+fn test_set_module_tags_reindexes_on_serve() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 0;
+		add_network( netuid, 10 );
+		register_module( netuid, U256::from( 0 ), 1_000 );
+		serve_with_tags( netuid, 0, vec![ b"llm", b"vision" ] );
+		assert_eq!( matches( netuid, b"vision" ), vec![ ( U256::from( 0 ), 0 ) ] );
+
+		// Serving again replaces the tags, the module is only indexed under the new ones.
+		serve_with_tags( netuid, 0, vec![ b"audio" ] );
+		assert_eq!( SubspaceModule::get_module_tags( netuid, &U256::from( 0 ) ), vec![ b"audio".to_vec() ] );
+		assert!( matches( netuid, b"llm" ).is_empty() );
+		assert!( matches( netuid, b"vision" ).is_empty() );
+		assert_eq!( matches( netuid, b"audio" ), vec![ ( U256::from( 0 ), 0 ) ] );
+
+		// Serving without tags clears them.
+		serve_with_tags( netuid, 0, vec![] );
+		assert!( SubspaceModule::get_module_tags( netuid, &U256::from( 0 ) ).is_empty() );
+		assert!( matches( netuid, b"audio" ).is_empty() );
+	});
+}
+
+#[test]
+fn test_module_tags_cleared_on_replace_and_remove() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 0;
+		add_network( netuid, 10 );
+		SubspaceModule::set_max_allowed_uids( netuid, 2 );
+		SubspaceModule::set_immunity_period( netuid, 0 );
+		register_module( netuid, U256::from( 1 ), 1_000 );
+		register_module( netuid, U256::from( 2 ), 1_000 );
+		serve_with_tags( netuid, 1, vec![ b"llm" ] );
+		serve_with_tags( netuid, 2, vec![ b"llm" ] );
+		assert_eq!( matches( netuid, b"llm" ).len(), 2 );
+
+		// The network is full, the new key replaces a module and the index drops the old key.
+		let uid: u16 = SubspaceModule::get_module_to_prune( netuid );
+		let old_key: U256 = SubspaceModule::get_key_for_net_and_uid( netuid, uid ).unwrap();
+		let kept_key: U256 = SubspaceModule::get_key_for_net_and_uid( netuid, 1 - uid ).unwrap();
+		register_module( netuid, U256::from( 3 ), 0 );
+		assert!( SubspaceModule::get_module_tags( netuid, &old_key ).is_empty() );
+		assert_eq!( matches( netuid, b"llm" ), vec![ ( kept_key, 1 - uid ) ] );
+
+		// A removed module leaves the index as well.
+		SubspaceModule::remove_module( netuid, 1 - uid );
+		assert!( SubspaceModule::get_module_tags( netuid, &kept_key ).is_empty() );
+		assert!( matches( netuid, b"llm" ).is_empty() );
+	});
+}
@@ -0,0 +1,97 @@
+mod mock;
+use mock::*;
+use frame_support::{assert_ok, assert_noop};
+use frame_system::Config;
+use pallet_subspace::Error;
+use sp_core::U256;
+
+/********************************************
+	weights::do_patch_weights() tests
+*********************************************/
+
+// Registers 'n' modules on a new network and returns the network id.
+fn setup_network( n: u16 ) -> u16 {
+	let netuid: u16 = 0;
+	add_network( netuid, 10 );
+	SubspaceModule::set_max_allowed_uids( netuid, n );
+	SubspaceModule::set_max_registrations_per_block( netuid, n );
+	SubspaceModule::set_weights_set_rate_limit( netuid, 0 );
+	for key in 0..n {
+		register_module( netuid, U256::from( key ), 1_000 );
+	}
+	netuid
+}
+
+fn weights_uids( netuid: u16, uid: u16 ) -> Vec<u16> {
+	let mut uids: Vec<u16> = SubspaceModule::get_weights_for_uid( netuid, uid ).iter().map( |(uid_j, _)| *uid_j ).collect();
+	uids.sort();
+	uids
+}
+
+#[test]
+fn test_patch_weights_upsert() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = setup_network( 4 );
+		let key: U256 = U256::from( 0 );
+		let origin = <<Test as Config>::RuntimeOrigin>::signed( key );
+		assert_ok!( SubspaceModule::set_weights( origin.clone(), netuid, vec![ 1, 2 ], vec![ 1, 1 ], 0 ) );
+
+		// A new uid is inserted and an existing one overwritten, the row is renormalized.
+		assert_ok!( SubspaceModule::patch_weights( origin, netuid, vec![ ( 3, u16::MAX / 2 ), ( 1, u16::MAX ) ], vec![], 0 ) );
+		assert_eq!( weights_uids( netuid, 0 ), vec![ 1, 2, 3 ] );
+		let row: Vec<(u16, u16)> = SubspaceModule::get_weights_for_uid( netuid, 0 );
+		let value = | uid: u16 | row.iter().find( |(uid_j, _)| *uid_j == uid ).unwrap().1;
+		assert!( value( 1 ) > value( 3 ) );
+		assert_eq!( value( 3 ), value( 2 ) );
+	});
+}
+
+#[test]
+fn test_patch_weights_remove() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = setup_network( 4 );
+		let key: U256 = U256::from( 0 );
+		let origin = <<Test as Config>::RuntimeOrigin>::signed( key );
+		assert_ok!( SubspaceModule::set_weights( origin.clone(), netuid, vec![ 1, 2, 3 ], vec![ 1, 1, 1 ], 0 ) );
+
+		assert_ok!( SubspaceModule::patch_weights( origin, netuid, vec![], vec![ 2 ], 0 ) );
+		assert_eq!( weights_uids( netuid, 0 ), vec![ 1, 3 ] );
+		let sum: u32 = SubspaceModule::get_weights_for_uid( netuid, 0 ).iter().map( |(_, value)| *value as u32 ).sum();
+		assert!( sum >= u16::MAX as u32 - 1 );
+	});
+}
+
+#[test]
+fn test_patch_weights_remove_everything() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = setup_network( 4 );
+		let key: U256 = U256::from( 0 );
+		let origin = <<Test as Config>::RuntimeOrigin>::signed( key );
+		assert_eq!( SubspaceModule::get_min_allowed_weights( netuid ), 0 );
+		assert_ok!( SubspaceModule::set_weights( origin.clone(), netuid, vec![ 1, 2 ], vec![ 1, 1 ], 0 ) );
+
+		// An empty row is rejected, even without a minimum number of weights, and the row is kept.
+		assert_noop!( SubspaceModule::patch_weights( origin, netuid, vec![], vec![ 1, 2 ], 0 ), Error::<Test>::EmptyWeights );
+		assert_eq!( weights_uids( netuid, 0 ), vec![ 1, 2 ] );
+	});
+}
+
+#[test]
+fn test_patch_weights_set_by_key() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = setup_network( 4 );
+		let key: U256 = U256::from( 0 );
+		let origin = <<Test as Config>::RuntimeOrigin>::signed( key );
+		assert_ok!( SubspaceModule::set_weights_by_key( origin.clone(), netuid, vec![ U256::from( 1 ), U256::from( 2 ) ], vec![ 1, 1 ], 0 ) );
+
+		assert_noop!( SubspaceModule::patch_weights( origin, netuid, vec![ ( 3, 1 ) ], vec![], 0 ), Error::<Test>::WeightsSetByKey );
+	});
+}
+
+#[test]
+fn test_patch_weights_network_does_not_exist() {
+	new_test_ext().execute_with(|| {
+		let origin = <<Test as Config>::RuntimeOrigin>::signed( U256::from( 0 ) );
+		assert_noop!( SubspaceModule::patch_weights( origin, 1, vec![ ( 1, 1 ) ], vec![], 0 ), Error::<Test>::NetworkDoesNotExist );
+	});
+}
@@ -6,7 +6,7 @@
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
-use codec::Encode;
+use codec::{Decode, Encode};
 use pallet_grandpa::{
 	fg_primitives, AuthorityId as GrandpaId, AuthorityList as GrandpaAuthorityList,
 };
@@ -20,7 +20,7 @@ use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{
-		AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, One, Verify,
+		AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, One, Verify,
 	},
 	transaction_validity::{TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, MultiSignature,
@@ -43,8 +43,9 @@ pub use frame_support::{
 		},
 		IdentityFee, Weight, WeightToFeeCoefficients, WeightToFeeCoefficient, WeightToFeePolynomial
 	},
-	StorageValue,
+	PalletId, StorageValue,
 };
+use frame_system::{EnsureRoot, EnsureRootWithSuccess};
 pub use frame_system::Call as SystemCall;
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_timestamp::Call as TimestampCall;
@@ -111,7 +112,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 101,
+	spec_version: 102,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -313,10 +314,46 @@ impl pallet_sudo::Config for Runtime {
 	type RuntimeCall = RuntimeCall;
 }
 
+// The treasury is funded by the treasury cut of the subspace block emission. Spending proposals
+// are approved or rejected by root.
+parameter_types! {
+	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
+	pub const ProposalBond: Permill = Permill::from_percent(5);
+	pub const ProposalBondMinimum: Balance = 1_000_000_000;
+	pub const SpendPeriod: BlockNumber = DAYS;
+	pub const Burn: Permill = Permill::zero();
+	pub const MaxApprovals: u32 = 100;
+	pub const MaxBalance: Balance = Balance::max_value();
+	pub TreasuryAccount: AccountId = TreasuryPalletId::get().into_account_truncating();
+}
+
+impl pallet_treasury::Config for Runtime {
+	type PalletId = TreasuryPalletId;
+	type Currency = Balances;
+	type ApproveOrigin = EnsureRoot<AccountId>;
+	type RejectOrigin = EnsureRoot<AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type OnSlash = Treasury;
+	type ProposalBond = ProposalBond;
+	type ProposalBondMinimum = ProposalBondMinimum;
+	type ProposalBondMaximum = ();
+	type SpendPeriod = SpendPeriod;
+	type Burn = Burn;
+	type BurnDestination = ();
+	type SpendFunds = ();
+	type WeightInfo = pallet_treasury::weights::SubstrateWeight<Runtime>;
+	type MaxApprovals = MaxApprovals;
+	type SpendOrigin = EnsureRootWithSuccess<AccountId, MaxBalance>;
+}
+
 // Configure the pallet subspace.
 parameter_types! {
     pub const SubspaceInitialMaxAllowedUids: u16 = 4096;
+    pub const SubspaceInitialMaxAllowedValidators: u16 = 128;
     pub const SubspaceInitialIssuance: u64 = 0;
+    pub const SubspaceInitialBlockEmission: u64 = 1_000_000_000;
+    pub const SubspaceMaxSupply: u64 = 21_000_000_000_000_000; // 21M tokens, the first halving after 10.5M blocks.
+    pub const SubspaceInitialTreasuryCut: u16 = 0; // Percentage of the block emission paid to the treasury.
     pub const SubspaceInitialMinAllowedWeights: u16 = 1024;
     pub const SubspaceInitialEmissionValue: u16 = 0;
     pub const SubspaceInitialMaxWeightsLimit: u16 = 1000; // 1000/2^16 = 0.015
@@ -328,16 +365,44 @@ parameter_types! {
     pub const SubspaceInitialMaxRegistrationsPerBlock: u16 = 1;
     pub const SubspaceInitialPruningScore : u16 = u16::MAX;
     pub const SubspaceInitialBondsMovingAverage: u64 = 900_000;
+    pub const SubspaceInitialRho: u16 = 10;
+    pub const SubspaceInitialKappa: u16 = 32_767; // 0.5 = 65535/2
+    pub const SubspaceInitialIncentiveRatio: u16 = 50; // Incentive and dividends share the emission equally.
     pub const SubspaceInitialServingRateLimit: u64 = 50; 
 	pub const SubspaceInitialTxRateLimit: u64 = 1000;
+	pub const SubspaceMaxAllowedUidsLowerBound: u16 = 1;
+	pub const SubspaceMaxAllowedUidsUpperBound: u16 = 4096;
+	pub const SubspaceImmunityPeriodUpperBound: u16 = 16384;
+	pub const SubspaceActivityCutoffLowerBound: u16 = 100;
+	pub const SubspaceActivityCutoffUpperBound: u16 = 50000;
+	pub const SubspaceBondsMovingAverageUpperBound: u64 = 1_000_000;
+	pub const SubspaceAdjustmentIntervalLowerBound: u16 = 1;
+	pub const SubspaceMaxRegistrationsPerBlockUpperBound: u16 = 256;
+	pub const SubspaceServingRateLimitUpperBound: u64 = 7200;
+	pub const SubspaceEpochWeightPerBlock: Weight = Weight::from_ref_time(WEIGHT_REF_TIME_PER_SECOND / 2);
+	pub const SubspaceMaxEmissionHistory: u32 = 32;
+	pub const SubspaceMaxModuleEndpoints: u32 = 8;
+	pub const SubspaceMaxNameLength: u32 = 64;
+	pub const SubspaceMaxUriLength: u32 = 256;
+	pub const SubspaceMaxScheduledChanges: u32 = 32;
+	pub const SubspaceMaxModuleTags: u32 = 8;
+	pub const SubspaceMaxTagLength: u32 = 32;
 }
 
 impl pallet_subspace::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type InitialMaxAllowedUids = SubspaceInitialMaxAllowedUids;
+	type InitialMaxAllowedValidators = SubspaceInitialMaxAllowedValidators;
 	type InitialBondsMovingAverage = SubspaceInitialBondsMovingAverage;
+	type InitialRho = SubspaceInitialRho;
+	type InitialKappa = SubspaceInitialKappa;
+	type InitialIncentiveRatio = SubspaceInitialIncentiveRatio;
 	type InitialIssuance = SubspaceInitialIssuance;
+	type InitialBlockEmission = SubspaceInitialBlockEmission;
+	type MaxSupply = SubspaceMaxSupply;
+	type TreasuryAccount = TreasuryAccount;
+	type InitialTreasuryCut = SubspaceInitialTreasuryCut;
 	type InitialMinAllowedWeights = SubspaceInitialMinAllowedWeights;
 	type InitialEmissionValue = SubspaceInitialEmissionValue;
 	type InitialMaxWeightsLimit = SubspaceInitialMaxWeightsLimit;
@@ -350,6 +415,24 @@ impl pallet_subspace::Config for Runtime {
 	type InitialPruningScore = SubspaceInitialPruningScore;
 	type InitialServingRateLimit = SubspaceInitialServingRateLimit;
 	type InitialTxRateLimit = SubspaceInitialTxRateLimit;
+	type MaxAllowedUidsLowerBound = SubspaceMaxAllowedUidsLowerBound;
+	type MaxAllowedUidsUpperBound = SubspaceMaxAllowedUidsUpperBound;
+	type ImmunityPeriodUpperBound = SubspaceImmunityPeriodUpperBound;
+	type ActivityCutoffLowerBound = SubspaceActivityCutoffLowerBound;
+	type ActivityCutoffUpperBound = SubspaceActivityCutoffUpperBound;
+	type BondsMovingAverageUpperBound = SubspaceBondsMovingAverageUpperBound;
+	type AdjustmentIntervalLowerBound = SubspaceAdjustmentIntervalLowerBound;
+	type MaxRegistrationsPerBlockUpperBound = SubspaceMaxRegistrationsPerBlockUpperBound;
+	type ServingRateLimitUpperBound = SubspaceServingRateLimitUpperBound;
+	type EpochWeightPerBlock = SubspaceEpochWeightPerBlock;
+	type MaxEmissionHistory = SubspaceMaxEmissionHistory;
+	type MaxModuleEndpoints = SubspaceMaxModuleEndpoints;
+	type MaxNameLength = SubspaceMaxNameLength;
+	type MaxUriLength = SubspaceMaxUriLength;
+	type MaxScheduledChanges = SubspaceMaxScheduledChanges;
+	type MaxModuleTags = SubspaceMaxModuleTags;
+	type MaxTagLength = SubspaceMaxTagLength;
+	type WeightInfo = pallet_subspace::weight_info::SubstrateWeight<Runtime>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -368,6 +451,7 @@ construct_runtime!(
 		Balances: pallet_balances,
 		TransactionPayment: pallet_transaction_payment,
 		Sudo: pallet_sudo,
+		Treasury: pallet_treasury,
 		SubspaceModule: pallet_subspace
 	}
 );
@@ -417,6 +501,7 @@ mod benches {
 		[pallet_balances, Balances]
 		[pallet_subspace, SubspaceModule]
 		[pallet_timestamp, Timestamp]
+		[pallet_treasury, Treasury]
 	);
 }
 
@@ -658,6 +743,11 @@ impl_runtime_apis! {
 				vec![]
 			}
 		}
+
+		fn get_modules_by_tag(netuid: u16, tag: Vec<u8>) -> Vec<u8> {
+			let result = SubspaceModule::get_modules_by_tag(netuid, tag);
+			result.encode()
+		}
 	}
 
 	impl subspace_custom_rpc_runtime_api::NetworkRuntimeApi<Block> for Runtime {
@@ -676,6 +766,19 @@ impl_runtime_apis! {
 			result.encode()
 		}
 	}
+
+	impl subspace_custom_rpc_runtime_api::EmissionRuntimeApi<Block> for Runtime {
+		fn get_projected_issuance(block_number: u64) -> u64 {
+			SubspaceModule::get_projected_issuance(block_number)
+		}
+
+		fn get_key_emission(netuid: u16, key: Vec<u8>) -> Vec<u8> {
+			match AccountId::decode(&mut &key[..]) {
+				Ok(key) => SubspaceModule::get_key_emission(netuid, &key).encode(),
+				Err(_) => vec![],
+			}
+		}
+	}
 }
 
 #[cfg(test)]